@@ -2207,6 +2207,18 @@ mod progenitor_support {
     }
 }
 
+/// Limits applied by functions that return all the pages of a request at once
+/// by following `Link` headers.
+#[derive(Debug, Clone, Default)]
+pub struct Pagination {
+    /// Stop once this many items have been fetched.
+    pub max_items: Option<usize>,
+    /// Stop once this many pages have been fetched.
+    pub max_pages: Option<usize>,
+    /// The number of items to request per page.
+    pub page_size: Option<u32>,
+}
+
 use std::convert::TryInto;
 use std::env;
 use std::ops::Add;
@@ -2227,6 +2239,7 @@ pub struct Client {
     redirect_uri: String,
 
     auto_refresh: bool,
+    pagination: Pagination,
     client: reqwest_middleware::ClientWithMiddleware,
 }
 
@@ -2326,6 +2339,7 @@ impl Client {
                     })),
 
                     auto_refresh: false,
+                    pagination: Default::default(),
                     client,
                 }
             }
@@ -2403,6 +2417,13 @@ impl Client {
         c
     }
 
+    /// Set the limits used by functions that return all the pages of a request at once.
+    pub fn with_pagination(&self, pagination: Pagination) -> Self {
+        let mut c = self.clone();
+        c.pagination = pagination;
+        c
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -2539,7 +2560,7 @@ impl Client {
         uri: &str,
         body: Option<reqwest::Body>,
     ) -> Result<reqwest::Request> {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -2701,7 +2722,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -2771,7 +2792,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -2840,7 +2861,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -2949,30 +2970,17 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = link.as_ref().and_then(crate::utils::next_link) {
-                let url = reqwest::Url::parse(&url)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
-            }
-        }
-
-        Ok(global_items)
-    }
+        let url = crate::utils::with_page_size(
+            &(self.host.to_string() + uri),
+            crate::utils::PAGE_SIZE_PARAM,
+            self.pagination.page_size,
+        );
 
-    #[allow(dead_code)]
-    async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        self.request_with_links(http::Method::GET, &(self.host.to_string() + uri), None)
-            .await
+        crate::utils::unfold(url, &self.pagination, |url| async move {
+            let url = reqwest::Url::parse(&url)?;
+            self.get_pages_url(&url).await
+        })
+        .await
     }
 
     #[allow(dead_code)]
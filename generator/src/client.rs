@@ -10,6 +10,7 @@ pub struct Client {
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
     credentials: Option<crate::auth::Credentials>,
+    pagination: Pagination,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
@@ -76,6 +77,7 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            pagination: Default::default(),
             http_cache,
         }
     }
@@ -92,6 +94,7 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            pagination: Default::default(),
         }
     }
 
@@ -102,6 +105,13 @@ impl Client {
         self.credentials = credentials.into();
     }
 
+    /// Set the limits used by functions that return all the pages of a request at once.
+    pub fn with_pagination(&self, pagination: Pagination) -> Self {
+        let mut c = self.clone();
+        c.pagination = pagination;
+        c
+    }
+
     fn credentials(&self, authentication: crate::auth::AuthenticationConstraint) -> Option<&crate::auth::Credentials> {
         match (authentication, self.credentials.as_ref()) {
             (crate::auth::AuthenticationConstraint::Unconstrained, creds) => creds,
@@ -342,19 +352,6 @@ impl Client {
         self.unfold(uri).await
     }
 
-    async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        self.request(
-            http::Method::GET,
-            &(self.host.clone() + uri),
-            None,
-            crate::utils::MediaType::Json,
-            crate::auth::AuthenticationConstraint::Unconstrained,
-        ).await
-    }
-
     async fn get_pages_url<D>(&self, url: &reqwest::Url) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
     where
         D: serde::de::DeserializeOwned + 'static + Send,
@@ -460,21 +457,16 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = link.as_ref().and_then(|l| crate::utils::next_link(l)) {
-                let url = reqwest::Url::parse(&url)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
-            }
-        }
+        let url = crate::utils::with_page_size(
+            &(self.host.clone() + uri),
+            crate::utils::PAGE_SIZE_PARAM,
+            self.pagination.page_size,
+        );
 
-        Ok(global_items)
+        crate::utils::unfold(url, &self.pagination, |url| async move {
+            let url = reqwest::Url::parse(&url)?;
+            self.get_pages_url(&url).await
+        }).await
     }"#;
 
 pub fn generate_client_generic_token(
@@ -530,6 +522,17 @@ pub fn generate_client_generic_token(
 
     let token_auth_template = get_token_auth_template(consent_pattern);
 
+    // Google supports partial responses with the `fields` parameter.
+    let (fields_struct, fields_init, fields_fn) = if proper_name.starts_with("Google") {
+        (
+            "fields: String,",
+            "fields: Default::default(),",
+            GOOGLE_WITH_FIELDS_TEMPLATE,
+        )
+    } else {
+        ("", "", "")
+    };
+
     format!(
         r#"use std::sync::Arc;
 use std::convert::TryInto;
@@ -549,8 +552,9 @@ pub struct Client {{
     client_id: String,
     client_secret: String,
     redirect_uri: String,
-    {}
+    {}{}
     auto_refresh: bool,
+    pagination: Pagination,
     client: reqwest_middleware::ClientWithMiddleware,
 }}
 
@@ -615,8 +619,9 @@ impl Client {{
                         refresh_token: refresh_token.to_string(),
                         expires_at: None
                     }})),
-                    {}
+                    {}{}
                     auto_refresh: false,
+                    pagination: Default::default(),
                     client,
                 }}
             }}
@@ -690,6 +695,16 @@ impl Client {{
         c
     }}
 
+    /// Set the limits used by functions that return all the pages of a request at once.
+    pub fn with_pagination(&self, pagination: Pagination) -> Self
+    {{
+        let mut c = self.clone();
+        c.pagination = pagination;
+        c
+    }}
+
+    {}
+
     {}
 
 
@@ -700,11 +715,14 @@ impl Client {{
         token_endpoint.trim_start_matches("https://"),
         user_consent_endpoint.trim_start_matches("https://"),
         add_post_header_struct,
+        fields_struct,
         ACCESS_TOKEN_STRUCT_TEMPLATE,
         add_post_header_type,
         add_post_header_args,
         add_post_header_args_where,
         add_post_header_fn,
+        fields_init,
+        fields_fn,
         new_from_env,
         token_auth_template,
         get_shared_functions(proper_name, add_post_header)
@@ -817,7 +835,9 @@ where
                     refresh_token: refresh_token.to_string(),
                     expires_at: None
                 })),
+                fields: Default::default(),
                 auto_refresh: false,
+                pagination: Default::default(),
                 client,
             }
         },
@@ -826,6 +846,37 @@ where
 }
 "#;
 
+const GOOGLE_WITH_FIELDS_TEMPLATE: &str = r#"/// Only return the selected fields of responses, like `files(id,name),nextPageToken`.
+///
+/// This is sent as the `fields` parameter of the requests of the generated
+/// functions, unless they have one already. Uploads, downloads and batches are
+/// not affected. Fields that are not selected are left to their defaults, so
+/// functions that return all the pages of a request need `nextPageToken` to be
+/// selected.
+pub fn with_fields<F>(&self, fields: F) -> Self
+where
+    F: ToString,
+{
+    let mut c = self.clone();
+    c.fields = fields.to_string();
+    c
+}
+
+/// Returns the URL of a request with the fields selected with `with_fields`.
+fn fields_url(&self, uri: &str) -> String {
+    if self.fields.is_empty() {
+        return uri.to_string();
+    }
+
+    match reqwest::Url::parse(uri) {
+        Ok(mut u) if !u.query_pairs().any(|(k, _)| k == "fields") => {
+            u.query_pairs_mut().append_pair("fields", &self.fields);
+            u.to_string()
+        }
+        _ => uri.to_string(),
+    }
+}"#;
+
 pub fn generate_client_generic_api_key(proper_name: &str, add_post_header: &str) -> String {
     format!(
         r#"use std::env;
@@ -835,6 +886,7 @@ pub fn generate_client_generic_api_key(proper_name: &str, add_post_header: &str)
 pub struct Client {{
     host: String,
     token: String,
+    pagination: Pagination,
 
     client: reqwest_middleware::ClientWithMiddleware,
 }}
@@ -868,6 +920,7 @@ impl Client {{
                 Client {{
                     host: DEFAULT_HOST.to_string(),
                     token: token.to_string(),
+                    pagination: Default::default(),
 
                     client,
                 }}
@@ -886,6 +939,14 @@ impl Client {{
         c
      }}
 
+    /// Set the limits used by functions that return all the pages of a request at once.
+    pub fn with_pagination(&self, pagination: Pagination) -> Self
+    {{
+        let mut c = self.clone();
+        c.pagination = pagination;
+        c
+    }}
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -931,6 +992,17 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
     };
 
     // Add auto refresh functionality to clients that support it
+    // Only the requests of the generated functions ask for the fields selected
+    // with `with_fields`.
+    let (fields_entity, fields_unfold) = if proper_name.starts_with("Google") {
+        (
+            "\n    let uri = &self.fields_url(uri);",
+            "\n    let url = self.fields_url(&url);",
+        )
+    } else {
+        ("", "")
+    };
+
     let raw_request = if proper_name.starts_with("Google")
         || proper_name == "DocuSign"
         || proper_name == "Gusto"
@@ -945,6 +1017,20 @@ fn get_shared_functions(proper_name: &str, add_post_header: &str) -> String {
         get_shared_raw_functions_without_refresh(&bearer, &post_header_args)
     };
 
+    // Slack answers most errors with a `200 OK`, and `"ok": false`.
+    let (slack_headers, slack_check) = if proper_name == "Slack" {
+        (
+            "\n    let headers = response.headers().clone();",
+            r#"
+    if let Some(error) = crate::error::SlackError::from_response(status, &headers, &response_body) {
+        return Err(error.into());
+    }
+"#,
+        )
+    } else {
+        ("", "")
+    };
+
     format!(
         r#"
 {}
@@ -960,10 +1046,10 @@ async fn request<Out>(
 {{
     let response = self.request_raw(method, uri, body).await?;
 
-    let status = response.status();
+    let status = response.status();{slack_headers}
 
     let response_body = response.bytes().await?;
-
+{slack_check}
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
@@ -998,7 +1084,7 @@ where
 {{
     let response = self.request_raw(method, uri, body).await?;
 
-    let status = response.status();
+    let status = response.status();{slack_headers}
     let link = response
         .headers()
         .get(http::header::LINK)
@@ -1006,7 +1092,7 @@ where
         .and_then(|l| l.parse().ok());
 
     let response_body = response.bytes().await?;
-
+{slack_check}
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
 
@@ -1040,7 +1126,7 @@ async fn post_form<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1065,10 +1151,10 @@ async fn post_form<Out>(
 
     let response = req.send().await?;
 
-    let status = response.status();
+    let status = response.status();{slack_headers}
 
     let response_body = response.bytes().await?;
-
+{slack_check}
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
@@ -1106,7 +1192,7 @@ async fn request_with_accept_mime<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1129,10 +1215,10 @@ async fn request_with_accept_mime<Out>(
 
     let response = req.send().await?;
 
-    let status = response.status();
+    let status = response.status();{slack_headers}
 
     let response_body = response.bytes().await?;
-
+{slack_check}
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
@@ -1171,7 +1257,7 @@ async fn request_with_mime<Out>(
     where
     Out: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1213,10 +1299,10 @@ async fn request_with_mime<Out>(
 
     let response = req.send().await?;
 
-    let status = response.status();
+    let status = response.status();{slack_headers}
 
     let response_body = response.bytes().await?;
-
+{slack_check}
     if status.is_success() {{
         log::debug!("Received successful response. Read payload.");
         let parsed_response = if status == http::StatusCode::NO_CONTENT || std::any::TypeId::of::<Out>() == std::any::TypeId::of::<()>(){{
@@ -1248,7 +1334,7 @@ async fn request_entity<D>(
 ) -> Result<D>
 where
     D: serde::de::DeserializeOwned + 'static + Send,
-{{
+{{{fields_entity}
     let r = self
         .request(method, uri, body)
         .await?;
@@ -1285,33 +1371,16 @@ async fn unfold<D>(
 where
     D: serde::de::DeserializeOwned + 'static + Send,
 {{
-    let mut global_items = Vec::new();
-    let (new_link, mut items) = self.get_pages(uri).await?;
-    let mut link = new_link;
-    while !items.is_empty() {{
-        global_items.append(&mut items);
-        // We need to get the next link.
-        if let Some(url) = link.as_ref().and_then(crate::utils::next_link) {{
-            let url = reqwest::Url::parse(&url)?;
-            let (new_link, new_items) = self.get_pages_url(&url).await?;
-            link = new_link;
-            items = new_items;
-        }}
-    }}
-
-    Ok(global_items)
-}}
-
-#[allow(dead_code)]
-async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
-where
-    D: serde::de::DeserializeOwned + 'static + Send,
-{{
-    self.request_with_links(
-        http::Method::GET,
+    let url = crate::utils::with_page_size(
         &(self.host.to_string() + uri),
-        None,
-    ).await
+        crate::utils::PAGE_SIZE_PARAM,
+        self.pagination.page_size,
+    );{fields_unfold}
+
+    crate::utils::unfold(url, &self.pagination, |url| async move {{
+        let url = reqwest::Url::parse(&url)?;
+        self.get_pages_url(&url).await
+    }}).await
 }}
 
 #[allow(dead_code)]
@@ -1373,7 +1442,11 @@ where
         message,
     ).await
 }}"#,
-        raw_request
+        raw_request,
+        slack_headers = slack_headers,
+        slack_check = slack_check,
+        fields_entity = fields_entity,
+        fields_unfold = fields_unfold,
     )
 }
 
@@ -1396,7 +1469,7 @@ async fn request_raw(
     body: Option<reqwest::Body>,
 ) -> Result<reqwest::Response>
 {{
-    let u = if uri.starts_with("https://") {{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1446,7 +1519,7 @@ async fn make_request(
     uri: &str,
     body: Option<reqwest::Body>,
 ) -> Result<reqwest::Request> {{
-    let u = if uri.starts_with("https://") {{
+    let u = if uri.starts_with("https://") || uri.starts_with("http://") {{
         uri.to_string()
     }} else {{
         (self.host.clone() + uri).to_string()
@@ -1680,6 +1753,7 @@ pub struct Client {{
     token: String,
     client_id: String,
     client_secret: String,
+    pagination: Pagination,
     client: reqwest_middleware::ClientWithMiddleware,
 }}
 
@@ -1720,6 +1794,7 @@ impl Client {{
                     client_id: client_id.to_string(),
                     client_secret: client_secret.to_string(),
                     token: token.to_string(),
+                    pagination: Default::default(),
 
                     client,
                 }}
@@ -1738,6 +1813,14 @@ impl Client {{
         c
     }}
 
+    /// Set the limits used by functions that return all the pages of a request at once.
+    pub fn with_pagination(&self, pagination: Pagination) -> Self
+    {{
+        let mut c = self.clone();
+        c.pagination = pagination;
+        c
+    }}
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -1810,3 +1893,864 @@ pub struct AccessToken {
     )]
     pub scope: String,
 }"#;
+
+/// Returns the path of the batch endpoint of the Google APIs that support
+/// batching requests.
+pub fn google_batch_path(proper_name: &str) -> Option<&'static str> {
+    match proper_name {
+        "Google Admin" => Some("/batch/admin/directory_v1"),
+        "Google Calendar" => Some("/batch/calendar/v3"),
+        "Google Drive" => Some("/batch/drive/v3"),
+        _ => None,
+    }
+}
+
+/// Generate the module for sending batches of requests to `batch_path`.
+pub fn generate_google_batch(batch_path: &str) -> String {
+    GOOGLE_BATCH_TEMPLATE.replace("BATCH_PATH_PLACEHOLDER", batch_path)
+}
+
+const GOOGLE_BATCH_TEMPLATE: &str = r#"use std::{
+    future::Future,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, bail, Result};
+
+/// The path of the batch endpoint, on the host of the client.
+const BATCH_PATH: &str = "BATCH_PATH_PLACEHOLDER";
+
+/// The most requests Google accepts in a single batch.
+pub const MAX_BATCH_SIZE: usize = 100;
+
+/// A batch of requests, sent together in a single `multipart/mixed` request.
+///
+/// Calls are added with `add`, which runs a function of the client without
+/// sending anything:
+///
+/// ```ignore
+/// let mut batch = client.batch();
+/// let call = batch.add(|c| async move { c.files().get("id", false, "", false, false).await }).await?;
+/// let responses = batch.send().await?;
+/// let file = responses.get(&call)?;
+/// ```
+pub struct Batch {
+    client: crate::Client,
+    requests: Vec<reqwest::Request>,
+}
+
+/// Refers to the response of a call added to a `Batch`, and the type it parses to.
+#[derive(Debug)]
+pub struct BatchCall<T> {
+    index: usize,
+    output: PhantomData<fn() -> T>,
+}
+
+/// The response to a single call of a batch.
+#[derive(Debug, Clone)]
+pub struct BatchResponse {
+    pub status: http::StatusCode,
+    pub body: bytes::Bytes,
+}
+
+/// The responses to the calls of a batch.
+#[derive(Debug, Clone, Default)]
+pub struct BatchResponses {
+    responses: Vec<Option<BatchResponse>>,
+}
+
+impl<T> BatchCall<T> {
+    pub(crate) fn new(index: usize) -> Self {
+        BatchCall {
+            index,
+            output: PhantomData,
+        }
+    }
+}
+
+impl Batch {
+    pub(crate) fn new(client: crate::Client) -> Self {
+        Batch {
+            client,
+            requests: Vec::new(),
+        }
+    }
+
+    /// Add the request of a call to the batch.
+    ///
+    /// `call` is given a client that records its request instead of sending it,
+    /// and must make exactly one request with it. Uploads, downloads and
+    /// functions that return all the pages of a request can not be batched.
+    pub async fn add<F, Fut, T>(&mut self, call: F) -> Result<BatchCall<T>>
+    where
+        F: FnOnce(crate::Client) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        if self.requests.len() >= MAX_BATCH_SIZE {
+            bail!("a batch can not have more than {} calls", MAX_BATCH_SIZE);
+        }
+
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let mut client = self.client.clone();
+        client.auto_refresh = false;
+        client.client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(BatchRecorder(recorded.clone()))
+            .build();
+
+        // The call fails since nothing was sent, all we want is its request.
+        let _ = call(client).await;
+
+        let mut recorded = std::mem::take(&mut *recorded.lock().unwrap());
+        if recorded.len() != 1 {
+            bail!(
+                "a batched call must make exactly one request, not {}",
+                recorded.len()
+            );
+        }
+        self.requests.push(recorded.remove(0));
+
+        Ok(BatchCall::new(self.requests.len() - 1))
+    }
+
+    /// Returns the number of calls in the batch.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns true if no calls were added to the batch.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Send the batch and return the response to each call.
+    pub async fn send(self) -> Result<BatchResponses> {
+        if self.requests.is_empty() {
+            return Ok(Default::default());
+        }
+
+        let boundary = format!("batch_{}", uuid::Uuid::new_v4().simple());
+        let body = encode_batch(&self.requests, &boundary)?;
+
+        let host = self.client.host.parse::<reqwest::Url>()?;
+        let url = format!("{}{}", host.origin().ascii_serialization(), BATCH_PATH);
+        let mut req = self
+            .client
+            .make_request(&reqwest::Method::POST, &url, Some(body.into()))
+            .await?;
+        req.headers_mut().insert(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_str(&format!(
+                "multipart/mixed; boundary={}",
+                boundary
+            ))?,
+        );
+
+        let resp = self.client.client.execute(req).await?;
+        let status = resp.status();
+        let content_type = resp
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let body = resp.bytes().await?;
+        if !status.is_success() {
+            bail!(
+                "code: {}, error: {:?}",
+                status,
+                String::from_utf8_lossy(&body)
+            );
+        }
+
+        let boundary = match content_type
+            .split(';')
+            .find_map(|p| p.trim().strip_prefix("boundary="))
+        {
+            Some(b) => b.trim_matches('"').to_string(),
+            None => bail!("batch response is not multipart: {}", content_type),
+        };
+
+        parse_batch_response(&body, &boundary, self.requests.len())
+    }
+}
+
+impl BatchResponses {
+    /// Returns the response to a call, parsed to the type the call returns.
+    ///
+    /// This returns an error with the body if the call did not succeed.
+    pub fn get<T>(&self, call: &BatchCall<T>) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let resp = match self.response(call) {
+            Some(r) => r,
+            None => bail!("the batch response has no response for call {}", call.index),
+        };
+
+        if !resp.status.is_success() {
+            return Err(anyhow!(
+                "code: {}, error: {:?}",
+                resp.status,
+                String::from_utf8_lossy(&resp.body)
+            ));
+        }
+
+        if resp.body.is_empty() {
+            Ok(serde_json::from_str("null")?)
+        } else {
+            Ok(serde_json::from_slice(&resp.body)?)
+        }
+    }
+
+    /// Returns the raw response to a call.
+    pub fn response<T>(&self, call: &BatchCall<T>) -> Option<&BatchResponse> {
+        self.responses.get(call.index).and_then(|r| r.as_ref())
+    }
+}
+
+/// Records the requests sent through a client, and fails them.
+struct BatchRecorder(Arc<Mutex<Vec<reqwest::Request>>>);
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for BatchRecorder {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        _extensions: &mut task_local_extensions::Extensions,
+        _next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        self.0.lock().unwrap().push(req);
+
+        Err(reqwest_middleware::Error::Middleware(anyhow!(
+            "the request was added to a batch"
+        )))
+    }
+}
+
+/// Encode requests as the parts of a `multipart/mixed` body.
+pub(crate) fn encode_batch(requests: &[reqwest::Request], boundary: &str) -> Result<Vec<u8>> {
+    let mut body = Vec::new();
+    for (i, req) in requests.iter().enumerate() {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Type: application/http\r\nContent-ID: <item{}>\r\n\r\n",
+                boundary, i
+            )
+            .as_bytes(),
+        );
+
+        let url = req.url();
+        let path = match url.query() {
+            Some(q) if !q.is_empty() => format!("{}?{}", url.path(), q),
+            _ => url.path().to_string(),
+        };
+        body.extend_from_slice(format!("{} {} HTTP/1.1\r\n", req.method(), path).as_bytes());
+
+        // The batch request is authorized for all of its calls.
+        for (name, value) in req.headers() {
+            if name != http::header::AUTHORIZATION {
+                body.extend_from_slice(name.as_str().as_bytes());
+                body.extend_from_slice(b": ");
+                body.extend_from_slice(value.as_bytes());
+                body.extend_from_slice(b"\r\n");
+            }
+        }
+        body.extend_from_slice(b"\r\n");
+
+        if let Some(b) = req.body() {
+            match b.as_bytes() {
+                Some(b) => body.extend_from_slice(b),
+                None => bail!("the body of a batched call can not be a stream"),
+            }
+        }
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+    Ok(body)
+}
+
+/// Parse the parts of a `multipart/mixed` batch response, matching them to
+/// the `count` calls of the batch by their `Content-ID`.
+pub(crate) fn parse_batch_response(
+    body: &[u8],
+    boundary: &str,
+    count: usize,
+) -> Result<BatchResponses> {
+    let delimiter = format!("--{}", boundary);
+    let mut responses = vec![None; count];
+
+    let mut parts = split_bytes(body, delimiter.as_bytes());
+    // Skip the preamble.
+    parts.next();
+    for (position, part) in parts.enumerate() {
+        // The last delimiter is followed by `--`.
+        if part.starts_with(b"--") {
+            break;
+        }
+
+        let (headers, http) = split_head(part)?;
+        // Match `response-item{i}` to the call that was sent as `item{i}`.
+        let index = headers
+            .lines()
+            .filter_map(|l| l.split_once(':'))
+            .find(|(n, _)| n.trim().eq_ignore_ascii_case("content-id"))
+            .and_then(|(_, v)| {
+                v.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .strip_prefix("response-item")
+                    .and_then(|i| i.parse::<usize>().ok())
+            })
+            .unwrap_or(position);
+        if index >= count {
+            bail!(
+                "the batch response has a response for unknown call {}",
+                index
+            );
+        }
+
+        let (head, body) = split_head(http)?;
+        let status = head
+            .lines()
+            .next()
+            .and_then(|l| l.split_whitespace().nth(1))
+            .and_then(|s| s.parse::<http::StatusCode>().ok())
+            .ok_or_else(|| anyhow!("invalid batch response part: {}", head))?;
+
+        let body = body.strip_suffix(b"\r\n").unwrap_or(body);
+        responses[index] = Some(BatchResponse {
+            status,
+            body: bytes::Bytes::copy_from_slice(body),
+        });
+    }
+
+    Ok(BatchResponses { responses })
+}
+
+/// Split the head of a part from its body at the first blank line.
+fn split_head(part: &[u8]) -> Result<(String, &[u8])> {
+    let part = part.strip_prefix(b"\r\n").unwrap_or(part);
+    let (end, len) = match find_bytes(part, b"\r\n\r\n") {
+        Some(i) => (i, 4),
+        None => match find_bytes(part, b"\n\n") {
+            Some(i) => (i, 2),
+            None => (part.len(), 0),
+        },
+    };
+
+    Ok((String::from_utf8(part[..end].to_vec())?, &part[end + len..]))
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn split_bytes<'a>(mut haystack: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = &'a [u8]> {
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        match find_bytes(haystack, needle) {
+            Some(i) => {
+                let part = &haystack[..i];
+                haystack = &haystack[i + needle.len()..];
+                Some(part)
+            }
+            None => {
+                done = true;
+                Some(haystack)
+            }
+        }
+    })
+}
+"#;
+
+/// Returns the name of the function that stops a push notification channel,
+/// for the Google APIs whose resources can be watched.
+pub fn google_channels_stop(proper_name: &str) -> Option<&'static str> {
+    match proper_name {
+        "Google Admin" => Some("admin_stop"),
+        "Google Calendar" => Some("stop"),
+        "Google Drive" => Some("stop"),
+        _ => None,
+    }
+}
+
+/// Generate the module for tracking push notification channels, which are
+/// stopped with `channels_stop`.
+pub fn generate_google_push(channels_stop: &str) -> String {
+    GOOGLE_PUSH_TEMPLATE.replace("CHANNELS_STOP_PLACEHOLDER", channels_stop)
+}
+
+const GOOGLE_PUSH_TEMPLATE: &str = r#"use std::{future::Future, pin::Pin, sync::Arc};
+
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+
+/// How long before it expires a channel is renewed, by default.
+pub const DEFAULT_RENEW_BEFORE: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+type WatchFn = Arc<
+    dyn Fn(
+            crate::Client,
+            crate::types::Channel,
+        ) -> Pin<Box<dyn Future<Output = Result<crate::types::Channel>> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// Tracks the push notification channels of a client, to verify the
+/// notifications they receive and renew them before they expire.
+///
+/// Channels are created with `watch`, which is given the generated `watch`
+/// function to call:
+///
+/// ```ignore
+/// let mut channels = PushChannels::new(client);
+/// let channel = channels
+///     .watch(
+///         "https://example.com/notifications",
+///         std::time::Duration::from_secs(24 * 60 * 60),
+///         |c, channel| async move { c.files().watch("id", false, "", true, false, &channel).await },
+///     )
+///     .await?;
+///
+/// // When a notification is received.
+/// let notification = Notification::from_headers(request.headers())?;
+/// let channel = channels.verify(&notification)?;
+///
+/// // Periodically, up to `next_renewal`.
+/// channels.renew_expiring().await?;
+/// ```
+///
+/// The channels outlive the process, so their `state` can be saved and
+/// tracked again after a restart with `track`.
+pub struct PushChannels {
+    client: crate::Client,
+    renew_before: std::time::Duration,
+    channels: Vec<WatchedChannel>,
+}
+
+/// The state of a tracked channel, to track it again with
+/// `PushChannels::track`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ChannelState {
+    pub channel: crate::types::Channel,
+    /// How long the channel is created for when it is renewed.
+    pub ttl: std::time::Duration,
+    /// The channel was replaced, but could not be stopped yet.
+    #[serde(default)]
+    pub replaced: bool,
+}
+
+struct WatchedChannel {
+    state: ChannelState,
+    watch: WatchFn,
+}
+
+/// The errors of `renew_expiring`, which renews the other channels when one
+/// of them fails.
+#[derive(Debug)]
+pub struct RenewError {
+    /// The channels that were renewed.
+    pub renewed: Vec<crate::types::Channel>,
+    /// The ID of each channel that could not be renewed or stopped, with its
+    /// error.
+    pub errors: Vec<(String, anyhow::Error)>,
+}
+
+impl std::fmt::Display for RenewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not renew {} channels", self.errors.len())?;
+        for (id, e) in &self.errors {
+            write!(f, ", {}: {}", id, e)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for RenewError {}
+
+impl PushChannels {
+    /// Track the channels created with `client`.
+    pub fn new(client: crate::Client) -> Self {
+        PushChannels {
+            // The channels need every field of the responses.
+            client: client.with_fields(""),
+            renew_before: DEFAULT_RENEW_BEFORE,
+            channels: Vec::new(),
+        }
+    }
+
+    /// Renew channels when they expire in less than `renew_before`.
+    pub fn with_renew_before(mut self, renew_before: std::time::Duration) -> Self {
+        self.renew_before = renew_before;
+        self
+    }
+
+    /// Create a channel that sends notifications to `address` for `ttl`, and
+    /// track it.
+    ///
+    /// `watch` is given the channel to create, with a new ID and token, and
+    /// must create it with the `watch` function of the resource to watch. It
+    /// is called again with a new channel every time the channel is renewed.
+    pub async fn watch<F, Fut>(
+        &mut self,
+        address: &str,
+        ttl: std::time::Duration,
+        watch: F,
+    ) -> Result<crate::types::Channel>
+    where
+        F: Fn(crate::Client, crate::types::Channel) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<crate::types::Channel>> + Send + 'static,
+    {
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        let watched = self.create(address, &token, ttl, watch_fn(watch)).await?;
+        let channel = watched.state.channel.clone();
+        self.channels.push(watched);
+
+        Ok(channel)
+    }
+
+    /// Track a channel from its saved `state`, with the `watch` function it
+    /// was created with.
+    pub fn track<F, Fut>(&mut self, state: ChannelState, watch: F)
+    where
+        F: Fn(crate::Client, crate::types::Channel) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<crate::types::Channel>> + Send + 'static,
+    {
+        self.channels
+            .retain(|w| w.state.channel.id != state.channel.id);
+        self.channels.push(WatchedChannel {
+            state,
+            watch: watch_fn(watch),
+        });
+    }
+
+    /// Returns the state of the tracked channels, to save it.
+    pub fn state(&self) -> Vec<ChannelState> {
+        self.channels.iter().map(|w| w.state.clone()).collect()
+    }
+
+    /// Returns the channels that are tracked.
+    ///
+    /// A channel that was replaced is tracked until it is stopped, so the
+    /// notifications it still sends can be verified.
+    pub fn channels(&self) -> impl Iterator<Item = &crate::types::Channel> {
+        self.channels.iter().map(|w| &w.state.channel)
+    }
+
+    /// Returns a tracked channel by its ID.
+    pub fn get(&self, channel_id: &str) -> Option<&crate::types::Channel> {
+        self.channels().find(|c| c.id == channel_id)
+    }
+
+    /// Returns the channel a notification was sent for, if the notification
+    /// is for a tracked channel and has its token.
+    pub fn verify(&self, notification: &Notification) -> Result<&crate::types::Channel> {
+        let channel = match self.get(&notification.channel_id) {
+            Some(c) => c,
+            None => bail!("unknown channel: {}", notification.channel_id),
+        };
+
+        if !constant_time_eq(
+            channel.token.as_bytes(),
+            notification.channel_token.as_bytes(),
+        ) {
+            bail!("invalid token for channel: {}", channel.id);
+        }
+        if !channel.resource_id.is_empty() && channel.resource_id != notification.resource_id {
+            bail!(
+                "channel {} watches resource {}, not {}",
+                channel.id,
+                channel.resource_id,
+                notification.resource_id
+            );
+        }
+
+        Ok(channel)
+    }
+
+    /// Returns when the next channel has to be renewed.
+    pub fn next_renewal(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.channels
+            .iter()
+            .filter(|w| !w.state.replaced)
+            .filter_map(|w| expiration(&w.state.channel))
+            .min()
+            .map(|e| e - to_chrono(self.renew_before))
+    }
+
+    /// Renew the channels that expire soon, and return the new channels.
+    ///
+    /// Google can not extend a channel, so each one is replaced with a new
+    /// channel with the same token, and then stopped. The old channel is
+    /// tracked until it is stopped, and stopping it is tried again on the
+    /// next call if it fails.
+    ///
+    /// Every channel is renewed even if some fail, which returns a
+    /// `RenewError`.
+    pub async fn renew_expiring(&mut self) -> Result<Vec<crate::types::Channel>> {
+        let now = chrono::Utc::now();
+        let renew_at = now + to_chrono(self.renew_before);
+
+        let mut renewed = Vec::new();
+        let mut stopped = Vec::new();
+        let mut errors = Vec::new();
+        for i in 0..self.channels.len() {
+            if !self.channels[i].state.replaced {
+                let old = &self.channels[i];
+                match expiration(&old.state.channel) {
+                    Some(e) if e <= renew_at => {}
+                    _ => continue,
+                }
+
+                let watched = self
+                    .create(
+                        &old.state.channel.address,
+                        &old.state.channel.token,
+                        old.state.ttl,
+                        old.watch.clone(),
+                    )
+                    .await;
+                match watched {
+                    Ok(watched) => {
+                        renewed.push(watched.state.channel.clone());
+                        self.channels[i].state.replaced = true;
+                        self.channels.push(watched);
+                    }
+                    Err(e) => {
+                        errors.push((old.state.channel.id.to_string(), e));
+                        continue;
+                    }
+                }
+            }
+
+            let old = &self.channels[i].state.channel;
+            match self.stop_channel(old).await {
+                Ok(()) => stopped.push(i),
+                // Google stops the channel when it expires anyway.
+                Err(_) if expiration(old).map(|e| e <= now).unwrap_or(false) => stopped.push(i),
+                Err(e) => errors.push((old.id.to_string(), e)),
+            }
+        }
+
+        for i in stopped.into_iter().rev() {
+            self.channels.remove(i);
+        }
+
+        if !errors.is_empty() {
+            return Err(RenewError { renewed, errors }.into());
+        }
+
+        Ok(renewed)
+    }
+
+    /// Stop a channel and stop tracking it.
+    pub async fn stop(&mut self, channel_id: &str) -> Result<()> {
+        let i = match self
+            .channels
+            .iter()
+            .position(|w| w.state.channel.id == channel_id)
+        {
+            Some(i) => i,
+            None => bail!("unknown channel: {}", channel_id),
+        };
+
+        self.stop_channel(&self.channels[i].state.channel).await?;
+        self.channels.remove(i);
+
+        Ok(())
+    }
+
+    /// Stop every channel that is tracked.
+    ///
+    /// The channels that could not be stopped are still tracked.
+    pub async fn stop_all(&mut self) -> Result<()> {
+        let mut error = None;
+        let mut failed = Vec::new();
+        for watched in std::mem::take(&mut self.channels) {
+            if let Err(e) = self.stop_channel(&watched.state.channel).await {
+                error.get_or_insert(e);
+                failed.push(watched);
+            }
+        }
+        self.channels = failed;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    async fn create(
+        &self,
+        address: &str,
+        token: &str,
+        ttl: std::time::Duration,
+        watch: WatchFn,
+    ) -> Result<WatchedChannel> {
+        let expiration = chrono::Utc::now() + to_chrono(ttl);
+        let request: crate::types::Channel = serde_json::from_value(serde_json::json!({
+            "id": uuid::Uuid::new_v4().to_string(),
+            "type": "web_hook",
+            "address": address,
+            "token": token,
+            "expiration": expiration.timestamp_millis(),
+        }))?;
+
+        let created = watch(self.client.clone(), request.clone()).await?;
+
+        let mut channel = request;
+        channel.resource_id = created.resource_id;
+        channel.resource_uri = created.resource_uri;
+        // Google can shorten the expiration we asked for.
+        if created.expiration > 0 {
+            channel.expiration = created.expiration;
+        }
+
+        Ok(WatchedChannel {
+            state: ChannelState {
+                channel,
+                ttl,
+                replaced: false,
+            },
+            watch,
+        })
+    }
+
+    async fn stop_channel(&self, channel: &crate::types::Channel) -> Result<()> {
+        self.client.channels().CHANNELS_STOP_PLACEHOLDER(channel).await
+    }
+}
+
+fn watch_fn<F, Fut>(watch: F) -> WatchFn
+where
+    F: Fn(crate::Client, crate::types::Channel) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<crate::types::Channel>> + Send + 'static,
+{
+    Arc::new(move |c, channel| Box::pin(watch(c, channel)))
+}
+
+/// The state of the resource a notification is sent for, from the
+/// `X-Goog-Resource-State` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceState {
+    /// The channel was created, and will send notifications.
+    Sync,
+    Add,
+    Change,
+    Exists,
+    NotExists,
+    Remove,
+    Trash,
+    Untrash,
+    Update,
+    Other(String),
+}
+
+impl ResourceState {
+    fn parse(s: &str) -> Self {
+        match s {
+            "sync" => ResourceState::Sync,
+            "add" => ResourceState::Add,
+            "change" => ResourceState::Change,
+            "exists" => ResourceState::Exists,
+            "not_exists" => ResourceState::NotExists,
+            "remove" => ResourceState::Remove,
+            "trash" => ResourceState::Trash,
+            "untrash" => ResourceState::Untrash,
+            "update" => ResourceState::Update,
+            _ => ResourceState::Other(s.to_string()),
+        }
+    }
+}
+
+/// A push notification, from the `X-Goog-*` headers of the request Google
+/// sends to the address of a channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub channel_id: String,
+    /// The token of the channel, empty if it has none.
+    pub channel_token: String,
+    pub channel_expiration: Option<chrono::DateTime<chrono::Utc>>,
+    /// Increases with each notification of the channel, starting at 1 with
+    /// the `sync` notification.
+    pub message_number: u64,
+    pub resource_id: String,
+    pub resource_state: ResourceState,
+    pub resource_uri: String,
+    /// What changed in the resource, from the `X-Goog-Changed` header.
+    pub changed: Vec<String>,
+}
+
+impl Notification {
+    /// Parse the headers of a push notification.
+    pub fn from_headers(headers: &http::HeaderMap) -> Result<Self> {
+        let header = |name: &str| -> Result<String> {
+            match headers.get(name) {
+                Some(v) => Ok(v.to_str()?.to_string()),
+                None => Ok(String::new()),
+            }
+        };
+        let required = |name: &str| -> Result<String> {
+            let value = header(name)?;
+            if value.is_empty() {
+                bail!("the notification has no {} header", name);
+            }
+            Ok(value)
+        };
+
+        let channel_expiration = match header("x-goog-channel-expiration")?.as_str() {
+            "" => None,
+            e => Some(
+                chrono::DateTime::parse_from_rfc2822(e)
+                    .map_err(|err| anyhow!("invalid channel expiration {:?}: {}", e, err))?
+                    .with_timezone(&chrono::Utc),
+            ),
+        };
+        let message_number = match header("x-goog-message-number")?.as_str() {
+            "" => 0,
+            n => n.parse()?,
+        };
+
+        Ok(Notification {
+            channel_id: required("x-goog-channel-id")?,
+            channel_token: header("x-goog-channel-token")?,
+            channel_expiration,
+            message_number,
+            resource_id: required("x-goog-resource-id")?,
+            resource_state: ResourceState::parse(&required("x-goog-resource-state")?),
+            resource_uri: header("x-goog-resource-uri")?,
+            changed: header("x-goog-changed")?
+                .split(',')
+                .map(|c| c.trim().to_string())
+                .filter(|c| !c.is_empty())
+                .collect(),
+        })
+    }
+}
+
+fn expiration(channel: &crate::types::Channel) -> Option<chrono::DateTime<chrono::Utc>> {
+    use chrono::TimeZone;
+
+    if channel.expiration <= 0 {
+        return None;
+    }
+    chrono::Utc
+        .timestamp_millis_opt(channel.expiration)
+        .single()
+}
+
+fn to_chrono(d: std::time::Duration) -> chrono::Duration {
+    chrono::Duration::from_std(d).unwrap_or_else(|_| chrono::Duration::max_value())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+"#;
@@ -101,10 +101,22 @@ pub fn generate_files(
                     a("#[async_recursion::async_recursion]");
                 }
 
+                // Streams are returned directly, rather than from an async function.
+                let asyncness = if response_type.starts_with("impl ") {
+                    ""
+                } else {
+                    "async "
+                };
+
                 if bounds.is_empty() {
-                    a(&format!("pub async fn {}(", fn_name,));
+                    a(&format!("pub {}fn {}(", asyncness, fn_name,));
                 } else {
-                    a(&format!("pub async fn {}<{}>(", fn_name, bounds.join(", ")));
+                    a(&format!(
+                        "pub {}fn {}<{}>(",
+                        asyncness,
+                        fn_name,
+                        bounds.join(", ")
+                    ));
                 }
                 a("&self,");
 
@@ -116,7 +128,11 @@ pub fn generate_files(
                     a(&format!("body: {}", bp));
                 }
 
-                a(&format!(") -> Result<{}> {{", response_type));
+                if response_type.starts_with("impl ") {
+                    a(&format!(") -> {} {{", response_type));
+                } else {
+                    a(&format!(") -> Result<{}> {{", response_type));
+                }
 
                 a(template);
 
@@ -422,6 +438,103 @@ pub fn generate_files(
                 );
             }
 
+            // MailChimp paginates with `count` and `offset` and returns the total
+            // number of items with each page, so we can get all the pages as well.
+            if proper_name == "MailChimp"
+                && http::Method::GET == m
+                && fn_params_str.iter().any(|f| f == "count: i64,")
+                && fn_params_str.iter().any(|f| f == "offset: i64,")
+            {
+                if let Some((items_type, items_property)) = get_mailchimp_page_items(ts, &tid)? {
+                    let (fn_params_str, query_params) =
+                        get_fn_params(ts, o, parameters, true, op.parameters.clone(), proper_name)?;
+
+                    let tmp = parse(p)?;
+                    let template = tmp.compile(query_params);
+
+                    let mut all_fn_name = get_mailchimp_all_fn_name(&fn_name, p);
+                    if fn_names.contains(&(all_fn_name.clone() + &tag)) {
+                        all_fn_name = format!("{}_all", all_fn_name);
+                    }
+                    fn_names.push(all_fn_name.clone() + &tag);
+
+                    print_fn(
+                        &get_fn_docs_all(o, m, p, &fn_name)?,
+                        &bounds,
+                        &fn_params_str,
+                        &body_param,
+                        &items_type,
+                        &template,
+                        &get_mailchimp_fn_inner(&response_type, &items_property, false),
+                        &all_fn_name,
+                    );
+
+                    let stream_fn_name = format!("{}_stream", all_fn_name);
+                    fn_names.push(stream_fn_name.clone() + &tag);
+
+                    print_fn(
+                        &get_fn_docs_stream(o, m, p, &fn_name)?,
+                        &bounds,
+                        &fn_params_str,
+                        &body_param,
+                        &format!(
+                            "impl futures::Stream<Item = Result<{}>> + '_",
+                            items_type.trim_start_matches("Vec<").trim_end_matches('>')
+                        ),
+                        &template,
+                        &get_mailchimp_fn_inner(&response_type, &items_property, true),
+                        &stream_fn_name,
+                    );
+                }
+            }
+
+            // Slack paginates with `cursor` and `limit` and returns the cursor of
+            // the next page with each page, so we can get all the pages as well.
+            if proper_name == "Slack"
+                && http::Method::GET == m
+                && fn_params_str.iter().any(|f| f == "cursor: &str,")
+            {
+                if let Some((item_type, items_property)) = get_slack_cursor_items(api, o, ts, &tid)?
+                {
+                    let (fn_params_str, query_params) =
+                        get_fn_params(ts, o, parameters, true, op.parameters.clone(), proper_name)?;
+
+                    let tmp = parse(p)?;
+                    let template = tmp.compile(query_params);
+
+                    let mut all_fn_name = get_slack_all_fn_name(&fn_name, p);
+                    if fn_names.contains(&(all_fn_name.clone() + &tag)) {
+                        all_fn_name = format!("{}_pages", all_fn_name);
+                    }
+                    fn_names.push(all_fn_name.clone() + &tag);
+
+                    print_fn(
+                        &get_fn_docs_all(o, m, p, &fn_name)?,
+                        &bounds,
+                        &fn_params_str,
+                        &body_param,
+                        &format!("Vec<{}>", item_type),
+                        &template,
+                        &get_slack_fn_inner(&item_type, &items_property, false),
+                        &all_fn_name,
+                    );
+
+                    let stream_fn_name = format!("{}_stream", all_fn_name);
+                    fn_names.push(stream_fn_name.clone() + &tag);
+
+                    print_fn(
+                        &get_fn_docs_stream(o, m, p, &fn_name)?,
+                        &bounds,
+                        &fn_params_str,
+                        &body_param,
+                        &format!("impl futures::Stream<Item = Result<{}>> + '_", item_type),
+                        &template,
+                        &get_slack_fn_inner(&item_type, &items_property, true),
+                        &stream_fn_name,
+                    );
+                }
+            }
+
             // Add this to our map of functions based on the tag name.
             tag_files.insert(tag, out.to_string());
 
@@ -1221,6 +1334,284 @@ fn get_fn_docs_all(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) ->
     Ok(out.trim().to_string())
 }
 
+/*
+ * For MailChimp, the pagination values are passed _in_ the resulting
+ * struct next to `total_items`, so find the property with the data.
+ */
+fn get_mailchimp_page_items(
+    ts: &mut TypeSpace,
+    tid: &crate::TypeId,
+) -> Result<Option<(String, String)>> {
+    let mut et = match ts.id_to_entry.get(tid) {
+        Some(et) => et,
+        None => return Ok(None),
+    };
+
+    if let crate::TypeDetails::NamedType(id, _) = &et.details {
+        et = ts.id_to_entry.get(id).unwrap();
+    }
+
+    if let crate::TypeDetails::Object(p, _) = &et.details {
+        if p.get("total_items").is_none() {
+            return Ok(None);
+        }
+
+        let mut items = Vec::new();
+        for (n, id) in p {
+            let rt = ts.render_type(id, false)?;
+            if rt.starts_with("Vec<") && n != "_links" {
+                items.push((rt, to_snake_case(n)));
+            }
+        }
+
+        // Only paginate if we know for sure which property has the data.
+        if items.len() == 1 {
+            return Ok(items.pop());
+        }
+    }
+
+    Ok(None)
+}
+
+/*
+ * For Slack, the cursor of the next page is passed _in_ the resulting struct,
+ * in `response_metadata.next_cursor`, so find the property with the data.
+ * Returns the type of one item of it, and the name of the property.
+ */
+fn get_slack_cursor_items(
+    api: &openapiv3::OpenAPI,
+    o: &openapiv3::Operation,
+    ts: &mut TypeSpace,
+    tid: &crate::TypeId,
+) -> Result<Option<(String, String)>> {
+    let mut et = match ts.id_to_entry.get(tid) {
+        Some(et) => et,
+        None => return Ok(None),
+    };
+
+    if let crate::TypeDetails::NamedType(id, _) = &et.details {
+        et = ts.id_to_entry.get(id).unwrap();
+    }
+
+    if let crate::TypeDetails::Object(p, _) = &et.details {
+        let mut items = Vec::new();
+        for (n, id) in p {
+            let rt = ts.render_type(id, false)?;
+            // Some responses model `response_metadata` as a list, and the
+            // history of a conversation lists the timestamps of its actions
+            // next to its messages.
+            if rt.starts_with("Vec<") && n != "response_metadata" && !n.ends_with("_ts") {
+                items.push((id.clone(), n.to_string()));
+            }
+        }
+
+        // Only paginate if we know for sure which property has the data.
+        if items.len() == 1 {
+            let (id, n) = items.pop().unwrap();
+            return Ok(get_slack_item_type(api, o, ts, &n, &id)?.map(|t| (t, n)));
+        }
+    }
+
+    Ok(None)
+}
+
+/*
+ * Returns the type of one item of the `property` list of a Slack response.
+ *
+ * The Slack spec describes some objects, like `objs_conversation` and
+ * `objs_user`, as `items` without a type, so a list of them renders as a list
+ * of lists, and the items of other lists have no type at all, so they render
+ * as strings. The first ones are one object per item, and the others are kept
+ * as JSON values.
+ */
+fn get_slack_item_type(
+    api: &openapiv3::OpenAPI,
+    o: &openapiv3::Operation,
+    ts: &mut TypeSpace,
+    property: &str,
+    id: &crate::TypeId,
+) -> Result<Option<String>> {
+    let item_id = match get_array_item(ts, id) {
+        Some(item_id) => item_id,
+        None => return Ok(None),
+    };
+
+    let response = match o.responses.responses.first().map(|(_, r)| r.item()) {
+        Some(Ok(response)) => response,
+        _ => return Ok(None),
+    };
+    let schema = match response
+        .content
+        .get("application/json")
+        .and_then(|mt| mt.schema.as_ref())
+        .map(|s| s.item())
+    {
+        Some(Ok(schema)) => schema,
+        _ => return Ok(None),
+    };
+    let list = match &schema.schema_kind {
+        openapiv3::SchemaKind::Type(openapiv3::Type::Object(o)) => o.properties.get(property),
+        openapiv3::SchemaKind::Any(a) => a.properties.get(property),
+        _ => None,
+    };
+    let items = match list.and_then(|l| get_slack_schema(api, l)) {
+        Some(l) => match &l.schema_kind {
+            openapiv3::SchemaKind::Type(openapiv3::Type::Array(a)) => a.items.as_ref(),
+            openapiv3::SchemaKind::Any(a) => a.items.as_ref(),
+            _ => None,
+        },
+        None => None,
+    };
+
+    match items.and_then(|i| get_slack_schema(api, i)).map(|i| &i.schema_kind) {
+        Some(openapiv3::SchemaKind::Any(a)) if a.items.is_some() => {
+            let item_id = match get_array_item(ts, &item_id) {
+                Some(item_id) => item_id,
+                None => return Ok(Some("serde_json::Value".to_string())),
+            };
+            let mut et = ts.id_to_entry.get(&item_id).unwrap();
+            if let crate::TypeDetails::NamedType(id, _) = &et.details {
+                et = ts.id_to_entry.get(id).unwrap();
+            }
+            match &et.details {
+                // The members of an `anyOf` all get flattened into its
+                // struct, which can not deserialize the fields they have in
+                // common, so use the first one, the most general.
+                crate::TypeDetails::AnyOf(ids, _) => Ok(Some(ts.render_type(&ids[0], false)?)),
+                crate::TypeDetails::Object(..) => Ok(Some(ts.render_type(&item_id, false)?)),
+                _ => Ok(Some("serde_json::Value".to_string())),
+            }
+        }
+        Some(openapiv3::SchemaKind::Any(_)) | None => Ok(Some("serde_json::Value".to_string())),
+        Some(_) => Ok(Some(ts.render_type(&item_id, false)?)),
+    }
+}
+
+/*
+ * Returns the type of the items of a list.
+ */
+fn get_array_item(ts: &TypeSpace, id: &crate::TypeId) -> Option<crate::TypeId> {
+    let mut et = ts.id_to_entry.get(id)?;
+    if let crate::TypeDetails::NamedType(id, _) = &et.details {
+        et = ts.id_to_entry.get(id)?;
+    }
+
+    match &et.details {
+        crate::TypeDetails::Array(id, _) => Some(id.clone()),
+        _ => None,
+    }
+}
+
+/*
+ * Returns a schema, or the component schema it references.
+ */
+fn get_slack_schema<'a>(
+    api: &'a openapiv3::OpenAPI,
+    s: &'a openapiv3::ReferenceOr<Box<openapiv3::Schema>>,
+) -> Option<&'a openapiv3::Schema> {
+    match s {
+        openapiv3::ReferenceOr::Item(i) => Some(i),
+        openapiv3::ReferenceOr::Reference { reference } => api
+            .components
+            .as_ref()?
+            .schemas
+            .get(reference.trim_start_matches("#/components/schemas/"))?
+            .item()
+            .ok(),
+    }
+}
+
+/*
+ * The name of the function that gets all the pages of a MailChimp page function.
+ */
+fn get_mailchimp_all_fn_name(fn_name: &str, p: &str) -> String {
+    let mut all_fn_name = if fn_name == "get" {
+        return "get_all".to_string();
+    } else if fn_name.starts_with("get_all") {
+        format!("{}_pages", fn_name)
+    } else if let Some(rest) = fn_name.strip_prefix("get_") {
+        format!("get_all_{}", rest)
+    } else {
+        format!("get_all_{}", fn_name)
+    };
+
+    // The page functions have their trailing s trimmed, so use the
+    // path to know if we are getting a collection of things.
+    if p.ends_with('s') && !all_fn_name.ends_with('s') {
+        all_fn_name.push('s');
+    }
+
+    all_fn_name
+}
+
+/*
+ * Get all the pages of a MailChimp `count` and `offset` paginated request.
+ */
+fn get_mailchimp_fn_inner(response_type: &str, pagination_property: &str, stream: bool) -> String {
+    let ret = if stream {
+        "resp"
+    } else {
+        "futures::TryStreamExt::try_collect(resp).await"
+    };
+
+    format!(
+        r#"let resp = crate::utils::offset_pages(&url, move |url| async move {{
+            let resp: {} = self.client.get(&url, None).await?;
+            Ok(resp.{})
+        }});
+
+        // Return our response data.
+        {}"#,
+        response_type, pagination_property, ret
+    )
+}
+
+/*
+ * The name of the function that gets all the pages of a Slack cursor function,
+ * from the name of the Slack method.
+ */
+fn get_slack_all_fn_name(fn_name: &str, p: &str) -> String {
+    if fn_name == "list" {
+        return "list_all".to_string();
+    }
+
+    let method = p.rsplit('.').next().unwrap_or(fn_name);
+    format!("{}_all", to_snake_case(method))
+}
+
+/*
+ * Get all the pages of a Slack `cursor` paginated request.
+ */
+fn get_slack_fn_inner(item_type: &str, pagination_property: &str, stream: bool) -> String {
+    let ret = if stream {
+        "resp"
+    } else {
+        "futures::TryStreamExt::try_collect(resp).await"
+    };
+
+    format!(
+        r#"let resp = crate::utils::cursor_pages(&url, move |url| async move {{
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<{}> = crate::utils::cursor_items(&mut page, "{}")?;
+            Ok((next_cursor, items))
+        }});
+
+        // Return our response data.
+        {}"#,
+        item_type, pagination_property, ret
+    )
+}
+
+fn get_fn_docs_stream(o: &openapiv3::Operation, m: &str, p: &str, fn_name: &str) -> Result<String> {
+    let docs = get_fn_docs_all(o, m, p, fn_name)?;
+
+    Ok(docs.replace(
+        "this function returns all the pages of the request at once.",
+        "this function returns a stream of all the items, requesting the pages as they are needed.",
+    ))
+}
+
 fn is_page_param(s: &str, proper_name: &str) -> bool {
     s == "page"
         || s == "per_page"
@@ -1237,6 +1628,8 @@ fn is_page_param(s: &str, proper_name: &str) -> bool {
         || s == "ending_before"
         || (s == "after" && proper_name == "Okta")
         || (s == "starting_after" && proper_name == "Stripe")
+        || ((s == "count" || s == "offset") && proper_name == "MailChimp")
+        || (s == "cursor" && proper_name == "Slack")
 }
 
 fn is_google_unnecessary_param(proper_name: &str, s: &str) -> bool {
@@ -49,11 +49,76 @@ where
     Ok(serde_json::from_reader(f)?)
 }
 
+/*
+ * A schema with both `type: object` and an `allOf` or `oneOf` is parsed by
+ * openapiv3 as an object without properties, and the composition is silently
+ * dropped. Zoom describes most of its responses this way, so when the object
+ * has no properties of its own, drop the type to keep the composition.
+ *
+ * `anyOf` is left alone: outside of Stripe it is generated as if it was an
+ * `allOf`, which would require the fields of every member.
+ */
+fn merge_typed_compositions(v: &mut serde_yaml::Value) {
+    match v {
+        serde_yaml::Value::Mapping(m) => {
+            let key = |k: &str| serde_yaml::Value::String(k.to_string());
+            if m.get(&key("type")) == Some(&key("object"))
+                && !m.contains_key(&key("properties"))
+                && (m.contains_key(&key("allOf")) || m.contains_key(&key("oneOf")))
+            {
+                m.remove(&key("type"));
+            }
+
+            for (_, x) in m.iter_mut() {
+                merge_typed_compositions(x);
+            }
+        }
+        serde_yaml::Value::Sequence(s) => {
+            for x in s.iter_mut() {
+                merge_typed_compositions(x);
+            }
+        }
+        _ => {}
+    }
+}
+
+/*
+ * Returns true if a schema, or the component schema it references, is an
+ * `allOf`, `anyOf` or `oneOf`.
+ */
+fn is_composition(api: &OpenAPI, s: &openapiv3::ReferenceOr<openapiv3::Schema>) -> bool {
+    let schema = match s {
+        openapiv3::ReferenceOr::Item(i) => i,
+        openapiv3::ReferenceOr::Reference { reference } => {
+            match api.components.as_ref().and_then(|c| {
+                c.schemas
+                    .get(reference.trim_start_matches("#/components/schemas/"))
+            }) {
+                Some(openapiv3::ReferenceOr::Item(i)) => i,
+                _ => return false,
+            }
+        }
+    };
+
+    matches!(
+        schema.schema_kind,
+        openapiv3::SchemaKind::AllOf { .. }
+            | openapiv3::SchemaKind::AnyOf { .. }
+            | openapiv3::SchemaKind::OneOf { .. }
+    )
+}
+
 fn load_api<P>(p: P) -> Result<OpenAPI>
 where
     P: AsRef<Path>,
 {
-    let api: OpenAPI = load(p)?;
+    /*
+     * Go through a YAML value, which keeps the order of the document, to fix up
+     * the schemas openapiv3 would misread.
+     */
+    let mut spec: serde_yaml::Value = load(p)?;
+    merge_typed_compositions(&mut spec);
+    let api: OpenAPI = serde_yaml::from_value(spec)?;
 
     if api.openapi != "3.0.3" {
         /*
@@ -886,6 +951,17 @@ impl TypeSpace {
         out.trim().to_string()
     }
 
+    /*
+     * Returns true if the type is rendered as a struct without any field.
+     */
+    fn is_empty_object(&self, tid: &TypeId) -> bool {
+        match self.id_to_entry.get(tid).map(|te| &te.details) {
+            Some(TypeDetails::Object(omap, _)) => omap.is_empty(),
+            Some(TypeDetails::NamedType(itid, _)) => self.is_empty_object(itid),
+            _ => false,
+        }
+    }
+
     fn render_type(&self, tid: &TypeId, in_mod: bool) -> Result<String> {
         if let Some(te) = self.id_to_entry.get(tid) {
             match &te.details {
@@ -1913,9 +1989,17 @@ impl TypeSpace {
                 omap.sort_unstable();
                 omap.dedup();
 
+                // Keep whether it is nullable, so a nullable property is
+                // optional, but not the docs of the property that named it.
                 Ok((
                     Some(one_of_name),
-                    TypeDetails::OneOf(omap, Default::default()),
+                    TypeDetails::OneOf(
+                        omap,
+                        openapiv3::SchemaData {
+                            nullable: s.schema_data.nullable,
+                            ..Default::default()
+                        },
+                    ),
                 ))
             }
             openapiv3::SchemaKind::AnyOf { any_of } => {
@@ -2287,14 +2371,35 @@ fn gen(
     a("");
     if proper_name == "GitHub" {
         a("pub mod auth;");
+        a("pub mod commit;");
         a(r#"#[cfg(feature = "httpcache")]"#);
         a(r#"#[cfg_attr(docsrs, doc(cfg(feature = "httpcache")))]"#);
         a("pub mod http_cache;");
     }
-    if proper_name == "Google Drive"
+    if client::google_batch_path(proper_name).is_some() {
+        a("pub mod batch;");
+    }
+    if client::google_channels_stop(proper_name).is_some() {
+        a("pub mod push;");
+    }
+    if proper_name == "SendGrid" {
+        a("pub mod inbound;");
+    }
+    if proper_name == "Slack" {
+        a("pub mod blocks;");
+        a("pub mod error;");
+        a("pub mod incoming;");
+        a("pub mod socket_mode;");
+    }
+    if proper_name == "Google Admin"
+        || proper_name == "Google Calendar"
+        || proper_name == "Google Drive"
         || proper_name == "Google Sheets"
         || proper_name == "SendGrid"
         || proper_name == "Rev.ai"
+        || proper_name == "Okta"
+        || proper_name == "MailChimp"
+        || proper_name == "Slack"
     {
         a("pub mod traits;");
     }
@@ -2392,6 +2497,21 @@ fn gen(
     a("}");
     a("");
 
+    a(
+        r#"/// Limits applied by functions that return all the pages of a request at once
+/// by following `Link` headers.
+#[derive(Debug, Clone, Default)]
+pub struct Pagination {
+    /// Stop once this many items have been fetched.
+    pub max_items: Option<usize>,
+    /// Stop once this many pages have been fetched.
+    pub max_pages: Option<usize>,
+    /// The number of items to request per page.
+    pub page_size: Option<u32>,
+}"#,
+    );
+    a("");
+
     a("");
 
     // Print the client template.
@@ -2485,6 +2605,16 @@ fn gen(
         }
     }
 
+    if client::google_batch_path(proper_name).is_some() {
+        a(
+            r#"/// Return a batch, to send up to 100 requests together in a single request.
+               pub fn batch(&self) -> batch::Batch {
+                    batch::Batch::new(self.clone())
+               }"#,
+        );
+        a("");
+    }
+
     a("}");
 
     Ok(out)
@@ -2960,6 +3090,13 @@ fn main() -> Result<()> {
 
                         let object_name = format!("{} response", name);
                         let id = ts.select(Some(&clean_name(&object_name)), s, "")?;
+                        if ts.is_empty_object(&id) && is_composition(&api, s) {
+                            bail!(
+                                "response {} would be generated as the empty struct {}",
+                                rn,
+                                ts.render_type(&id, true)?
+                            );
+                        }
 
                         // Insert the named type for our reference.
                         // DO NOT CLEAN THE NAME HERE.
@@ -3118,6 +3255,16 @@ fn main() -> Result<()> {
                                         let id =
                                             ts.select(Some(&clean_name(&object_name)), s, "")?;
                                         let rt = ts.render_type(&id, false)?;
+                                        // Every field the server sends would be
+                                        // dropped.
+                                        if ts.is_empty_object(&id) && is_composition(&api, s) {
+                                            bail!(
+                                                "the response of {} would be generated as the \
+                                                 empty struct {}",
+                                                od,
+                                                rt
+                                            );
+                                        }
                                         res.push(format!("{} {:?}", rt, id));
                                     }
                                 } else {
@@ -3218,6 +3365,114 @@ yup-oauth2 = "^5""#
                     .to_string();
             }
 
+            let mut futures_lib = "".to_string();
+            if proper_name == "Okta"
+                || proper_name == "MailChimp"
+                || proper_name == "Google Admin"
+                || proper_name == "Google Drive"
+                || proper_name == "Slack"
+                || proper_name == "GitHub"
+            {
+                futures_lib = r#"
+futures = "0.3""#
+                    .to_string();
+            }
+
+            let mut archive_lib = "".to_string();
+            if proper_name == "MailChimp" {
+                archive_lib = r#"
+flate2 = "1"
+tar = "0.4""#
+                    .to_string();
+            }
+
+            let mut batch_lib = "".to_string();
+            if client::google_batch_path(&proper_name).is_some() {
+                batch_lib = r#"
+task-local-extensions = "0.1""#
+                    .to_string();
+            }
+
+            let mut sendgrid_lib = "".to_string();
+            if proper_name == "SendGrid" {
+                // Mail attachments are base64 encoded, and inbound mail is
+                // decoded from the charset of each field, or parsed from the
+                // raw MIME message.
+                sendgrid_lib = r#"
+base64 = "^0.13"
+encoding_rs = "0.8"
+mail-parser = "0.9""#
+                    .to_string();
+            }
+
+            let mut github_lib = "".to_string();
+            if proper_name == "GitHub" {
+                // Blobs are created from base64 encoded contents.
+                github_lib = r#"
+base64 = "^0.13""#
+                    .to_string();
+            }
+
+            let mut slack_lib = "".to_string();
+            let mut native_tls_features = "".to_string();
+            let mut rustls_tls_features = "".to_string();
+            let mut reqwest_features = "".to_string();
+            if proper_name == "Slack" {
+                // Socket Mode connects over a WebSocket, with the TLS backend
+                // of the client.
+                slack_lib = r#"
+tokio-tungstenite = "0.17""#
+                    .to_string();
+                native_tls_features = r#", "tokio-tungstenite/native-tls""#.to_string();
+                rustls_tls_features = r#", "tokio-tungstenite/rustls-tls-webpki-roots""#.to_string();
+                // Files are uploaded from a stream of their contents.
+                reqwest_features = r#", "stream""#.to_string();
+            }
+
+            let mut groups_settings_feature = "".to_string();
+            let mut groups_settings_lib = "".to_string();
+            if proper_name == "Google Admin" {
+                // The directory export can join in the settings of each group.
+                groups_settings_feature = r#"
+# export the settings of each group with the directory
+groups-settings = ["google-groups-settings"]"#
+                    .to_string();
+                groups_settings_lib = r#"
+google-groups-settings = { version = "0.4", path = "../groups-settings", optional = true }"#
+                    .to_string();
+            }
+
+            let mut test_lib = "".to_string();
+            if proper_name == "GitHub"
+                || proper_name == "Google Admin"
+                || proper_name == "Google Calendar"
+                || proper_name == "Google Drive"
+                || proper_name == "MailChimp"
+                || proper_name == "SendGrid"
+                || proper_name == "Slack"
+            {
+                // The tests serve the API from a mock server.
+                test_lib = r#"
+wiremock = "0.6""#
+                    .to_string();
+            }
+            if proper_name == "MailChimp" {
+                // Waiting for a batch is tested without waiting for real.
+                test_lib.push_str(
+                    r#"
+tokio = { version = "1.20.1", features = ["test-util"] }"#,
+                );
+            }
+
+            let mut rustls_ring = r#", "ring""#;
+            let mut ring_optional = ", optional = true";
+            if proper_name == "SendGrid" || proper_name == "Slack" {
+                // Webhooks are verified with ring, whichever TLS backend is
+                // used.
+                rustls_ring = "";
+                ring_optional = "";
+            }
+
             let mut toml = root.clone();
             toml.push("Cargo.toml");
             let tomlout = format!(
@@ -3235,8 +3490,8 @@ license = "MIT"
 default = ["rustls-tls"]
 # enable etag-based http_cache functionality
 httpcache = ["dirs"]
-native-tls = ["reqwest/default-tls", "openssl"]
-rustls-tls = ["reqwest/rustls-tls", "ring", "pem"]
+native-tls = ["reqwest/default-tls", "openssl"{}]
+rustls-tls = ["reqwest/rustls-tls"{}, "pem"{}]{}
 
 [dependencies]
 anyhow = "1"
@@ -3251,29 +3506,49 @@ mime = "0.3"
 openssl = {{ version = "0.10", default-features = false, optional = true }}
 pem = {{ version = "1.1.0",  default-features = false, optional = true }}
 percent-encoding = "2.1"
-reqwest = {{ version = "0.11.11", default-features = false, features = ["json", "multipart"] }}
+reqwest = {{ version = "0.11.11", default-features = false, features = ["json", "multipart"{}] }}
 reqwest-conditional-middleware = "0.1.0"
 reqwest-middleware = "0.1.5"
 reqwest-retry = "0.1.4"
 reqwest-tracing = {{ version = "0.3.0", features = ["opentelemetry_0_17"] }}
-ring = {{ version = "0.16", default-features = false, optional = true }}
+ring = {{ version = "0.16", default-features = false{} }}
 schemars = {{ version = "0.8", features = ["bytes", "chrono", "url", "uuid1"] }}
 serde = {{ version = "1", features = ["derive"] }}
 serde_json = "1"
 serde_urlencoded = "^0.7"
-url = {{ version = "2", features = ["serde"] }}{}{}
+url = {{ version = "2", features = ["serde"] }}{}{}{}{}{}{}{}{}{}
 tokio = {{ version = "1.20.1", features = ["full"] }}
 
 [dev-dependencies]
 base64 = "^0.13"
 dirs = "^3.0.2"
-nom_pem = "4"
+nom_pem = "4"{}
 
 [package.metadata.docs.rs]
 all-features = true
 rustdoc-args = ["--cfg", "docsrs"]
 "#,
-                name, description, version, name, output_dir, uuid_lib, yup_oauth2_lib
+                name,
+                description,
+                version,
+                name,
+                output_dir,
+                native_tls_features,
+                rustls_ring,
+                rustls_tls_features,
+                groups_settings_feature,
+                reqwest_features,
+                ring_optional,
+                uuid_lib,
+                yup_oauth2_lib,
+                futures_lib,
+                archive_lib,
+                batch_lib,
+                groups_settings_lib,
+                sendgrid_lib,
+                slack_lib,
+                github_lib,
+                test_lib
             );
             save(&toml, tomlout.as_str())?;
 
@@ -3359,6 +3634,26 @@ rustdoc-args = ["--cfg", "docsrs"]
             utilsrs.push("utils.rs");
             save(utilsrs, utils.as_str())?;
 
+            /*
+             * Create the Rust batch module, for the APIs that support it:
+             */
+            if let Some(batch_path) = client::google_batch_path(&proper_name) {
+                let batch = client::generate_google_batch(batch_path);
+                let mut batchrs = src.clone();
+                batchrs.push("batch.rs");
+                save(batchrs, batch.as_str())?;
+            }
+
+            /*
+             * Create the Rust push module, for the APIs with push notifications:
+             */
+            if let Some(channels_stop) = client::google_channels_stop(&proper_name) {
+                let push = client::generate_google_push(channels_stop);
+                let mut pushrs = src.clone();
+                pushrs.push("push.rs");
+                save(pushrs, push.as_str())?;
+            }
+
             /*
              * Create the Rust source types file containing the generated types:
              */
@@ -3436,3 +3731,77 @@ impl {} {{
 
     Ok(())
 }
+
+#[cfg(test)]
+mod test {
+    use anyhow::Result;
+
+    use super::{merge_typed_compositions, TypeSpace};
+
+    fn schema(yaml: &str) -> Result<openapiv3::Schema> {
+        let mut spec: serde_yaml::Value = serde_yaml::from_str(yaml)?;
+        merge_typed_compositions(&mut spec);
+        Ok(serde_yaml::from_value(spec)?)
+    }
+
+    #[test]
+    fn typed_all_of() -> Result<()> {
+        let s = schema(
+            r#"
+title: Meeting List
+type: object
+allOf:
+  - type: object
+    properties:
+      page_size:
+        type: integer
+  - type: object
+    properties:
+      meetings:
+        type: array
+        items:
+          type: string
+"#,
+        )?;
+        assert!(matches!(s.schema_kind, openapiv3::SchemaKind::AllOf { .. }));
+
+        let mut ts = TypeSpace::new();
+        let id = ts.select_schema(Some("meetings response"), &s, "", "")?;
+        assert_eq!(ts.render_type(&id, true)?, "MeetingsResponseAllOf");
+        assert!(!ts.is_empty_object(&id));
+
+        // An object with properties of its own is kept as it is.
+        let s = schema(
+            r#"
+type: object
+properties:
+  id:
+    type: string
+allOf:
+  - type: object
+"#,
+        )?;
+        assert!(matches!(s.schema_kind, openapiv3::SchemaKind::Type(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_all_of() -> Result<()> {
+        let s = schema(
+            r#"
+allOf:
+  - type: object
+    title: first
+  - type: object
+    title: second
+"#,
+        )?;
+
+        let mut ts = TypeSpace::new();
+        ts.select_schema(Some("empty response"), &s, "", "")?;
+        assert!(crate::types::generate_types(&mut ts, "Zoom").is_err());
+
+        Ok(())
+    }
+}
@@ -40,17 +40,17 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                     );
                     a(&p);
                 }
-                TypeDetails::OneOf(omap, _) => a(&do_one_of_type(ts, omap, sn)),
+                TypeDetails::OneOf(omap, _) => a(&do_one_of_type(ts, omap, sn)?),
                 TypeDetails::AnyOf(omap, _) => {
                     // When Stripe uses anyof what they really mean is one of.
                     // Because something can not both be a string and an object.
                     if proper_name == "Stripe" {
-                        a(&do_one_of_type(ts, omap, sn));
+                        a(&do_one_of_type(ts, omap, sn)?);
                     } else {
-                        a(&do_all_of_type(ts, omap, sn));
+                        a(&do_all_of_type(ts, omap, sn)?);
                     }
                 }
-                TypeDetails::AllOf(omap, _) => a(&do_all_of_type(ts, omap, sn)),
+                TypeDetails::AllOf(omap, _) => a(&do_all_of_type(ts, omap, sn)?),
                 TypeDetails::Object(omap, schema_data) => {
                     /*
                      * TODO: This breaks things so ignore for now.
@@ -136,6 +136,13 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
                             if prop == "next" {
                                 rt = "String".to_string();
                             }
+                            // Cells can hold numbers and booleans, not only strings.
+                            if prop == "values"
+                                && rt == "Vec<Vec<String>>"
+                                && proper_name == "Google Sheets"
+                            {
+                                rt = "Vec<Vec<CellValue>>".to_string();
+                            }
                             if prop == "ref"
                                 || prop == "type"
                                 || prop == "self"
@@ -307,10 +314,20 @@ pub fn generate_types(ts: &mut TypeSpace, proper_name: &str) -> Result<String> {
         }
     }
 
+    if proper_name == "Google Sheets" {
+        a(GOOGLE_SHEETS_CELL_VALUE_TEMPLATE);
+    }
+
     Ok(out.to_string())
 }
 
-fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
+fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Result<String> {
+    // An empty struct matches any object, so the enum would never hold
+    // anything else.
+    if omap.iter().all(|tid| ts.is_empty_object(tid)) {
+        bail!("one of type {} would only have empty structs", sn);
+    }
+
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -493,10 +510,15 @@ fn do_one_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
         a("");
     }
 
-    out
+    Ok(out)
 }
 
-fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> String {
+fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Result<String> {
+    // Flattening only empty structs would drop every field of the object.
+    if omap.iter().all(|tid| ts.is_empty_object(tid)) {
+        bail!("all of type {} would only flatten empty structs", sn);
+    }
+
     let mut out = String::new();
 
     let mut a = |s: &str| {
@@ -560,5 +582,195 @@ fn do_all_of_type(ts: &mut TypeSpace, omap: &[crate::TypeId], sn: String) -> Str
     a("}");
     a("");
 
-    out
+    Ok(out)
+}
+
+const GOOGLE_SHEETS_CELL_VALUE_TEMPLATE: &str = r##"/// The value of a single cell.
+///
+/// Which variant a cell comes back as depends on the `ValueRenderOption` of
+/// the request: with `FormattedValue` every non-empty cell is a string, with
+/// `UnformattedValue` numbers and booleans keep their type and with `Formula`
+/// formulas are returned as strings.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub enum CellValue {
+    #[default]
+    Empty,
+    String(String),
+    Number(f64),
+    Bool(bool),
+    /// A formula error, like `#DIV/0!` or `#N/A`, from the `errorValue` of a
+    /// cell. The values API returns errors as text, which is read as a
+    /// `String` since it can not be told apart from a cell holding that text.
+    Error(String),
+}
+
+impl CellValue {
+    pub fn is_empty(&self) -> bool {
+        matches!(self, CellValue::Empty)
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            CellValue::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            CellValue::Number(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            CellValue::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for CellValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CellValue::Empty => Ok(()),
+            CellValue::String(s) | CellValue::Error(s) => s.fmt(f),
+            CellValue::Number(n) => n.fmt(f),
+            CellValue::Bool(true) => "TRUE".fmt(f),
+            CellValue::Bool(false) => "FALSE".fmt(f),
+        }
+    }
+}
+
+impl From<&str> for CellValue {
+    fn from(s: &str) -> Self {
+        s.to_string().into()
+    }
+}
+
+impl From<String> for CellValue {
+    fn from(s: String) -> Self {
+        if s.is_empty() {
+            CellValue::Empty
+        } else {
+            CellValue::String(s)
+        }
+    }
+}
+
+impl From<&ErrorValue> for CellValue {
+    fn from(e: &ErrorValue) -> Self {
+        let s = match e.type_ {
+            Some(ErrorValueType::NullValue) => "#NULL!",
+            Some(ErrorValueType::DivideByZero) => "#DIV/0!",
+            Some(ErrorValueType::Value) => "#VALUE!",
+            Some(ErrorValueType::Ref) => "#REF!",
+            Some(ErrorValueType::Name) => "#NAME?",
+            Some(ErrorValueType::Num) => "#NUM!",
+            Some(ErrorValueType::NA) => "#N/A",
+            Some(ErrorValueType::Loading) => "Loading...",
+            _ => "#ERROR!",
+        };
+        CellValue::Error(s.to_string())
+    }
+}
+
+impl From<f64> for CellValue {
+    fn from(n: f64) -> Self {
+        CellValue::Number(n)
+    }
+}
+
+impl From<i64> for CellValue {
+    fn from(n: i64) -> Self {
+        CellValue::Number(n as f64)
+    }
+}
+
+impl From<bool> for CellValue {
+    fn from(b: bool) -> Self {
+        CellValue::Bool(b)
+    }
+}
+
+impl Serialize for CellValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            // An empty string clears the cell, while null would leave it as is.
+            CellValue::Empty => serializer.serialize_str(""),
+            CellValue::String(s) | CellValue::Error(s) => serializer.serialize_str(s),
+            // Keep whole numbers whole, so they are not written as `1.0`.
+            CellValue::Number(n) if n.fract() == 0.0 && n.abs() < 9_007_199_254_740_992.0 => {
+                serializer.serialize_i64(*n as i64)
+            }
+            CellValue::Number(n) => serializer.serialize_f64(*n),
+            CellValue::Bool(b) => serializer.serialize_bool(*b),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for CellValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct CellValueVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for CellValueVisitor {
+            type Value = CellValue;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a string, number, boolean or null")
+            }
+
+            fn visit_unit<E>(self) -> Result<CellValue, E> {
+                Ok(CellValue::Empty)
+            }
+
+            fn visit_none<E>(self) -> Result<CellValue, E> {
+                Ok(CellValue::Empty)
+            }
+
+            fn visit_bool<E>(self, b: bool) -> Result<CellValue, E> {
+                Ok(CellValue::Bool(b))
+            }
+
+            fn visit_i64<E>(self, n: i64) -> Result<CellValue, E> {
+                Ok(CellValue::Number(n as f64))
+            }
+
+            fn visit_u64<E>(self, n: u64) -> Result<CellValue, E> {
+                Ok(CellValue::Number(n as f64))
+            }
+
+            fn visit_f64<E>(self, n: f64) -> Result<CellValue, E> {
+                Ok(CellValue::Number(n))
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<CellValue, E> {
+                Ok(s.into())
+            }
+
+            fn visit_string<E>(self, s: String) -> Result<CellValue, E> {
+                Ok(s.into())
+            }
+        }
+
+        deserializer.deserialize_any(CellValueVisitor)
+    }
+}
+
+impl JsonSchema for CellValue {
+    fn schema_name() -> String {
+        "CellValue".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <serde_json::Value>::json_schema(gen)
+    }
 }
+"##;
@@ -0,0 +1,361 @@
+//! Commit several files at once, with the Git Data API.
+//!
+//! ```ignore
+//! let commit = CommitBuilder::new("oxidecomputer", "reports", "main", "Update the reports")
+//!     .add_file("reports/weekly.csv", csv)
+//!     .add_file_with_mode("bin/report.sh", script, GitCreateTreeRequestMode::ExecutableBlob)
+//!     .delete_file("reports/old.csv")
+//!     .with_author("Reports Bot", "reports@example.com")
+//!     .commit(&github)
+//!     .await?;
+//! ```
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Result};
+use futures::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+use crate::types::GitCreateTreeRequestMode;
+
+/// The most blobs that are created at the same time.
+pub const MAX_CONCURRENT_BLOBS: usize = 8;
+
+/// The branch of a commit moved from the commit it was built on, so it can
+/// not be updated without losing the commits since.
+#[derive(PartialEq, Debug, Clone)]
+pub struct CommitConflict {
+    pub branch: String,
+    /// The commit the new commit was built on.
+    pub expected: String,
+    /// The commit the branch points to.
+    pub actual: String,
+}
+
+impl std::fmt::Display for CommitConflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "branch {} moved from {} to {}",
+            self.branch, self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for CommitConflict {}
+
+#[derive(Debug, Clone)]
+enum Change {
+    Write {
+        contents: Vec<u8>,
+        mode: GitCreateTreeRequestMode,
+    },
+    Delete,
+    Mode(GitCreateTreeRequestMode),
+}
+
+/// A commit of changes to several files of a branch.
+///
+/// The blobs of the files are created, then a tree on top of the tree of the
+/// parent commit, then the commit. The branch is then fast-forwarded to the
+/// commit, or force-updated with `force`.
+#[derive(Debug, Clone)]
+pub struct CommitBuilder {
+    owner: String,
+    repo: String,
+    branch: String,
+    message: String,
+    parent: String,
+    author: Option<crate::types::GitCreateCommitRequestAuthor>,
+    committer: Option<crate::types::GitCreateCommitRequestCommitter>,
+    force: bool,
+    changes: BTreeMap<String, Change>,
+}
+
+#[derive(Serialize)]
+struct TreeEntry {
+    path: String,
+    mode: GitCreateTreeRequestMode,
+    #[serde(rename = "type")]
+    type_: &'static str,
+    /// `None` deletes the file.
+    sha: Option<String>,
+}
+
+#[derive(Serialize)]
+struct TreeRequest {
+    base_tree: String,
+    tree: Vec<TreeEntry>,
+}
+
+#[derive(Deserialize)]
+struct Sha {
+    sha: String,
+}
+
+impl CommitBuilder {
+    /// A commit to `branch`, which must exist.
+    pub fn new(owner: &str, repo: &str, branch: &str, message: &str) -> Self {
+        CommitBuilder {
+            owner: owner.to_string(),
+            repo: repo.to_string(),
+            branch: branch.to_string(),
+            message: message.to_string(),
+            parent: String::new(),
+            author: None,
+            committer: None,
+            force: false,
+            changes: BTreeMap::new(),
+        }
+    }
+
+    /// Add a file, or replace the contents of an existing one.
+    pub fn add_file<C: Into<Vec<u8>>>(self, path: &str, contents: C) -> Self {
+        self.add_file_with_mode(path, contents, GitCreateTreeRequestMode::FileBlob)
+    }
+
+    /// Add a file with a mode, like `ExecutableBlob` or `SymlinkPathBlob`.
+    pub fn add_file_with_mode<C: Into<Vec<u8>>>(
+        mut self,
+        path: &str,
+        contents: C,
+        mode: GitCreateTreeRequestMode,
+    ) -> Self {
+        let contents = contents.into();
+        self.changes
+            .insert(path.to_string(), Change::Write { contents, mode });
+        self
+    }
+
+    pub fn delete_file(mut self, path: &str) -> Self {
+        self.changes.insert(path.to_string(), Change::Delete);
+        self
+    }
+
+    /// Change the mode of an existing file, without changing its contents.
+    pub fn set_mode(mut self, path: &str, mode: GitCreateTreeRequestMode) -> Self {
+        self.changes.insert(path.to_string(), Change::Mode(mode));
+        self
+    }
+
+    /// Build on this commit instead of the head of the branch.
+    ///
+    /// Unless `force` is set, the commit fails with a `CommitConflict` if the
+    /// branch moved from it.
+    pub fn with_parent(mut self, sha: &str) -> Self {
+        self.parent = sha.to_string();
+        self
+    }
+
+    /// By default, the author is the authenticated user.
+    pub fn with_author(mut self, name: &str, email: &str) -> Self {
+        self.author = Some(crate::types::GitCreateCommitRequestAuthor {
+            date: None,
+            email: email.to_string(),
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// By default, the committer is the author.
+    pub fn with_committer(mut self, name: &str, email: &str) -> Self {
+        self.committer = Some(crate::types::GitCreateCommitRequestCommitter {
+            date: None,
+            email: email.to_string(),
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// Update the branch to the commit even if it is not a fast-forward.
+    ///
+    /// The branch is still only updated if it did not move since the commit
+    /// was started.
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Create the commit, and update the branch to it.
+    ///
+    /// Fails with a `CommitConflict` if the branch moved from the parent of
+    /// the commit.
+    pub async fn commit(self, client: &crate::Client) -> Result<crate::types::GitCommit> {
+        if self.changes.is_empty() {
+            bail!("there are no changes to commit");
+        }
+        for (path, change) in &self.changes {
+            let mode = match change {
+                Change::Write { mode, .. } | Change::Mode(mode) => mode,
+                Change::Delete => continue,
+            };
+            if !matches!(
+                mode,
+                GitCreateTreeRequestMode::FileBlob
+                    | GitCreateTreeRequestMode::ExecutableBlob
+                    | GitCreateTreeRequestMode::SymlinkPathBlob
+            ) {
+                bail!("{} can not have the mode {} of a file", path, mode);
+            }
+        }
+
+        let git = client.git();
+        let head = self.head(&git).await?;
+        let parent = if self.parent.is_empty() {
+            head.to_string()
+        } else {
+            self.parent.to_string()
+        };
+        if parent != head && !self.force {
+            return Err(self.conflict(&parent, &head).into());
+        }
+
+        let base_tree = git
+            .get_commit(&self.owner, &self.repo, &parent)
+            .await?
+            .tree
+            .sha;
+        let tree = self.create_tree(client, &base_tree).await?;
+
+        let commit = git
+            .create_commit(
+                &self.owner,
+                &self.repo,
+                &crate::types::GitCreateCommitRequest {
+                    author: self.author.clone(),
+                    committer: self.committer.clone(),
+                    message: self.message.to_string(),
+                    parents: vec![parent.to_string()],
+                    signature: String::new(),
+                    tree,
+                },
+            )
+            .await?;
+
+        // A forced update is only safe if the branch did not move since we
+        // looked at it.
+        if self.force {
+            let actual = self.head(&git).await?;
+            if actual != head {
+                return Err(self.conflict(&head, &actual).into());
+            }
+        }
+
+        let update = git
+            .update_ref(
+                &self.owner,
+                &self.repo,
+                &format!("heads/{}", self.branch),
+                &crate::types::GitUpdateRefRequest {
+                    force: Some(self.force),
+                    sha: commit.sha.to_string(),
+                },
+            )
+            .await;
+        if let Err(e) = update {
+            // GitHub refuses updates that are not a fast-forward.
+            let actual = self.head(&git).await?;
+            if actual != parent {
+                return Err(self.conflict(&parent, &actual).into());
+            }
+            return Err(e);
+        }
+
+        Ok(commit)
+    }
+
+    async fn head(&self, git: &crate::git::Git) -> Result<String> {
+        let branch = git
+            .get_ref(&self.owner, &self.repo, &format!("heads/{}", self.branch))
+            .await?;
+
+        Ok(branch.object.sha)
+    }
+
+    fn conflict(&self, expected: &str, actual: &str) -> CommitConflict {
+        CommitConflict {
+            branch: self.branch.to_string(),
+            expected: expected.to_string(),
+            actual: actual.to_string(),
+        }
+    }
+
+    /// Create the blobs of the changes, and a tree of them on top of
+    /// `base_tree`.
+    async fn create_tree(&self, client: &crate::Client, base_tree: &str) -> Result<String> {
+        let git = client.git();
+        let tree = futures::stream::iter(&self.changes)
+            .map(|(path, change)| {
+                let git = &git;
+                async move {
+                    let (mode, sha) = match change {
+                        Change::Write { contents, mode } => {
+                            let blob = git
+                                .create_blob(
+                                    &self.owner,
+                                    &self.repo,
+                                    &crate::types::GitCreateBlobRequest {
+                                        content: base64::encode(contents),
+                                        encoding: "base64".to_string(),
+                                    },
+                                )
+                                .await?;
+                            (mode.clone(), Some(blob.sha))
+                        }
+                        Change::Delete => (GitCreateTreeRequestMode::FileBlob, None),
+                        Change::Mode(mode) => {
+                            let sha = self.blob_sha(git, base_tree, path).await?;
+                            (mode.clone(), Some(sha))
+                        }
+                    };
+
+                    Ok::<_, anyhow::Error>(TreeEntry {
+                        path: path.to_string(),
+                        mode,
+                        type_: "blob",
+                        sha,
+                    })
+                }
+            })
+            .buffered(MAX_CONCURRENT_BLOBS)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let url = format!(
+            "/repos/{}/{}/git/trees",
+            crate::progenitor_support::encode_path(&self.owner),
+            crate::progenitor_support::encode_path(&self.repo),
+        );
+        let body = TreeRequest {
+            base_tree: base_tree.to_string(),
+            tree,
+        };
+        let tree: Sha = client
+            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(&body)?)))
+            .await?;
+
+        Ok(tree.sha)
+    }
+
+    /// Returns the SHA of the blob at `path`, in a tree.
+    async fn blob_sha(&self, git: &crate::git::Git, tree: &str, path: &str) -> Result<String> {
+        let mut tree = tree.to_string();
+        let mut names = path.split('/').peekable();
+        while let Some(name) = names.next() {
+            let entries = git.get_tree(&self.owner, &self.repo, &tree, "").await?.tree;
+            let entry = match entries.into_iter().find(|e| e.path == name) {
+                Some(entry) => entry,
+                None => bail!("{} is not in the tree of the parent commit", path),
+            };
+            if names.peek().is_none() {
+                if entry.type_ != "blob" {
+                    bail!("{} is a {}, not a file", path, entry.type_);
+                }
+                return Ok(entry.sha);
+            }
+            tree = entry.sha;
+        }
+
+        bail!("{} is not the path of a file", path)
+    }
+}
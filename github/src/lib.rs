@@ -191,6 +191,7 @@ pub mod checks;
 pub mod code_scanning;
 /// Insight into codes of conduct for your communities.
 pub mod codes_of_conduct;
+pub mod commit;
 /// List emojis available to use on GitHub.
 pub mod emojis;
 /// Administer a GitHub enterprise.
@@ -272,6 +273,18 @@ mod progenitor_support {
     }
 }
 
+/// Limits applied by functions that return all the pages of a request at once
+/// by following `Link` headers.
+#[derive(Debug, Clone, Default)]
+pub struct Pagination {
+    /// Stop once this many items have been fetched.
+    pub max_items: Option<usize>,
+    /// Stop once this many pages have been fetched.
+    pub max_pages: Option<usize>,
+    /// The number of items to request per page.
+    pub page_size: Option<u32>,
+}
+
 /// Entrypoint for interacting with the API client.
 #[derive(Clone)]
 pub struct Client {
@@ -279,6 +292,7 @@ pub struct Client {
     agent: String,
     client: reqwest_middleware::ClientWithMiddleware,
     credentials: Option<crate::auth::Credentials>,
+    pagination: Pagination,
     #[cfg(feature = "httpcache")]
     http_cache: crate::http_cache::BoxedHttpCache,
 }
@@ -344,6 +358,7 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            pagination: Default::default(),
             http_cache,
         }
     }
@@ -365,6 +380,7 @@ impl Client {
             agent: agent.into(),
             client: http,
             credentials: credentials.into(),
+            pagination: Default::default(),
         }
     }
 
@@ -375,6 +391,13 @@ impl Client {
         self.credentials = credentials.into();
     }
 
+    /// Set the limits used by functions that return all the pages of a request at once.
+    pub fn with_pagination(&self, pagination: Pagination) -> Self {
+        let mut c = self.clone();
+        c.pagination = pagination;
+        c
+    }
+
     fn credentials(
         &self,
         authentication: crate::auth::AuthenticationConstraint,
@@ -652,20 +675,6 @@ impl Client {
         self.unfold(uri).await
     }
 
-    async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        self.request(
-            http::Method::GET,
-            &(self.host.clone() + uri),
-            None,
-            crate::utils::MediaType::Json,
-            crate::auth::AuthenticationConstraint::Unconstrained,
-        )
-        .await
-    }
-
     async fn get_pages_url<D>(
         &self,
         url: &reqwest::Url,
@@ -789,21 +798,17 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = link.as_ref().and_then(crate::utils::next_link) {
-                let url = reqwest::Url::parse(&url)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
-            }
-        }
+        let url = crate::utils::with_page_size(
+            &(self.host.clone() + uri),
+            crate::utils::PAGE_SIZE_PARAM,
+            self.pagination.page_size,
+        );
 
-        Ok(global_items)
+        crate::utils::unfold(url, &self.pagination, |url| async move {
+            let url = reqwest::Url::parse(&url)?;
+            self.get_pages_url(&url).await
+        })
+        .await
     }
 
     /// Endpoints to manage GitHub Actions using the REST API.
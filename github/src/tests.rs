@@ -1 +1,255 @@
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+use crate::{
+    commit::{CommitBuilder, CommitConflict},
+    types::GitCreateTreeRequestMode,
+};
+
+fn commit_json(sha: &str, tree: &str, parent: &str) -> String {
+    let person = serde_json::json!({
+        "name": "Bot",
+        "email": "bot@example.com",
+        "date": "2022-08-01T00:00:00Z",
+    });
+    serde_json::json!({
+        "sha": sha,
+        "node_id": "C_1",
+        "url": format!("https://api.github.com/repos/o/r/git/commits/{}", sha),
+        "html_url": format!("https://github.com/o/r/commit/{}", sha),
+        "author": person,
+        "committer": person,
+        "message": "Update",
+        "tree": { "sha": tree, "url": "" },
+        "parents": [{ "sha": parent, "url": "", "html_url": "" }],
+        "verification": {
+            "verified": false,
+            "reason": "unsigned",
+            "signature": null,
+            "payload": null,
+        },
+    })
+    .to_string()
+}
+
+fn tree_json(sha: &str, path: &str, type_: &str, mode: &str, entry_sha: &str) -> String {
+    serde_json::json!({
+        "sha": sha,
+        "url": "",
+        "truncated": false,
+        "tree": [{
+            "path": path,
+            "mode": mode,
+            "type": type_,
+            "sha": entry_sha,
+            "size": 0,
+            "url": "",
+        }],
+    })
+    .to_string()
+}
+
+/// Serve the Git Data API of `o/r`, where `main` points to `c0` until it is
+/// updated, or to `heads` in turn if it is not empty.
+async fn serve_git(heads: Vec<&'static str>, update_status: u16) -> (crate::Client, MockServer) {
+    let server = MockServer::start().await;
+    let head = |sha: &str| {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ref": "refs/heads/main",
+            "object": { "sha": sha, "type": "commit" },
+        }))
+    };
+    for sha in heads {
+        Mock::given(method("GET"))
+            .and(path("/repos/o/r/git/ref/heads/main"))
+            .respond_with(head(sha))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/repos/o/r/git/ref/heads/main"))
+        .respond_with(head("c0"))
+        .mount(&server)
+        .await;
+    for (verb, route, status, body) in [
+        (
+            "GET",
+            "/repos/o/r/git/commits/c0",
+            200,
+            commit_json("c0", "t0", "c"),
+        ),
+        (
+            "POST",
+            "/repos/o/r/git/blobs",
+            200,
+            r#"{"sha":"b1"}"#.to_string(),
+        ),
+        (
+            "GET",
+            "/repos/o/r/git/trees/t0",
+            200,
+            tree_json("t0", "bin", "tree", "040000", "t1"),
+        ),
+        (
+            "GET",
+            "/repos/o/r/git/trees/t1",
+            200,
+            tree_json("t1", "run.sh", "blob", "100644", "b0"),
+        ),
+        (
+            "POST",
+            "/repos/o/r/git/trees",
+            200,
+            r#"{"sha":"t2"}"#.to_string(),
+        ),
+        (
+            "POST",
+            "/repos/o/r/git/commits",
+            200,
+            commit_json("c1", "t2", "c0"),
+        ),
+        (
+            "PATCH",
+            "/repos/o/r/git/refs/heads/main",
+            update_status,
+            r#"{"ref":"refs/heads/main","object":{"sha":"c1","type":"commit"}}"#.to_string(),
+        ),
+    ] {
+        Mock::given(method(verb))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(status).set_body_raw(body, "application/json"))
+            .mount(&server)
+            .await;
+    }
+
+    let client = crate::Client::host(server.uri(), "octorust-tests", None).unwrap();
+    (client, server)
+}
+
+/// The method, path and JSON body of the requests `server` received.
+async fn received(server: &MockServer) -> Vec<(String, String, serde_json::Value)> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|r| {
+            (
+                r.method.to_string(),
+                r.url.path().to_string(),
+                serde_json::from_slice(&r.body).unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_commit_builder() {
+    let (client, server) = serve_git(vec![], 200).await;
+
+    let commit = CommitBuilder::new("o", "r", "main", "Update")
+        .add_file("README.md", "# r\n")
+        .delete_file("old.txt")
+        .set_mode("bin/run.sh", GitCreateTreeRequestMode::ExecutableBlob)
+        .with_author("Bot", "bot@example.com")
+        .commit(&client)
+        .await
+        .unwrap();
+    assert_eq!(commit.sha, "c1");
+
+    let requests = received(&server).await;
+    let find = |method: &str, path: &str| {
+        requests
+            .iter()
+            .find(|(m, p, _)| m == method && p == path)
+            .map(|(_, _, body)| body.clone())
+            .unwrap()
+    };
+    assert_eq!(
+        find("POST", "/repos/o/r/git/blobs"),
+        serde_json::json!({ "content": base64::encode("# r\n"), "encoding": "base64" })
+    );
+    assert_eq!(
+        find("POST", "/repos/o/r/git/trees"),
+        serde_json::json!({
+            "base_tree": "t0",
+            "tree": [
+                { "path": "README.md", "mode": "100644", "type": "blob", "sha": "b1" },
+                { "path": "bin/run.sh", "mode": "100755", "type": "blob", "sha": "b0" },
+                { "path": "old.txt", "mode": "100644", "type": "blob", "sha": null },
+            ],
+        })
+    );
+    assert_eq!(
+        find("POST", "/repos/o/r/git/commits"),
+        serde_json::json!({
+            "author": { "name": "Bot", "email": "bot@example.com" },
+            "message": "Update",
+            "parents": ["c0"],
+            "tree": "t2",
+        })
+    );
+    assert_eq!(
+        find("PATCH", "/repos/o/r/git/refs/heads/main"),
+        serde_json::json!({ "force": false, "sha": "c1" })
+    );
+}
+
+#[tokio::test]
+async fn test_commit_builder_conflicts() {
+    // The branch moved from the parent before the commit started.
+    let (client, server) = serve_git(vec!["c5"], 200).await;
+    let err = CommitBuilder::new("o", "r", "main", "Update")
+        .add_file("README.md", "# r\n")
+        .with_parent("c0")
+        .commit(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<CommitConflict>(),
+        Some(&CommitConflict {
+            branch: "main".to_string(),
+            expected: "c0".to_string(),
+            actual: "c5".to_string(),
+        })
+    );
+    // Nothing was created.
+    assert_eq!(received(&server).await.len(), 1);
+
+    // The branch moved while the commit was created, so it is not a
+    // fast-forward.
+    let (client, _) = serve_git(vec!["c0", "c5"], 422).await;
+    let err = CommitBuilder::new("o", "r", "main", "Update")
+        .add_file("README.md", "# r\n")
+        .commit(&client)
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "branch main moved from c0 to c5");
+
+    // A forced update does not overwrite commits it did not see either.
+    let (client, server) = serve_git(vec!["c0", "c5"], 200).await;
+    let err = CommitBuilder::new("o", "r", "main", "Update")
+        .add_file("README.md", "# r\n")
+        .force(true)
+        .commit(&client)
+        .await
+        .unwrap_err();
+    assert!(err.downcast_ref::<CommitConflict>().is_some());
+    assert!(!received(&server).await.iter().any(|(m, _, _)| m == "PATCH"));
+
+    let (client, server) = serve_git(vec![], 200).await;
+    CommitBuilder::new("o", "r", "main", "Update")
+        .add_file("README.md", "# r\n")
+        .force(true)
+        .commit(&client)
+        .await
+        .unwrap();
+    let requests = received(&server).await;
+    assert_eq!(
+        requests.last().unwrap().2,
+        serde_json::json!({ "force": true, "sha": "c1" })
+    );
+}
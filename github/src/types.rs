@@ -6618,7 +6618,7 @@ pub struct MinimalRepository {
      * Minimal Repository
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub template_repository: Option<Data>,
+    pub template_repository: Option<Repository>,
     /**
      * Minimal Repository
      */
@@ -9602,7 +9602,7 @@ pub struct TeamRepository {
      * A team's access to a repository.
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub template_repository: Option<Data>,
+    pub template_repository: Option<Repository>,
     /**
      * A team's access to a repository.
      */
@@ -10452,7 +10452,7 @@ pub struct FullRepository {
      * Full Repository
      */
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub template_repository: Option<Data>,
+    pub template_repository: Option<Repository>,
     /**
      * Full Repository
      */
@@ -0,0 +1,356 @@
+use std::{
+    future::Future,
+    marker::PhantomData,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{anyhow, bail, Result};
+
+/// The path of the batch endpoint, on the host of the client.
+const BATCH_PATH: &str = "/batch/admin/directory_v1";
+
+/// The most requests Google accepts in a single batch.
+pub const MAX_BATCH_SIZE: usize = 100;
+
+/// A batch of requests, sent together in a single `multipart/mixed` request.
+///
+/// Calls are added with `add`, which runs a function of the client without
+/// sending anything:
+///
+/// ```ignore
+/// let mut batch = client.batch();
+/// let call = batch.add(|c| async move { c.files().get("id", false, "", false, false).await }).await?;
+/// let responses = batch.send().await?;
+/// let file = responses.get(&call)?;
+/// ```
+pub struct Batch {
+    client: crate::Client,
+    requests: Vec<reqwest::Request>,
+}
+
+/// Refers to the response of a call added to a `Batch`, and the type it parses to.
+#[derive(Debug)]
+pub struct BatchCall<T> {
+    index: usize,
+    output: PhantomData<fn() -> T>,
+}
+
+/// The response to a single call of a batch.
+#[derive(Debug, Clone)]
+pub struct BatchResponse {
+    pub status: http::StatusCode,
+    pub body: bytes::Bytes,
+}
+
+/// The responses to the calls of a batch.
+#[derive(Debug, Clone, Default)]
+pub struct BatchResponses {
+    responses: Vec<Option<BatchResponse>>,
+}
+
+impl<T> BatchCall<T> {
+    pub(crate) fn new(index: usize) -> Self {
+        BatchCall {
+            index,
+            output: PhantomData,
+        }
+    }
+}
+
+impl Batch {
+    pub(crate) fn new(client: crate::Client) -> Self {
+        Batch {
+            client,
+            requests: Vec::new(),
+        }
+    }
+
+    /// Add the request of a call to the batch.
+    ///
+    /// `call` is given a client that records its request instead of sending it,
+    /// and must make exactly one request with it. Uploads, downloads and
+    /// functions that return all the pages of a request can not be batched.
+    pub async fn add<F, Fut, T>(&mut self, call: F) -> Result<BatchCall<T>>
+    where
+        F: FnOnce(crate::Client) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        if self.requests.len() >= MAX_BATCH_SIZE {
+            bail!("a batch can not have more than {} calls", MAX_BATCH_SIZE);
+        }
+
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let mut client = self.client.clone();
+        client.auto_refresh = false;
+        client.client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new())
+            .with(BatchRecorder(recorded.clone()))
+            .build();
+
+        // The call fails since nothing was sent, all we want is its request.
+        let _ = call(client).await;
+
+        let mut recorded = std::mem::take(&mut *recorded.lock().unwrap());
+        if recorded.len() != 1 {
+            bail!(
+                "a batched call must make exactly one request, not {}",
+                recorded.len()
+            );
+        }
+        self.requests.push(recorded.remove(0));
+
+        Ok(BatchCall::new(self.requests.len() - 1))
+    }
+
+    /// Returns the number of calls in the batch.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns true if no calls were added to the batch.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Send the batch and return the response to each call.
+    pub async fn send(self) -> Result<BatchResponses> {
+        if self.requests.is_empty() {
+            return Ok(Default::default());
+        }
+
+        let boundary = format!("batch_{}", uuid::Uuid::new_v4().simple());
+        let body = encode_batch(&self.requests, &boundary)?;
+
+        let host = self.client.host.parse::<reqwest::Url>()?;
+        let url = format!("{}{}", host.origin().ascii_serialization(), BATCH_PATH);
+        let mut req = self
+            .client
+            .make_request(&reqwest::Method::POST, &url, Some(body.into()))
+            .await?;
+        req.headers_mut().insert(
+            reqwest::header::CONTENT_TYPE,
+            reqwest::header::HeaderValue::from_str(&format!(
+                "multipart/mixed; boundary={}",
+                boundary
+            ))?,
+        );
+
+        let resp = self.client.client.execute(req).await?;
+        let status = resp.status();
+        let content_type = resp
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let body = resp.bytes().await?;
+        if !status.is_success() {
+            bail!(
+                "code: {}, error: {:?}",
+                status,
+                String::from_utf8_lossy(&body)
+            );
+        }
+
+        let boundary = match content_type
+            .split(';')
+            .find_map(|p| p.trim().strip_prefix("boundary="))
+        {
+            Some(b) => b.trim_matches('"').to_string(),
+            None => bail!("batch response is not multipart: {}", content_type),
+        };
+
+        parse_batch_response(&body, &boundary, self.requests.len())
+    }
+}
+
+impl BatchResponses {
+    /// Returns the response to a call, parsed to the type the call returns.
+    ///
+    /// This returns an error with the body if the call did not succeed.
+    pub fn get<T>(&self, call: &BatchCall<T>) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let resp = match self.response(call) {
+            Some(r) => r,
+            None => bail!("the batch response has no response for call {}", call.index),
+        };
+
+        if !resp.status.is_success() {
+            return Err(anyhow!(
+                "code: {}, error: {:?}",
+                resp.status,
+                String::from_utf8_lossy(&resp.body)
+            ));
+        }
+
+        if resp.body.is_empty() {
+            Ok(serde_json::from_str("null")?)
+        } else {
+            Ok(serde_json::from_slice(&resp.body)?)
+        }
+    }
+
+    /// Returns the raw response to a call.
+    pub fn response<T>(&self, call: &BatchCall<T>) -> Option<&BatchResponse> {
+        self.responses.get(call.index).and_then(|r| r.as_ref())
+    }
+}
+
+/// Records the requests sent through a client, and fails them.
+struct BatchRecorder(Arc<Mutex<Vec<reqwest::Request>>>);
+
+#[async_trait::async_trait]
+impl reqwest_middleware::Middleware for BatchRecorder {
+    async fn handle(
+        &self,
+        req: reqwest::Request,
+        _extensions: &mut task_local_extensions::Extensions,
+        _next: reqwest_middleware::Next<'_>,
+    ) -> reqwest_middleware::Result<reqwest::Response> {
+        self.0.lock().unwrap().push(req);
+
+        Err(reqwest_middleware::Error::Middleware(anyhow!(
+            "the request was added to a batch"
+        )))
+    }
+}
+
+/// Encode requests as the parts of a `multipart/mixed` body.
+pub(crate) fn encode_batch(requests: &[reqwest::Request], boundary: &str) -> Result<Vec<u8>> {
+    let mut body = Vec::new();
+    for (i, req) in requests.iter().enumerate() {
+        body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Type: application/http\r\nContent-ID: <item{}>\r\n\r\n",
+                boundary, i
+            )
+            .as_bytes(),
+        );
+
+        let url = req.url();
+        let path = match url.query() {
+            Some(q) if !q.is_empty() => format!("{}?{}", url.path(), q),
+            _ => url.path().to_string(),
+        };
+        body.extend_from_slice(format!("{} {} HTTP/1.1\r\n", req.method(), path).as_bytes());
+
+        // The batch request is authorized for all of its calls.
+        for (name, value) in req.headers() {
+            if name != http::header::AUTHORIZATION {
+                body.extend_from_slice(name.as_str().as_bytes());
+                body.extend_from_slice(b": ");
+                body.extend_from_slice(value.as_bytes());
+                body.extend_from_slice(b"\r\n");
+            }
+        }
+        body.extend_from_slice(b"\r\n");
+
+        if let Some(b) = req.body() {
+            match b.as_bytes() {
+                Some(b) => body.extend_from_slice(b),
+                None => bail!("the body of a batched call can not be a stream"),
+            }
+        }
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+    Ok(body)
+}
+
+/// Parse the parts of a `multipart/mixed` batch response, matching them to
+/// the `count` calls of the batch by their `Content-ID`.
+pub(crate) fn parse_batch_response(
+    body: &[u8],
+    boundary: &str,
+    count: usize,
+) -> Result<BatchResponses> {
+    let delimiter = format!("--{}", boundary);
+    let mut responses = vec![None; count];
+
+    let mut parts = split_bytes(body, delimiter.as_bytes());
+    // Skip the preamble.
+    parts.next();
+    for (position, part) in parts.enumerate() {
+        // The last delimiter is followed by `--`.
+        if part.starts_with(b"--") {
+            break;
+        }
+
+        let (headers, http) = split_head(part)?;
+        // Match `response-item{i}` to the call that was sent as `item{i}`.
+        let index = headers
+            .lines()
+            .filter_map(|l| l.split_once(':'))
+            .find(|(n, _)| n.trim().eq_ignore_ascii_case("content-id"))
+            .and_then(|(_, v)| {
+                v.trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .strip_prefix("response-item")
+                    .and_then(|i| i.parse::<usize>().ok())
+            })
+            .unwrap_or(position);
+        if index >= count {
+            bail!(
+                "the batch response has a response for unknown call {}",
+                index
+            );
+        }
+
+        let (head, body) = split_head(http)?;
+        let status = head
+            .lines()
+            .next()
+            .and_then(|l| l.split_whitespace().nth(1))
+            .and_then(|s| s.parse::<http::StatusCode>().ok())
+            .ok_or_else(|| anyhow!("invalid batch response part: {}", head))?;
+
+        let body = body.strip_suffix(b"\r\n").unwrap_or(body);
+        responses[index] = Some(BatchResponse {
+            status,
+            body: bytes::Bytes::copy_from_slice(body),
+        });
+    }
+
+    Ok(BatchResponses { responses })
+}
+
+/// Split the head of a part from its body at the first blank line.
+fn split_head(part: &[u8]) -> Result<(String, &[u8])> {
+    let part = part.strip_prefix(b"\r\n").unwrap_or(part);
+    let (end, len) = match find_bytes(part, b"\r\n\r\n") {
+        Some(i) => (i, 4),
+        None => match find_bytes(part, b"\n\n") {
+            Some(i) => (i, 2),
+            None => (part.len(), 0),
+        },
+    };
+
+    Ok((String::from_utf8(part[..end].to_vec())?, &part[end + len..]))
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn split_bytes<'a>(mut haystack: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = &'a [u8]> {
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        match find_bytes(haystack, needle) {
+            Some(i) => {
+                let part = &haystack[..i];
+                haystack = &haystack[i + needle.len()..];
+                Some(part)
+            }
+            None => {
+                done = true;
+                Some(haystack)
+            }
+        }
+    })
+}
@@ -1 +1,192 @@
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+use crate::traits::{DirectoryExport, DirectorySnapshot};
+
+fn snapshot(memberships: &[(&str, &str, &str)]) -> DirectorySnapshot {
+    let mut groups: Vec<serde_json::Value> = Vec::new();
+    for (group, member, role) in memberships {
+        if !groups.iter().any(|g| g["group"]["email"] == *group) {
+            groups.push(serde_json::json!({ "group": { "email": group }, "members": [] }));
+        }
+        let g = groups
+            .iter_mut()
+            .find(|g| g["group"]["email"] == *group)
+            .unwrap();
+        g["members"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!({
+                "email": member,
+                "id": format!("id-{}", member),
+                "role": role,
+                "type": "USER",
+            }));
+    }
+
+    serde_json::from_value(serde_json::json!({
+        "taken_at": "2022-01-01T00:00:00Z",
+        "users": [],
+        "groups": groups,
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_directory_export() {
+    let server = MockServer::start().await;
+    // Rate limits are not retried by the client itself.
+    Mock::given(method("GET"))
+        .and(path("/admin/directory/v1/users"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .set_body_json(serde_json::json!({ "error": { "message": "rateLimitExceeded" } })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/admin/directory/v1/users"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "users": [
+            { "id": "2", "primaryEmail": "zoe@example.com", "name": { "fullName": "Zoe, Z" }, "customSchemas": {} },
+            { "id": "1", "primaryEmail": "ann@example.com", "isAdmin": true, "customSchemas": {} },
+        ]})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/admin/directory/v1/groups"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "groups": [
+                { "id": "g2", "email": "ops@example.com" },
+                { "id": "g1", "email": "eng@example.com" },
+            ]})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/admin/directory/v1/groups/g1/members"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "members": [
+                { "id": "2", "email": "zoe@example.com", "role": "MEMBER", "type": "USER" },
+                { "id": "1", "email": "ann@example.com", "role": "OWNER", "type": "USER" },
+            ]})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/admin/directory/v1/groups/g2/members"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "members": [] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    #[cfg(feature = "groups-settings")]
+    for email in ["eng@example.com", "ops@example.com"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/groups/v1/groups/{}", email)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "email": email, "whoCanJoin": "INVITED_CAN_JOIN" }),
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let client = crate::Client::new("", "", "", "", "").with_host(server.uri());
+    let export = DirectoryExport::new(client);
+    #[cfg(feature = "groups-settings")]
+    let export = export.with_groups_settings(
+        google_groups_settings::Client::new("", "", "", "", "")
+            .with_host(format!("{}/groups/v1/groups", server.uri())),
+    );
+    let snapshot = export
+        .with_concurrency(2)
+        .with_retries(1, std::time::Duration::from_millis(1))
+        .run()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        snapshot
+            .users
+            .iter()
+            .map(|u| u.primary_email.as_str())
+            .collect::<Vec<_>>(),
+        vec!["ann@example.com", "zoe@example.com"]
+    );
+    assert_eq!(snapshot.groups[0].group.email, "eng@example.com");
+    #[cfg(feature = "groups-settings")]
+    assert_eq!(
+        snapshot.groups[0].settings.as_ref().unwrap().who_can_join,
+        "INVITED_CAN_JOIN"
+    );
+    assert!(snapshot.groups[1].members.is_empty());
+
+    assert_eq!(
+        snapshot.memberships_csv(),
+        "group_email,member_email,member_id,role,type,status\r\neng@example.com,ann@example.com,1,\
+         OWNER,USER,\r\neng@example.com,zoe@example.com,2,MEMBER,USER,\r\n"
+    );
+    assert!(snapshot
+        .users_csv()
+        .contains("2,zoe@example.com,\"Zoe, Z\",,false,false,false,\r\n"));
+
+    let json = snapshot.to_json().unwrap();
+    assert_eq!(DirectorySnapshot::from_json(&json).unwrap(), snapshot);
+}
+
+#[test]
+fn test_snapshot_diff() {
+    let old = snapshot(&[
+        ("eng@example.com", "ann@example.com", "OWNER"),
+        ("eng@example.com", "bob@example.com", "MEMBER"),
+        ("ops@example.com", "bob@example.com", "MEMBER"),
+    ]);
+    let new = snapshot(&[
+        ("eng@example.com", "ann@example.com", "OWNER"),
+        ("eng@example.com", "bob@example.com", "MANAGER"),
+        ("ops@example.com", "cat@example.com", "MEMBER"),
+    ]);
+
+    let diff = old.diff(&new);
+    let emails = |m: &[crate::traits::Membership]| {
+        m.iter()
+            .map(|m| format!("{} {} {}", m.group_email, m.member_email, m.role))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        emails(&diff.added),
+        vec!["ops@example.com cat@example.com MEMBER"]
+    );
+    assert_eq!(
+        emails(&diff.removed),
+        vec!["ops@example.com bob@example.com MEMBER"]
+    );
+    assert_eq!(
+        emails(&diff.changed),
+        vec!["eng@example.com bob@example.com MANAGER"]
+    );
+    assert!(old.diff(&old).is_empty());
+}
+
+#[test]
+fn test_memberships_csv_formulas() {
+    let snapshot = snapshot(&[
+        ("eng@example.com", "=HYPERLINK(\"http://x\")", "MEMBER"),
+        ("eng@example.com", "+1-555", "MEMBER"),
+    ]);
+
+    assert_eq!(
+        snapshot.memberships_csv(),
+        "group_email,member_email,member_id,role,type,status\r\n\
+         eng@example.com,\"'=HYPERLINK(\"\"http://x\"\")\",\"id-=HYPERLINK(\"\"http://x\"\")\",MEMBER,USER,\r\n\
+         eng@example.com,'+1-555,id-+1-555,MEMBER,USER,\r\n"
+    );
+}
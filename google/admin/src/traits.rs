@@ -0,0 +1,383 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use futures::{StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
+/// Exports a snapshot of the users, groups and group members of a Google
+/// Workspace directory. With the `groups-settings` feature, it can also export
+/// the settings of each group.
+///
+/// ```ignore
+/// let snapshot = DirectoryExport::new(client)
+///     .with_groups_settings(groups_settings_client)
+///     .run()
+///     .await?;
+/// std::fs::write("memberships.csv", snapshot.memberships_csv())?;
+/// ```
+pub struct DirectoryExport {
+    client: crate::Client,
+    #[cfg(feature = "groups-settings")]
+    groups_settings: Option<google_groups_settings::Client>,
+    customer: String,
+    domain: String,
+    concurrency: usize,
+    retries: u32,
+    retry_backoff: std::time::Duration,
+}
+
+impl DirectoryExport {
+    /// Export the directory of the customer of the authenticated user.
+    pub fn new(client: crate::Client) -> Self {
+        DirectoryExport {
+            client,
+            #[cfg(feature = "groups-settings")]
+            groups_settings: None,
+            customer: "my_customer".to_string(),
+            domain: String::new(),
+            concurrency: 8,
+            retries: 3,
+            retry_backoff: std::time::Duration::from_millis(500),
+        }
+    }
+
+    /// Set the ID of the Google Workspace account to export.
+    pub fn with_customer(mut self, customer: &str) -> Self {
+        self.customer = customer.to_string();
+        self
+    }
+
+    /// Only export the users and groups of a domain.
+    pub fn with_domain(mut self, domain: &str) -> Self {
+        self.domain = domain.to_string();
+        self
+    }
+
+    /// Also export the settings of every group, with a Groups Settings client.
+    #[cfg(feature = "groups-settings")]
+    pub fn with_groups_settings(mut self, client: google_groups_settings::Client) -> Self {
+        self.groups_settings = Some(client);
+        self
+    }
+
+    /// Set how many groups are exported at the same time.
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Set how many times a failed listing is retried, and how long to wait
+    /// before the first retry. The wait doubles with each retry.
+    pub fn with_retries(mut self, retries: u32, backoff: std::time::Duration) -> Self {
+        self.retries = retries;
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Export the directory.
+    pub async fn run(&self) -> Result<DirectorySnapshot> {
+        let taken_at = chrono::Utc::now();
+
+        let users = self.client.users();
+        let mut users = self
+            .retry(|| {
+                users.list_all(
+                    &self.customer,
+                    &self.domain,
+                    crate::types::Event::Noop,
+                    crate::types::DirectoryUsersListOrderBy::Noop,
+                    crate::types::DirectoryUsersListProjection::Full,
+                    "",
+                    "",
+                    crate::types::SortOrder::Noop,
+                    crate::types::ViewType::AdminView,
+                )
+            })
+            .await?;
+        users.sort_by(|a, b| a.primary_email.cmp(&b.primary_email));
+
+        let groups = self.client.groups();
+        let groups = self
+            .retry(|| {
+                groups.list_all(
+                    &self.customer,
+                    &self.domain,
+                    crate::types::DirectoryGroupsListOrderBy::Noop,
+                    "",
+                    crate::types::SortOrder::Noop,
+                    "",
+                )
+            })
+            .await?;
+
+        let mut groups: Vec<GroupSnapshot> = futures::stream::iter(groups)
+            .map(|group| self.export_group(group))
+            .buffer_unordered(self.concurrency)
+            .try_collect()
+            .await?;
+        groups.sort_by(|a, b| a.group.email.cmp(&b.group.email));
+
+        Ok(DirectorySnapshot {
+            taken_at,
+            users,
+            groups,
+        })
+    }
+
+    async fn export_group(&self, group: crate::types::Group) -> Result<GroupSnapshot> {
+        let members = self.client.members();
+        let mut members = self
+            .retry(|| members.list_all(&group.id, false, ""))
+            .await?;
+        members.sort_by(|a, b| a.email.cmp(&b.email).then(a.id.cmp(&b.id)));
+
+        #[cfg(feature = "groups-settings")]
+        let settings = match &self.groups_settings {
+            Some(client) => {
+                let groups = client.groups();
+                Some(
+                    self.retry(|| {
+                        groups.get(google_groups_settings::types::Alt::Json, &group.email)
+                    })
+                    .await?,
+                )
+            }
+            None => None,
+        };
+
+        Ok(GroupSnapshot {
+            group,
+            members,
+            #[cfg(feature = "groups-settings")]
+            settings,
+        })
+    }
+
+    async fn retry<T, F, Fut>(&self, f: F) -> Result<T>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<T>>,
+    {
+        let mut backoff = self.retry_backoff;
+        let mut attempt = 0;
+        loop {
+            match f().await {
+                Ok(v) => return Ok(v),
+                Err(e) if attempt < self.retries => {
+                    log::warn!("directory export failed, retrying in {:?}: {}", backoff, e);
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// A snapshot of the users, groups and group members of a directory.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct DirectorySnapshot {
+    /// When the export started.
+    pub taken_at: chrono::DateTime<chrono::Utc>,
+    /// The users, sorted by primary email.
+    pub users: Vec<crate::types::User>,
+    /// The groups, sorted by email.
+    pub groups: Vec<GroupSnapshot>,
+}
+
+/// A group of a `DirectorySnapshot`, with its members and settings.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct GroupSnapshot {
+    pub group: crate::types::Group,
+    /// The members, sorted by email.
+    pub members: Vec<crate::types::Member>,
+    /// The settings of the group, if they were exported.
+    #[cfg(feature = "groups-settings")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub settings: Option<google_groups_settings::types::Groups>,
+}
+
+/// A member of a group, as one row of a membership listing.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone, PartialOrd, Ord)]
+pub struct Membership {
+    pub group_email: String,
+    pub member_email: String,
+    pub member_id: String,
+    pub role: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub status: String,
+}
+
+impl Membership {
+    /// Identifies the member of a group, across snapshots.
+    fn key(&self) -> (String, String) {
+        let member = if self.member_id.is_empty() {
+            self.member_email.to_lowercase()
+        } else {
+            self.member_id.to_string()
+        };
+        (self.group_email.to_lowercase(), member)
+    }
+}
+
+/// The memberships that changed between two snapshots.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct SnapshotDiff {
+    pub added: Vec<Membership>,
+    pub removed: Vec<Membership>,
+    /// The memberships whose role, type or status changed, as they are in the
+    /// newer snapshot.
+    pub changed: Vec<Membership>,
+}
+
+impl SnapshotDiff {
+    /// Returns true if no membership changed.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl DirectorySnapshot {
+    /// Returns every member of every group.
+    pub fn memberships(&self) -> Vec<Membership> {
+        self.groups
+            .iter()
+            .flat_map(|g| {
+                g.members.iter().map(move |m| Membership {
+                    group_email: g.group.email.to_string(),
+                    member_email: m.email.to_string(),
+                    member_id: m.id.to_string(),
+                    role: m.role.to_string(),
+                    type_: m.type_.to_string(),
+                    status: m.status.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the memberships that were added, removed or changed in
+    /// `newer`, compared to this snapshot.
+    pub fn diff(&self, newer: &DirectorySnapshot) -> SnapshotDiff {
+        let old: BTreeMap<_, _> = self
+            .memberships()
+            .into_iter()
+            .map(|m| (m.key(), m))
+            .collect();
+        let new: BTreeMap<_, _> = newer
+            .memberships()
+            .into_iter()
+            .map(|m| (m.key(), m))
+            .collect();
+
+        let mut diff = SnapshotDiff::default();
+        for (key, m) in &new {
+            match old.get(key) {
+                None => diff.added.push(m.clone()),
+                Some(o) if (&o.role, &o.type_, &o.status) != (&m.role, &m.type_, &m.status) => {
+                    diff.changed.push(m.clone())
+                }
+                Some(_) => {}
+            }
+        }
+        for (key, m) in old {
+            if !new.contains_key(&key) {
+                diff.removed.push(m);
+            }
+        }
+
+        diff
+    }
+
+    /// Serialize the snapshot as JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Parse a snapshot serialized with `to_json`.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Returns the users as CSV, one row per user.
+    pub fn users_csv(&self) -> String {
+        let mut rows = vec![csv_row(&[
+            "id",
+            "primary_email",
+            "name",
+            "org_unit_path",
+            "is_admin",
+            "suspended",
+            "archived",
+            "last_login_time",
+        ])];
+        for u in &self.users {
+            rows.push(csv_row(&[
+                &u.id,
+                &u.primary_email,
+                &u.name
+                    .as_ref()
+                    .map(|n| n.full_name.to_string())
+                    .unwrap_or_default(),
+                &u.org_unit_path,
+                &u.is_admin.to_string(),
+                &u.suspended.to_string(),
+                &u.archived.to_string(),
+                &u.last_login_time
+                    .map(|t| t.to_rfc3339())
+                    .unwrap_or_default(),
+            ]));
+        }
+
+        rows.concat()
+    }
+
+    /// Returns the memberships as CSV, one row per member of a group.
+    pub fn memberships_csv(&self) -> String {
+        let mut rows = vec![csv_row(&[
+            "group_email",
+            "member_email",
+            "member_id",
+            "role",
+            "type",
+            "status",
+        ])];
+        for m in self.memberships() {
+            rows.push(csv_row(&[
+                &m.group_email,
+                &m.member_email,
+                &m.member_id,
+                &m.role,
+                &m.type_,
+                &m.status,
+            ]));
+        }
+
+        rows.concat()
+    }
+}
+
+/// Format a CSV row, quoting the fields that need it.
+///
+/// Spreadsheets run the fields that start with `=`, `+`, `-` or `@` as
+/// formulas, so these are prefixed with a `'` to be read as text.
+fn csv_row(fields: &[&str]) -> String {
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|f| {
+            let f = if f.starts_with(['=', '+', '-', '@']) {
+                format!("'{}", f)
+            } else {
+                f.to_string()
+            };
+            if f.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f.to_string()
+            }
+        })
+        .collect();
+
+    format!("{}\r\n", fields.join(","))
+}
@@ -1,3 +1,8 @@
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
+
 const EVENT: &str = r#"{
    "kind": "calendar#event",
    "etag": "\"3258434761704000\"",
@@ -99,3 +104,122 @@ fn test_deserialize() {
     let deserialized: crate::types::Event = serde_json::from_str(EVENT).unwrap();
     println!("event = {:?}", deserialized);
 }
+
+fn events_page(
+    events: &[(&str, &str)],
+    next_page_token: &str,
+    next_sync_token: &str,
+) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "items": events
+            .iter()
+            .map(|(id, status)| serde_json::json!({ "id": id, "status": status }))
+            .collect::<Vec<_>>(),
+        "nextPageToken": next_page_token,
+        "nextSyncToken": next_sync_token,
+    }))
+}
+
+#[tokio::test]
+async fn test_event_sync_incremental() {
+    use crate::traits::EventOps;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/calendars/team@example.com/events"))
+        .and(query_param("syncToken", "s1"))
+        .respond_with(events_page(
+            &[("a", "confirmed"), ("b", "cancelled")],
+            "",
+            "s2",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("", "", "", "", "").with_host(server.uri());
+    let sync = client
+        .events()
+        .sync("team@example.com", "s1")
+        .await
+        .unwrap();
+
+    assert_eq!(sync.kind, crate::traits::SyncKind::Incremental);
+    assert_eq!(sync.next_sync_token, "s2");
+    assert_eq!(
+        sync.updated().map(|e| e.id.as_str()).collect::<Vec<_>>(),
+        vec!["a"]
+    );
+    assert_eq!(
+        sync.deleted().map(|e| e.id.as_str()).collect::<Vec<_>>(),
+        vec!["b"]
+    );
+}
+
+#[tokio::test]
+async fn test_event_sync_resyncs_when_token_expired() {
+    use crate::traits::EventOps;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param("syncToken", "old"))
+        .respond_with(ResponseTemplate::new(410))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param_is_missing("syncToken"))
+        .and(query_param_is_missing("pageToken"))
+        .respond_with(events_page(&[("a", "confirmed")], "p2", ""))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/events"))
+        .and(query_param_is_missing("syncToken"))
+        .and(query_param("pageToken", "p2"))
+        .respond_with(events_page(&[("c", "confirmed")], "", "s3"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("", "", "", "", "").with_host(server.uri());
+    let sync = client.events().sync("primary", "old").await.unwrap();
+
+    assert_eq!(sync.kind, crate::traits::SyncKind::Resync);
+    assert_eq!(sync.next_sync_token, "s3");
+    assert_eq!(
+        sync.items.iter().map(|e| e.id.as_str()).collect::<Vec<_>>(),
+        vec!["a", "c"]
+    );
+}
+
+#[tokio::test]
+async fn test_acl_sync_full() {
+    use crate::traits::AclOps;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/calendars/primary/acl"))
+        .and(query_param_is_missing("syncToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "items": [
+                { "id": "user:a@example.com", "role": "owner" },
+                { "id": "user:b@example.com", "role": "none" },
+            ],
+            "nextSyncToken": "s1",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("", "", "", "", "").with_host(server.uri());
+    let sync = client.acl().sync("primary", "").await.unwrap();
+
+    assert_eq!(sync.kind, crate::traits::SyncKind::Full);
+    assert_eq!(sync.next_sync_token, "s1");
+    assert_eq!(sync.updated().count(), 1);
+    assert_eq!(sync.deleted().next().unwrap().id, "user:b@example.com");
+}
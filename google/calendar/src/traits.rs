@@ -0,0 +1,235 @@
+use anyhow::{anyhow, Result};
+
+#[async_trait::async_trait]
+pub trait EventOps {
+    /// Sync the events of a calendar.
+    ///
+    /// With an empty `sync_token` this lists every event of the calendar,
+    /// otherwise only the events that changed since the listing that returned
+    /// `sync_token`, including the ones that were cancelled.
+    ///
+    /// If Google expired the sync token (`410 Gone`), this falls back to a full
+    /// listing and reports it with `SyncKind::Resync`.
+    async fn sync(
+        &self,
+        calendar_id: &str,
+        sync_token: &str,
+    ) -> Result<SyncResult<crate::types::Event>>;
+}
+
+#[async_trait::async_trait]
+impl EventOps for crate::events::Events {
+    /// Sync the events of a calendar.
+    ///
+    /// With an empty `sync_token` this lists every event of the calendar,
+    /// otherwise only the events that changed since the listing that returned
+    /// `sync_token`, including the ones that were cancelled.
+    ///
+    /// If Google expired the sync token (`410 Gone`), this falls back to a full
+    /// listing and reports it with `SyncKind::Resync`.
+    async fn sync(
+        &self,
+        calendar_id: &str,
+        sync_token: &str,
+    ) -> Result<SyncResult<crate::types::Event>> {
+        let url = format!(
+            "/calendars/{}/events",
+            crate::progenitor_support::encode_path(calendar_id),
+        );
+
+        self.client
+            .sync_pages::<crate::types::Events>(&url, sync_token)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+pub trait AclOps {
+    /// Sync the access control list of a calendar.
+    ///
+    /// With an empty `sync_token` this lists every rule of the calendar,
+    /// otherwise only the rules that changed since the listing that returned
+    /// `sync_token`, including the ones that were deleted.
+    ///
+    /// If Google expired the sync token (`410 Gone`), this falls back to a full
+    /// listing and reports it with `SyncKind::Resync`.
+    async fn sync(
+        &self,
+        calendar_id: &str,
+        sync_token: &str,
+    ) -> Result<SyncResult<crate::types::AclRule>>;
+}
+
+#[async_trait::async_trait]
+impl AclOps for crate::acl::Acl {
+    /// Sync the access control list of a calendar.
+    ///
+    /// With an empty `sync_token` this lists every rule of the calendar,
+    /// otherwise only the rules that changed since the listing that returned
+    /// `sync_token`, including the ones that were deleted.
+    ///
+    /// If Google expired the sync token (`410 Gone`), this falls back to a full
+    /// listing and reports it with `SyncKind::Resync`.
+    async fn sync(
+        &self,
+        calendar_id: &str,
+        sync_token: &str,
+    ) -> Result<SyncResult<crate::types::AclRule>> {
+        let url = format!(
+            "/calendars/{}/acl",
+            crate::progenitor_support::encode_path(calendar_id),
+        );
+
+        self.client
+            .sync_pages::<crate::types::Acl>(&url, sync_token)
+            .await
+    }
+}
+
+/// How a sync listed its items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SyncKind {
+    /// No sync token was given, so every item was listed.
+    Full,
+    /// Only the items that changed since the sync token were listed.
+    Incremental,
+    /// The sync token had expired, so every item was listed again.
+    ///
+    /// Anything stored from previous syncs should be cleared and replaced
+    /// with the items of this one.
+    Resync,
+}
+
+/// The items listed by a sync, and the token to pass to the next one.
+#[derive(Debug, Clone)]
+pub struct SyncResult<T> {
+    /// The items that were listed.
+    pub items: Vec<T>,
+    /// Pass this to the next sync to only list what changed since this one.
+    pub next_sync_token: String,
+    /// How the items were listed.
+    pub kind: SyncKind,
+}
+
+impl<T: SyncItem> SyncResult<T> {
+    /// The items that were deleted: cancelled events, or rules with the role
+    /// `none`.
+    pub fn deleted(&self) -> impl Iterator<Item = &T> {
+        self.items.iter().filter(|i| i.is_deleted())
+    }
+
+    /// The items that were created or updated.
+    pub fn updated(&self) -> impl Iterator<Item = &T> {
+        self.items.iter().filter(|i| !i.is_deleted())
+    }
+}
+
+/// An item that can be listed by a sync.
+pub trait SyncItem {
+    /// Returns true if the item was deleted since the last sync.
+    fn is_deleted(&self) -> bool;
+}
+
+impl SyncItem for crate::types::Event {
+    fn is_deleted(&self) -> bool {
+        self.status == "cancelled"
+    }
+}
+
+impl SyncItem for crate::types::AclRule {
+    fn is_deleted(&self) -> bool {
+        self.role == "none"
+    }
+}
+
+/// A page of a listing that supports sync tokens.
+pub(crate) trait SyncPage: serde::de::DeserializeOwned + Send + 'static {
+    type Item: Send;
+
+    /// Returns the items, the next page token and the next sync token.
+    fn into_parts(self) -> (Vec<Self::Item>, String, String);
+}
+
+impl SyncPage for crate::types::Events {
+    type Item = crate::types::Event;
+
+    fn into_parts(self) -> (Vec<Self::Item>, String, String) {
+        (self.items, self.next_page_token, self.next_sync_token)
+    }
+}
+
+impl SyncPage for crate::types::Acl {
+    type Item = crate::types::AclRule;
+
+    fn into_parts(self) -> (Vec<Self::Item>, String, String) {
+        (self.items, self.next_page_token, self.next_sync_token)
+    }
+}
+
+impl crate::Client {
+    /// List every page of `url`, from `sync_token` if it is not empty, and
+    /// start over without it if Google says it expired.
+    pub(crate) async fn sync_pages<P: SyncPage>(
+        &self,
+        url: &str,
+        sync_token: &str,
+    ) -> Result<SyncResult<P::Item>> {
+        let mut kind = if sync_token.is_empty() {
+            SyncKind::Full
+        } else {
+            SyncKind::Incremental
+        };
+
+        'sync: loop {
+            let mut items = Vec::new();
+            let mut page_token = String::new();
+
+            loop {
+                let mut query_args: Vec<(&str, &str)> = Default::default();
+                if kind == SyncKind::Incremental {
+                    query_args.push(("syncToken", sync_token));
+                }
+                if !page_token.is_empty() {
+                    query_args.push(("pageToken", &page_token));
+                }
+                let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+
+                let resp = self
+                    .request_raw(
+                        reqwest::Method::GET,
+                        &format!("{}{}?{}", self.host, url, query_),
+                        None,
+                    )
+                    .await?;
+                let status = resp.status();
+                if status == http::StatusCode::GONE && kind == SyncKind::Incremental {
+                    log::debug!("sync token for {} expired, doing a full sync", url);
+                    kind = SyncKind::Resync;
+                    continue 'sync;
+                }
+
+                let body = resp.bytes().await?;
+                if !status.is_success() {
+                    return Err(anyhow!(
+                        "code: {}, error: {:?}",
+                        status,
+                        String::from_utf8_lossy(&body),
+                    ));
+                }
+
+                let (mut page, next_page_token, next_sync_token) =
+                    serde_json::from_slice::<P>(&body)?.into_parts();
+                items.append(&mut page);
+
+                if next_page_token.is_empty() {
+                    return Ok(SyncResult {
+                        items,
+                        next_sync_token,
+                        kind,
+                    });
+                }
+                page_token = next_page_token;
+            }
+        }
+    }
+}
@@ -110,12 +110,14 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod about;
+pub mod batch;
 pub mod changes;
 pub mod channels;
 pub mod comments;
 pub mod drives;
 pub mod files;
 pub mod permissions;
+pub mod push;
 pub mod replies;
 pub mod revisions;
 pub mod teamdrives;
@@ -150,6 +152,18 @@ mod progenitor_support {
     }
 }
 
+/// Limits applied by functions that return all the pages of a request at once
+/// by following `Link` headers.
+#[derive(Debug, Clone, Default)]
+pub struct Pagination {
+    /// Stop once this many items have been fetched.
+    pub max_items: Option<usize>,
+    /// Stop once this many pages have been fetched.
+    pub max_pages: Option<usize>,
+    /// The number of items to request per page.
+    pub page_size: Option<u32>,
+}
+
 use std::convert::TryInto;
 use std::env;
 use std::ops::Add;
@@ -168,8 +182,9 @@ pub struct Client {
     client_id: String,
     client_secret: String,
     redirect_uri: String,
-
+    fields: String,
     auto_refresh: bool,
+    pagination: Pagination,
     client: reqwest_middleware::ClientWithMiddleware,
 }
 
@@ -267,8 +282,9 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
-
+                    fields: Default::default(),
                     auto_refresh: false,
+                    pagination: Default::default(),
                     client,
                 }
             }
@@ -346,6 +362,44 @@ impl Client {
         c
     }
 
+    /// Set the limits used by functions that return all the pages of a request at once.
+    pub fn with_pagination(&self, pagination: Pagination) -> Self {
+        let mut c = self.clone();
+        c.pagination = pagination;
+        c
+    }
+
+    /// Only return the selected fields of responses, like `files(id,name),nextPageToken`.
+    ///
+    /// This is sent as the `fields` parameter of the requests of the generated
+    /// functions, unless they have one already. Uploads, downloads and batches are
+    /// not affected. Fields that are not selected are left to their defaults, so
+    /// functions that return all the pages of a request need `nextPageToken` to be
+    /// selected.
+    pub fn with_fields<F>(&self, fields: F) -> Self
+    where
+        F: ToString,
+    {
+        let mut c = self.clone();
+        c.fields = fields.to_string();
+        c
+    }
+
+    /// Returns the URL of a request with the fields selected with `with_fields`.
+    fn fields_url(&self, uri: &str) -> String {
+        if self.fields.is_empty() {
+            return uri.to_string();
+        }
+
+        match reqwest::Url::parse(uri) {
+            Ok(mut u) if !u.query_pairs().any(|(k, _)| k == "fields") => {
+                u.query_pairs_mut().append_pair("fields", &self.fields);
+                u.to_string()
+            }
+            _ => uri.to_string(),
+        }
+    }
+
     /// Create a new Client struct from environment variables. It
     /// takes a type that can convert into
     /// an &str (`String` or `Vec<u8>` for example). As long as the function is
@@ -388,7 +442,9 @@ impl Client {
                         refresh_token: refresh_token.to_string(),
                         expires_at: None,
                     })),
+                    fields: Default::default(),
                     auto_refresh: false,
+                    pagination: Default::default(),
                     client,
                 }
             }
@@ -512,7 +568,7 @@ impl Client {
         uri: &str,
         body: Option<reqwest::Body>,
     ) -> Result<reqwest::Request> {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -674,7 +730,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -744,7 +800,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -813,7 +869,7 @@ impl Client {
     where
         Out: serde::de::DeserializeOwned + 'static + Send,
     {
-        let u = if uri.starts_with("https://") {
+        let u = if uri.starts_with("https://") || uri.starts_with("http://") {
             uri.to_string()
         } else {
             (self.host.clone() + uri).to_string()
@@ -894,6 +950,7 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
+        let uri = &self.fields_url(uri);
         let r = self.request(method, uri, body).await?;
         Ok(r)
     }
@@ -922,30 +979,18 @@ impl Client {
     where
         D: serde::de::DeserializeOwned + 'static + Send,
     {
-        let mut global_items = Vec::new();
-        let (new_link, mut items) = self.get_pages(uri).await?;
-        let mut link = new_link;
-        while !items.is_empty() {
-            global_items.append(&mut items);
-            // We need to get the next link.
-            if let Some(url) = link.as_ref().and_then(crate::utils::next_link) {
-                let url = reqwest::Url::parse(&url)?;
-                let (new_link, new_items) = self.get_pages_url(&url).await?;
-                link = new_link;
-                items = new_items;
-            }
-        }
-
-        Ok(global_items)
-    }
+        let url = crate::utils::with_page_size(
+            &(self.host.to_string() + uri),
+            crate::utils::PAGE_SIZE_PARAM,
+            self.pagination.page_size,
+        );
+        let url = self.fields_url(&url);
 
-    #[allow(dead_code)]
-    async fn get_pages<D>(&self, uri: &str) -> Result<(Option<hyperx::header::Link>, Vec<D>)>
-    where
-        D: serde::de::DeserializeOwned + 'static + Send,
-    {
-        self.request_with_links(http::Method::GET, &(self.host.to_string() + uri), None)
-            .await
+        crate::utils::unfold(url, &self.pagination, |url| async move {
+            let url = reqwest::Url::parse(&url)?;
+            self.get_pages_url(&url).await
+        })
+        .await
     }
 
     #[allow(dead_code)]
@@ -1049,4 +1094,9 @@ impl Client {
     pub fn teamdrives(&self) -> teamdrives::Teamdrives {
         teamdrives::Teamdrives::new(self.clone())
     }
+
+    /// Return a batch, to send up to 100 requests together in a single request.
+    pub fn batch(&self) -> batch::Batch {
+        batch::Batch::new(self.clone())
+    }
 }
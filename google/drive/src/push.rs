@@ -0,0 +1,468 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+
+/// How long before it expires a channel is renewed, by default.
+pub const DEFAULT_RENEW_BEFORE: std::time::Duration = std::time::Duration::from_secs(60 * 60);
+
+type WatchFn = Arc<
+    dyn Fn(
+            crate::Client,
+            crate::types::Channel,
+        ) -> Pin<Box<dyn Future<Output = Result<crate::types::Channel>> + Send>>
+        + Send
+        + Sync,
+>;
+
+/// Tracks the push notification channels of a client, to verify the
+/// notifications they receive and renew them before they expire.
+///
+/// Channels are created with `watch`, which is given the generated `watch`
+/// function to call:
+///
+/// ```ignore
+/// let mut channels = PushChannels::new(client);
+/// let channel = channels
+///     .watch(
+///         "https://example.com/notifications",
+///         std::time::Duration::from_secs(24 * 60 * 60),
+///         |c, channel| async move { c.files().watch("id", false, "", true, false, &channel).await },
+///     )
+///     .await?;
+///
+/// // When a notification is received.
+/// let notification = Notification::from_headers(request.headers())?;
+/// let channel = channels.verify(&notification)?;
+///
+/// // Periodically, up to `next_renewal`.
+/// channels.renew_expiring().await?;
+/// ```
+///
+/// The channels outlive the process, so their `state` can be saved and
+/// tracked again after a restart with `track`.
+pub struct PushChannels {
+    client: crate::Client,
+    renew_before: std::time::Duration,
+    channels: Vec<WatchedChannel>,
+}
+
+/// The state of a tracked channel, to track it again with
+/// `PushChannels::track`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ChannelState {
+    pub channel: crate::types::Channel,
+    /// How long the channel is created for when it is renewed.
+    pub ttl: std::time::Duration,
+    /// The channel was replaced, but could not be stopped yet.
+    #[serde(default)]
+    pub replaced: bool,
+}
+
+struct WatchedChannel {
+    state: ChannelState,
+    watch: WatchFn,
+}
+
+/// The errors of `renew_expiring`, which renews the other channels when one
+/// of them fails.
+#[derive(Debug)]
+pub struct RenewError {
+    /// The channels that were renewed.
+    pub renewed: Vec<crate::types::Channel>,
+    /// The ID of each channel that could not be renewed or stopped, with its
+    /// error.
+    pub errors: Vec<(String, anyhow::Error)>,
+}
+
+impl std::fmt::Display for RenewError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "could not renew {} channels", self.errors.len())?;
+        for (id, e) in &self.errors {
+            write!(f, ", {}: {}", id, e)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for RenewError {}
+
+impl PushChannels {
+    /// Track the channels created with `client`.
+    pub fn new(client: crate::Client) -> Self {
+        PushChannels {
+            // The channels need every field of the responses.
+            client: client.with_fields(""),
+            renew_before: DEFAULT_RENEW_BEFORE,
+            channels: Vec::new(),
+        }
+    }
+
+    /// Renew channels when they expire in less than `renew_before`.
+    pub fn with_renew_before(mut self, renew_before: std::time::Duration) -> Self {
+        self.renew_before = renew_before;
+        self
+    }
+
+    /// Create a channel that sends notifications to `address` for `ttl`, and
+    /// track it.
+    ///
+    /// `watch` is given the channel to create, with a new ID and token, and
+    /// must create it with the `watch` function of the resource to watch. It
+    /// is called again with a new channel every time the channel is renewed.
+    pub async fn watch<F, Fut>(
+        &mut self,
+        address: &str,
+        ttl: std::time::Duration,
+        watch: F,
+    ) -> Result<crate::types::Channel>
+    where
+        F: Fn(crate::Client, crate::types::Channel) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<crate::types::Channel>> + Send + 'static,
+    {
+        let token = uuid::Uuid::new_v4().simple().to_string();
+        let watched = self.create(address, &token, ttl, watch_fn(watch)).await?;
+        let channel = watched.state.channel.clone();
+        self.channels.push(watched);
+
+        Ok(channel)
+    }
+
+    /// Track a channel from its saved `state`, with the `watch` function it
+    /// was created with.
+    pub fn track<F, Fut>(&mut self, state: ChannelState, watch: F)
+    where
+        F: Fn(crate::Client, crate::types::Channel) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<crate::types::Channel>> + Send + 'static,
+    {
+        self.channels
+            .retain(|w| w.state.channel.id != state.channel.id);
+        self.channels.push(WatchedChannel {
+            state,
+            watch: watch_fn(watch),
+        });
+    }
+
+    /// Returns the state of the tracked channels, to save it.
+    pub fn state(&self) -> Vec<ChannelState> {
+        self.channels.iter().map(|w| w.state.clone()).collect()
+    }
+
+    /// Returns the channels that are tracked.
+    ///
+    /// A channel that was replaced is tracked until it is stopped, so the
+    /// notifications it still sends can be verified.
+    pub fn channels(&self) -> impl Iterator<Item = &crate::types::Channel> {
+        self.channels.iter().map(|w| &w.state.channel)
+    }
+
+    /// Returns a tracked channel by its ID.
+    pub fn get(&self, channel_id: &str) -> Option<&crate::types::Channel> {
+        self.channels().find(|c| c.id == channel_id)
+    }
+
+    /// Returns the channel a notification was sent for, if the notification
+    /// is for a tracked channel and has its token.
+    pub fn verify(&self, notification: &Notification) -> Result<&crate::types::Channel> {
+        let channel = match self.get(&notification.channel_id) {
+            Some(c) => c,
+            None => bail!("unknown channel: {}", notification.channel_id),
+        };
+
+        if !constant_time_eq(
+            channel.token.as_bytes(),
+            notification.channel_token.as_bytes(),
+        ) {
+            bail!("invalid token for channel: {}", channel.id);
+        }
+        if !channel.resource_id.is_empty() && channel.resource_id != notification.resource_id {
+            bail!(
+                "channel {} watches resource {}, not {}",
+                channel.id,
+                channel.resource_id,
+                notification.resource_id
+            );
+        }
+
+        Ok(channel)
+    }
+
+    /// Returns when the next channel has to be renewed.
+    pub fn next_renewal(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.channels
+            .iter()
+            .filter(|w| !w.state.replaced)
+            .filter_map(|w| expiration(&w.state.channel))
+            .min()
+            .map(|e| e - to_chrono(self.renew_before))
+    }
+
+    /// Renew the channels that expire soon, and return the new channels.
+    ///
+    /// Google can not extend a channel, so each one is replaced with a new
+    /// channel with the same token, and then stopped. The old channel is
+    /// tracked until it is stopped, and stopping it is tried again on the
+    /// next call if it fails.
+    ///
+    /// Every channel is renewed even if some fail, which returns a
+    /// `RenewError`.
+    pub async fn renew_expiring(&mut self) -> Result<Vec<crate::types::Channel>> {
+        let now = chrono::Utc::now();
+        let renew_at = now + to_chrono(self.renew_before);
+
+        let mut renewed = Vec::new();
+        let mut stopped = Vec::new();
+        let mut errors = Vec::new();
+        for i in 0..self.channels.len() {
+            if !self.channels[i].state.replaced {
+                let old = &self.channels[i];
+                match expiration(&old.state.channel) {
+                    Some(e) if e <= renew_at => {}
+                    _ => continue,
+                }
+
+                let watched = self
+                    .create(
+                        &old.state.channel.address,
+                        &old.state.channel.token,
+                        old.state.ttl,
+                        old.watch.clone(),
+                    )
+                    .await;
+                match watched {
+                    Ok(watched) => {
+                        renewed.push(watched.state.channel.clone());
+                        self.channels[i].state.replaced = true;
+                        self.channels.push(watched);
+                    }
+                    Err(e) => {
+                        errors.push((old.state.channel.id.to_string(), e));
+                        continue;
+                    }
+                }
+            }
+
+            let old = &self.channels[i].state.channel;
+            match self.stop_channel(old).await {
+                Ok(()) => stopped.push(i),
+                // Google stops the channel when it expires anyway.
+                Err(_) if expiration(old).map(|e| e <= now).unwrap_or(false) => stopped.push(i),
+                Err(e) => errors.push((old.id.to_string(), e)),
+            }
+        }
+
+        for i in stopped.into_iter().rev() {
+            self.channels.remove(i);
+        }
+
+        if !errors.is_empty() {
+            return Err(RenewError { renewed, errors }.into());
+        }
+
+        Ok(renewed)
+    }
+
+    /// Stop a channel and stop tracking it.
+    pub async fn stop(&mut self, channel_id: &str) -> Result<()> {
+        let i = match self
+            .channels
+            .iter()
+            .position(|w| w.state.channel.id == channel_id)
+        {
+            Some(i) => i,
+            None => bail!("unknown channel: {}", channel_id),
+        };
+
+        self.stop_channel(&self.channels[i].state.channel).await?;
+        self.channels.remove(i);
+
+        Ok(())
+    }
+
+    /// Stop every channel that is tracked.
+    ///
+    /// The channels that could not be stopped are still tracked.
+    pub async fn stop_all(&mut self) -> Result<()> {
+        let mut error = None;
+        let mut failed = Vec::new();
+        for watched in std::mem::take(&mut self.channels) {
+            if let Err(e) = self.stop_channel(&watched.state.channel).await {
+                error.get_or_insert(e);
+                failed.push(watched);
+            }
+        }
+        self.channels = failed;
+
+        match error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    async fn create(
+        &self,
+        address: &str,
+        token: &str,
+        ttl: std::time::Duration,
+        watch: WatchFn,
+    ) -> Result<WatchedChannel> {
+        let expiration = chrono::Utc::now() + to_chrono(ttl);
+        let request: crate::types::Channel = serde_json::from_value(serde_json::json!({
+            "id": uuid::Uuid::new_v4().to_string(),
+            "type": "web_hook",
+            "address": address,
+            "token": token,
+            "expiration": expiration.timestamp_millis(),
+        }))?;
+
+        let created = watch(self.client.clone(), request.clone()).await?;
+
+        let mut channel = request;
+        channel.resource_id = created.resource_id;
+        channel.resource_uri = created.resource_uri;
+        // Google can shorten the expiration we asked for.
+        if created.expiration > 0 {
+            channel.expiration = created.expiration;
+        }
+
+        Ok(WatchedChannel {
+            state: ChannelState {
+                channel,
+                ttl,
+                replaced: false,
+            },
+            watch,
+        })
+    }
+
+    async fn stop_channel(&self, channel: &crate::types::Channel) -> Result<()> {
+        self.client.channels().stop(channel).await
+    }
+}
+
+fn watch_fn<F, Fut>(watch: F) -> WatchFn
+where
+    F: Fn(crate::Client, crate::types::Channel) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<crate::types::Channel>> + Send + 'static,
+{
+    Arc::new(move |c, channel| Box::pin(watch(c, channel)))
+}
+
+/// The state of the resource a notification is sent for, from the
+/// `X-Goog-Resource-State` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceState {
+    /// The channel was created, and will send notifications.
+    Sync,
+    Add,
+    Change,
+    Exists,
+    NotExists,
+    Remove,
+    Trash,
+    Untrash,
+    Update,
+    Other(String),
+}
+
+impl ResourceState {
+    fn parse(s: &str) -> Self {
+        match s {
+            "sync" => ResourceState::Sync,
+            "add" => ResourceState::Add,
+            "change" => ResourceState::Change,
+            "exists" => ResourceState::Exists,
+            "not_exists" => ResourceState::NotExists,
+            "remove" => ResourceState::Remove,
+            "trash" => ResourceState::Trash,
+            "untrash" => ResourceState::Untrash,
+            "update" => ResourceState::Update,
+            _ => ResourceState::Other(s.to_string()),
+        }
+    }
+}
+
+/// A push notification, from the `X-Goog-*` headers of the request Google
+/// sends to the address of a channel.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub channel_id: String,
+    /// The token of the channel, empty if it has none.
+    pub channel_token: String,
+    pub channel_expiration: Option<chrono::DateTime<chrono::Utc>>,
+    /// Increases with each notification of the channel, starting at 1 with
+    /// the `sync` notification.
+    pub message_number: u64,
+    pub resource_id: String,
+    pub resource_state: ResourceState,
+    pub resource_uri: String,
+    /// What changed in the resource, from the `X-Goog-Changed` header.
+    pub changed: Vec<String>,
+}
+
+impl Notification {
+    /// Parse the headers of a push notification.
+    pub fn from_headers(headers: &http::HeaderMap) -> Result<Self> {
+        let header = |name: &str| -> Result<String> {
+            match headers.get(name) {
+                Some(v) => Ok(v.to_str()?.to_string()),
+                None => Ok(String::new()),
+            }
+        };
+        let required = |name: &str| -> Result<String> {
+            let value = header(name)?;
+            if value.is_empty() {
+                bail!("the notification has no {} header", name);
+            }
+            Ok(value)
+        };
+
+        let channel_expiration = match header("x-goog-channel-expiration")?.as_str() {
+            "" => None,
+            e => Some(
+                chrono::DateTime::parse_from_rfc2822(e)
+                    .map_err(|err| anyhow!("invalid channel expiration {:?}: {}", e, err))?
+                    .with_timezone(&chrono::Utc),
+            ),
+        };
+        let message_number = match header("x-goog-message-number")?.as_str() {
+            "" => 0,
+            n => n.parse()?,
+        };
+
+        Ok(Notification {
+            channel_id: required("x-goog-channel-id")?,
+            channel_token: header("x-goog-channel-token")?,
+            channel_expiration,
+            message_number,
+            resource_id: required("x-goog-resource-id")?,
+            resource_state: ResourceState::parse(&required("x-goog-resource-state")?),
+            resource_uri: header("x-goog-resource-uri")?,
+            changed: header("x-goog-changed")?
+                .split(',')
+                .map(|c| c.trim().to_string())
+                .filter(|c| !c.is_empty())
+                .collect(),
+        })
+    }
+}
+
+fn expiration(channel: &crate::types::Channel) -> Option<chrono::DateTime<chrono::Utc>> {
+    use chrono::TimeZone;
+
+    if channel.expiration <= 0 {
+        return None;
+    }
+    chrono::Utc
+        .timestamp_millis_opt(channel.expiration)
+        .single()
+}
+
+fn to_chrono(d: std::time::Duration) -> chrono::Duration {
+    chrono::Duration::from_std(d).unwrap_or_else(|_| chrono::Duration::max_value())
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -1 +1,990 @@
+use wiremock::{
+    matchers::{method, path, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
 
+use crate::traits::ChangeOps;
+
+fn feed() -> crate::traits::ChangeFeed {
+    crate::Client::new("", "", "", "", "").changes().feed("10")
+}
+
+#[test]
+fn test_change_feed_page_url() {
+    assert_eq!(feed().page_url(), "/changes?pageToken=10");
+    assert_eq!(
+        feed()
+            .with_drive_id("shared")
+            .with_page_size(100)
+            .page_url(),
+        "/changes?driveId=shared&includeItemsFromAllDrives=true&supportsAllDrives=true&\
+         pageSize=100&pageToken=10"
+    );
+    assert_eq!(
+        feed().with_all_drives(true).page_url(),
+        "/changes?includeItemsFromAllDrives=true&supportsAllDrives=true&pageToken=10"
+    );
+}
+
+#[tokio::test]
+async fn test_change_feed() {
+    use futures::StreamExt;
+
+    let page = |changes: serde_json::Value, token: &str, value: &str| {
+        let mut page = serde_json::json!({ "kind": "drive#changeList", "changes": changes });
+        page[token] = value.into();
+        ResponseTemplate::new(200).set_body_json(page)
+    };
+    let file = serde_json::json!({
+        "kind": "drive#change",
+        "changeType": "file",
+        "fileId": "f1",
+        "removed": false,
+        "file": { "id": "f1", "name": "report.csv" },
+    });
+    let removed = serde_json::json!({
+        "kind": "drive#change",
+        "changeType": "file",
+        "fileId": "f2",
+        "removed": true,
+    });
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/changes/startPageToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(
+            serde_json::json!({ "kind": "drive#startPageToken", "startPageToken": "10" }),
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "10"))
+        .respond_with(page(
+            serde_json::json!([file, removed]),
+            "nextPageToken",
+            "11",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "11"))
+        .respond_with(page(serde_json::json!([]), "newStartPageToken", "12"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    // Nothing changed since the last feed.
+    Mock::given(method("GET"))
+        .and(path("/changes"))
+        .and(query_param("pageToken", "12"))
+        .respond_with(page(serde_json::json!([]), "newStartPageToken", "12"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let changes = crate::Client::new("", "", "", "", "")
+        .with_host(server.uri())
+        .changes();
+    let mut feed = changes.feed_from_now("").await.unwrap();
+    assert_eq!(feed.page_token(), "10");
+
+    let change = feed.next().await.unwrap().unwrap();
+    assert_eq!(change.file.unwrap().name, "report.csv");
+    // The page was not read to the end, so a restart has to list it again.
+    assert_eq!(feed.page_token(), "10");
+    let change = feed.next().await.unwrap().unwrap();
+    assert_eq!(change.removed, Some(true));
+    assert_eq!(feed.page_token(), "11");
+    assert!(feed.next().await.is_none());
+    assert_eq!(feed.page_token(), "12");
+    assert_eq!(feed.new_start_page_token(), Some("12"));
+
+    let mut feed = changes.feed("12");
+    assert!(feed.next().await.is_none());
+    assert_eq!(feed.new_start_page_token(), Some("12"));
+}
+
+/// An upload session that commits only half of the first chunk when
+/// `partial` is set.
+#[derive(Default)]
+struct UploadStub {
+    committed: Vec<u8>,
+    partial: bool,
+    /// The statuses of the next requests to the session, which fail.
+    failures: std::collections::VecDeque<u16>,
+}
+
+/// Serves an `UploadStub` from `/session`.
+#[derive(Clone)]
+struct UploadSessionStub(std::sync::Arc<std::sync::Mutex<UploadStub>>);
+
+impl UploadSessionStub {
+    async fn mount(stub: UploadStub, server: &MockServer) -> Self {
+        let session = UploadSessionStub(std::sync::Arc::new(std::sync::Mutex::new(stub)));
+        Mock::given(method("PUT"))
+            .and(path("/session"))
+            .respond_with(session.clone())
+            .mount(server)
+            .await;
+
+        session
+    }
+
+    fn committed(&self) -> Vec<u8> {
+        self.0.lock().unwrap().committed.clone()
+    }
+}
+
+impl wiremock::Respond for UploadSessionStub {
+    fn respond(&self, req: &wiremock::Request) -> ResponseTemplate {
+        let mut stub = self.0.lock().unwrap();
+        if let Some(status) = stub.failures.pop_front() {
+            return ResponseTemplate::new(status).set_body_string("failed");
+        }
+
+        let range = req.headers.get("content-range").unwrap().to_str().unwrap();
+        let (start, total) = range.trim_start_matches("bytes ").split_once('/').unwrap();
+        if start != "*" {
+            let from: usize = start.split('-').next().unwrap().parse().unwrap();
+            assert_eq!(from, stub.committed.len());
+            let mut body = req.body.as_slice();
+            if stub.partial {
+                stub.partial = false;
+                body = &body[..body.len() / 2];
+            }
+            stub.committed.extend_from_slice(body);
+        }
+
+        if total.parse::<usize>().ok() == Some(stub.committed.len()) {
+            ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "id": "f1", "size": stub.committed.len().to_string() }),
+            )
+        } else if stub.committed.is_empty() {
+            ResponseTemplate::new(308)
+        } else {
+            let range = format!("bytes=0-{}", stub.committed.len() - 1);
+            ResponseTemplate::new(308).insert_header("range", range.as_str())
+        }
+    }
+}
+
+/// The `Content-Range` of every request to the upload session.
+async fn upload_ranges(server: &MockServer) -> Vec<String> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|r| r.url.path() == "/session")
+        .map(|r| {
+            r.headers
+                .get("content-range")
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string()
+        })
+        .collect()
+}
+
+fn upload_contents(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i % 251) as u8).collect()
+}
+
+fn upload_options() -> crate::traits::UploadOptions {
+    crate::traits::UploadOptions {
+        chunk_size: crate::traits::UPLOAD_CHUNK_ALIGNMENT,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_upload_in_chunks() {
+    use crate::traits::UploadOps;
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/files"))
+        .and(query_param("uploadType", "resumable"))
+        .respond_with(ResponseTemplate::new(200).insert_header("location", "/session"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let stub = UploadSessionStub::mount(
+        UploadStub {
+            partial: true,
+            ..Default::default()
+        },
+        &server,
+    )
+    .await;
+    let files = crate::Client::new("", "", "", "", "")
+        .with_host(server.uri())
+        .files();
+
+    let session = files
+        .start_upload("", &Default::default(), None)
+        .await
+        .unwrap();
+    let session = crate::traits::UploadSession {
+        uri: format!("{}{}", server.uri(), session.uri),
+        ..session
+    };
+
+    let contents = upload_contents(600 * 1024);
+    let file = files
+        .upload(&session, contents.as_slice(), &upload_options())
+        .await
+        .unwrap();
+
+    assert_eq!(file.id, "f1");
+    assert!(stub.committed() == contents);
+    assert_eq!(
+        upload_ranges(&server).await,
+        vec![
+            "bytes 0-262143/*",
+            // Only half of the first chunk was committed, so send the rest again.
+            "bytes 131072-262143/*",
+            "bytes 262144-524287/*",
+            "bytes 524288-614399/614400",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_resume_upload() {
+    use crate::traits::UploadOps;
+
+    let contents = upload_contents(3 * crate::traits::UPLOAD_CHUNK_ALIGNMENT);
+    let server = MockServer::start().await;
+    let stub = UploadSessionStub::mount(
+        UploadStub {
+            committed: contents[..crate::traits::UPLOAD_CHUNK_ALIGNMENT].to_vec(),
+            ..Default::default()
+        },
+        &server,
+    )
+    .await;
+    let files = crate::Client::new("", "", "", "", "").files();
+
+    // A session persisted before a restart.
+    let session: crate::traits::UploadSession = serde_json::from_value(serde_json::json!({
+        "uri": format!("{}/session", server.uri()),
+        "size": contents.len(),
+    }))
+    .unwrap();
+    let file = files
+        .resume_upload(&session, contents.as_slice(), &upload_options())
+        .await
+        .unwrap();
+
+    assert_eq!(file.id, "f1");
+    assert!(stub.committed() == contents);
+    assert_eq!(
+        upload_ranges(&server).await,
+        vec![
+            "bytes */786432",
+            "bytes 262144-524287/786432",
+            "bytes 524288-786431/786432",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_upload_retries() {
+    use crate::traits::UploadOps;
+
+    let upload = |failures: Vec<u16>| async move {
+        let server = MockServer::start().await;
+        UploadSessionStub::mount(
+            UploadStub {
+                failures: failures.into(),
+                ..Default::default()
+            },
+            &server,
+        )
+        .await;
+        // Without the retries of the client, to see every failure.
+        let mut client = crate::Client::new("", "", "", "", "");
+        client.client = reqwest_middleware::ClientBuilder::new(reqwest::Client::new()).build();
+
+        let session = crate::traits::UploadSession {
+            uri: format!("{}/session", server.uri()),
+            ..Default::default()
+        };
+        let options = crate::traits::UploadOptions {
+            retry_interval: std::time::Duration::from_millis(1),
+            ..upload_options()
+        };
+        let result = client
+            .files()
+            .upload(&session, &upload_contents(1000)[..], &options)
+            .await;
+        (result, upload_ranges(&server).await)
+    };
+
+    // The chunk fails, and so does the first query of the committed offset.
+    let (result, ranges) = upload(vec![503, 503]).await;
+    assert_eq!(result.unwrap().id, "f1");
+    assert_eq!(
+        ranges,
+        vec![
+            "bytes 0-999/1000",
+            "bytes */*",
+            "bytes */*",
+            "bytes 0-999/1000"
+        ]
+    );
+
+    // Giving up after `max_retries`.
+    let (result, ranges) = upload(vec![429; 10]).await;
+    assert!(result.is_err());
+    assert_eq!(ranges.len(), 6);
+
+    // Client errors are not retried.
+    let (result, ranges) = upload(vec![400]).await;
+    let err = result.unwrap_err();
+    let err = err.downcast_ref::<crate::traits::UploadError>().unwrap();
+    assert_eq!(err.status, http::StatusCode::BAD_REQUEST);
+    assert!(!err.is_session_expired());
+    assert_eq!(ranges.len(), 1);
+
+    // The session must be started again.
+    let (result, ranges) = upload(vec![410]).await;
+    let err = result.unwrap_err();
+    assert!(err
+        .downcast_ref::<crate::traits::UploadError>()
+        .unwrap()
+        .is_session_expired());
+    assert_eq!(ranges.len(), 1);
+}
+
+#[tokio::test]
+async fn test_upload_chunk_size() {
+    use crate::traits::UploadOps;
+
+    let session = crate::traits::UploadSession {
+        uri: "http://127.0.0.1:1/session".to_string(),
+        ..Default::default()
+    };
+    let options = crate::traits::UploadOptions {
+        chunk_size: 1000,
+        ..Default::default()
+    };
+
+    let err = crate::Client::new("", "", "", "", "")
+        .files()
+        .upload(&session, &b"contents"[..], &options)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not a multiple"));
+}
+
+/// The method, path and content type of every request `server` received.
+async fn received(server: &MockServer) -> Vec<(String, String, String)> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|r| {
+            let content_type = r
+                .headers
+                .get("x-upload-content-type")
+                .or_else(|| r.headers.get("content-type"))
+                .map(|v| v.to_str().unwrap().to_string())
+                .unwrap_or_default();
+            (r.method.to_string(), r.url.path().to_string(), content_type)
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_create_or_update() {
+    use crate::traits::FileOps;
+
+    for existing in [false, true] {
+        let server = MockServer::start().await;
+        let files = if existing {
+            serde_json::json!({
+                "files": [{ "id": "f0", "name": "report.csv", "mimeType": "text/plain" }],
+            })
+        } else {
+            serde_json::json!({ "files": [] })
+        };
+        Mock::given(method("GET"))
+            .and(path("/files"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(files))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let (start_method, start_path) = if existing {
+            ("PATCH", "/files/f0")
+        } else {
+            ("POST", "/files")
+        };
+        Mock::given(method(start_method))
+            .and(path(start_path))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("location", format!("{}/session", server.uri()).as_str()),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("PUT"))
+            .and(path("/session"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": "f0" })),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let file = crate::Client::new("", "", "", "", "")
+            .with_host(server.uri())
+            .files()
+            .create_or_update("d1", "p1", "report.csv", "text/csv", b"a,b\n")
+            .await
+            .unwrap();
+        assert_eq!(file.id, "f0");
+
+        let requests = received(&server).await;
+        let requests: Vec<(&str, &str, &str)> = requests
+            .iter()
+            .map(|(m, p, t)| (m.as_str(), p.as_str(), t.as_str()))
+            .collect();
+        assert_eq!(
+            requests[1..],
+            [
+                (start_method, start_path, "text/csv"),
+                ("PUT", "/session", "text/csv"),
+            ]
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_with_fields_operations_only() {
+    use crate::traits::FileOps;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/files/f1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": "f1" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "files": [] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/files"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("location", format!("{}/session", server.uri()).as_str()),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/session"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "id": "f2" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("", "", "", "", "")
+        .with_host(server.uri())
+        .with_fields("id");
+    let file = client
+        .files()
+        .get("f1", false, "", true, false)
+        .await
+        .unwrap();
+    assert_eq!(file.id, "f1");
+    let file = client
+        .files()
+        .create_or_update("d1", "p1", "report.csv", "text/csv", b"a,b\n")
+        .await
+        .unwrap();
+    assert_eq!(file.id, "f2");
+
+    // The upload session is not a generated function, so it gets the whole
+    // file back.
+    let with_fields = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|r| {
+            (
+                r.method.to_string(),
+                r.url.query_pairs().any(|(k, v)| k == "fields" && v == "id"),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        with_fields,
+        vec![
+            ("GET".to_string(), true),
+            ("GET".to_string(), true),
+            ("POST".to_string(), false),
+            ("PUT".to_string(), false)
+        ]
+    );
+}
+
+#[test]
+fn test_parse_upload_range() {
+    assert_eq!(crate::traits::parse_upload_range("bytes=0-42").unwrap(), 43);
+    assert!(crate::traits::parse_upload_range("bytes 0-42").is_err());
+}
+
+#[tokio::test]
+async fn test_with_fields() {
+    let fields = "files(id,name),nextPageToken";
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/files"))
+        .and(query_param("fields", fields))
+        .and(query_param_is_missing("pageToken"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(
+                serde_json::json!({ "files": [{ "id": "a" }], "nextPageToken": "p2" }),
+            ),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/files"))
+        .and(query_param("fields", fields))
+        .and(query_param("pageToken", "p2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "files": [{ "id": "b", "name": "b.txt" }] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let files = crate::Client::new("", "", "", "", "")
+        .with_host(server.uri())
+        .with_fields(fields)
+        .files()
+        .list_all("", "", false, "", false, "", "", "", false, false, "")
+        .await
+        .unwrap();
+
+    assert_eq!(
+        files.iter().map(|f| f.id.as_str()).collect::<Vec<_>>(),
+        vec!["a", "b"]
+    );
+    assert_eq!(files[1].name, "b.txt");
+}
+
+#[tokio::test]
+async fn test_batch() {
+    // Google does not have to answer in the order of the calls.
+    let body = [
+        "--resp\r\n",
+        "Content-Type: application/http\r\n",
+        "Content-ID: <response-item1>\r\n",
+        "\r\n",
+        "HTTP/1.1 404 Not Found\r\n",
+        "Content-Type: application/json\r\n",
+        "\r\n",
+        "{\"error\":{\"code\":404}}\r\n",
+        "--resp\r\n",
+        "Content-Type: application/http\r\n",
+        "Content-ID: <response-item0>\r\n",
+        "\r\n",
+        "HTTP/1.1 200 OK\r\n",
+        "Content-Type: application/json\r\n",
+        "\r\n",
+        "{\"id\":\"a\",\"name\":\"a.txt\"}\r\n",
+        "--resp--\r\n",
+    ]
+    .concat();
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/batch/drive/v3"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(body, "multipart/mixed; boundary=resp"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("", "", "", "", "").with_host(server.uri());
+    let mut batch = client.batch();
+    let get = batch
+        .add(|c| async move { c.files().get("a", false, "", false, false).await })
+        .await
+        .unwrap();
+    let delete = batch
+        .add(|c| async move { c.files().delete("b", true, false).await })
+        .await
+        .unwrap();
+    assert_eq!(batch.len(), 2);
+
+    let responses = batch.send().await.unwrap();
+    let file = responses.get(&get).unwrap();
+    assert_eq!((file.id.as_str(), file.name.as_str()), ("a", "a.txt"));
+    let err = responses.get(&delete).unwrap_err();
+    assert!(err.to_string().starts_with("code: 404"), "{}", err);
+
+    let requests = server.received_requests().await.unwrap();
+    let boundary = requests[0]
+        .headers
+        .get("content-type")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .strip_prefix("multipart/mixed; boundary=")
+        .unwrap();
+    let sent = String::from_utf8(requests[0].body.clone()).unwrap();
+    assert!(sent.ends_with(&format!("--{}--\r\n", boundary)));
+    assert!(sent.contains("Content-ID: <item0>\r\n\r\nGET /files/a HTTP/1.1\r\n"));
+    assert!(sent.contains(
+        "Content-ID: <item1>\r\n\r\nDELETE /files/b?supportsAllDrives=true HTTP/1.1\r\n"
+    ));
+    assert!(!sent.to_lowercase().contains("authorization"));
+}
+
+#[tokio::test]
+async fn test_batch_one_request_per_call() {
+    let mut batch = crate::Client::new("", "", "", "", "").batch();
+    let err = batch.add(|_| async move { Ok(()) }).await.unwrap_err();
+    assert!(err.to_string().contains("exactly one request"));
+    assert!(batch.is_empty());
+}
+
+#[test]
+fn test_parse_batch_response() {
+    let body = b"preamble\r\n--b\r\nContent-Type: application/http\r\n\r\nHTTP/1.1 204 No Content\r\n\r\n\r\n--b--\r\n";
+    let responses = crate::batch::parse_batch_response(body, "b", 1).unwrap();
+    let call = crate::batch::BatchCall::<()>::new(0);
+    let resp = responses.response(&call).unwrap();
+    assert_eq!(resp.status, http::StatusCode::NO_CONTENT);
+    assert!(resp.body.is_empty());
+    responses.get(&call).unwrap();
+
+    assert!(crate::batch::parse_batch_response(body, "b", 0).is_err());
+}
+
+#[test]
+fn test_query() {
+    use crate::traits::{Comparison, Query};
+
+    assert_eq!(Query::new().to_string(), "");
+    assert_eq!(
+        Query::new()
+            .name_is("Bob's \\ files")
+            .folders()
+            .in_parents("p1")
+            .trashed(false)
+            .to_string(),
+        r#"name = 'Bob\'s \\ files' and mimeType = 'application/vnd.google-apps.folder' and 'p1' in parents and trashed = false"#
+    );
+
+    let time = chrono::DateTime::parse_from_rfc3339("2021-06-01T12:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    assert_eq!(
+        Query::new()
+            .full_text_contains("it's")
+            .modified_time(Comparison::AfterOrEqual, time)
+            .property("team", "o'neil")
+            .to_string(),
+        r#"fullText contains 'it\'s' and modifiedTime >= '2021-06-01T12:00:00Z' and properties has { key='team' and value='o\'neil' }"#
+    );
+
+    assert_eq!(
+        Query::new()
+            .name_contains("report")
+            .or(Query::new().mime_type("text/csv").trashed(false))
+            .not_mime_type("image/png")
+            .to_string(),
+        "(name contains 'report' or (mimeType = 'text/csv' and trashed = false)) and mimeType != \
+         'image/png'"
+    );
+    assert_eq!(
+        Query::new().mime_type("text/csv").negate().to_string(),
+        "not mimeType = 'text/csv'"
+    );
+    assert!(Query::new().name_is("a").or(Query::new()).is_empty());
+}
+
+#[tokio::test]
+async fn test_get_by_name_escapes_query() {
+    use crate::traits::FileOps;
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/files"))
+        .and(query_param(
+            "q",
+            r#"name = 'Bob\'s file' and 'p1' in parents"#,
+        ))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "files": [{ "id": "a" }] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let files = crate::Client::new("", "", "", "", "")
+        .with_host(server.uri())
+        .files()
+        .get_by_name("d1", "p1", "Bob's file")
+        .await
+        .unwrap();
+
+    assert_eq!(files[0].id, "a");
+}
+
+#[test]
+fn test_notification_from_headers() {
+    use crate::push::{Notification, ResourceState};
+
+    let mut headers = http::HeaderMap::new();
+    for (name, value) in [
+        ("X-Goog-Channel-ID", "c1"),
+        ("X-Goog-Channel-Token", "secret"),
+        ("X-Goog-Channel-Expiration", "Tue, 19 Nov 2013 01:13:52 GMT"),
+        ("X-Goog-Message-Number", "10"),
+        ("X-Goog-Resource-ID", "r1"),
+        ("X-Goog-Resource-State", "update"),
+        (
+            "X-Goog-Resource-URI",
+            "https://www.googleapis.com/drive/v3/files/f1",
+        ),
+        ("X-Goog-Changed", "content,properties"),
+    ] {
+        headers.insert(name, value.parse().unwrap());
+    }
+
+    let notification = Notification::from_headers(&headers).unwrap();
+    assert_eq!(notification.channel_id, "c1");
+    assert_eq!(notification.channel_token, "secret");
+    assert_eq!(
+        notification.channel_expiration.unwrap().to_rfc3339(),
+        "2013-11-19T01:13:52+00:00"
+    );
+    assert_eq!(notification.message_number, 10);
+    assert_eq!(notification.resource_id, "r1");
+    assert_eq!(notification.resource_state, ResourceState::Update);
+    assert_eq!(notification.changed, vec!["content", "properties"]);
+
+    headers.insert("X-Goog-Resource-State", "not_exists".parse().unwrap());
+    assert_eq!(
+        Notification::from_headers(&headers).unwrap().resource_state,
+        ResourceState::NotExists
+    );
+
+    headers.remove("X-Goog-Channel-ID");
+    assert!(Notification::from_headers(&headers).is_err());
+}
+
+/// Answer a watch request with the channel it asks for.
+fn watched(req: &wiremock::Request) -> ResponseTemplate {
+    let channel: serde_json::Value = serde_json::from_slice(&req.body).unwrap();
+    // Google sends the expiration as a string.
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "kind": "api#channel",
+        "id": channel["id"],
+        "resourceId": "r1",
+        "resourceUri": "https://www.googleapis.com/drive/v3/files/f1",
+        "expiration": channel["expiration"].to_string(),
+    }))
+}
+
+#[tokio::test]
+async fn test_push_channels() {
+    use crate::push::{Notification, PushChannels, ResourceState};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/files/f1/watch"))
+        .and(query_param("supportsAllDrives", "true"))
+        .respond_with(watched)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/channels/stop"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("", "", "", "", "").with_host(server.uri());
+    let mut channels = PushChannels::new(client);
+    let channel = channels
+        .watch(
+            "https://example.com/notifications",
+            std::time::Duration::from_secs(30 * 60),
+            |c, channel| async move {
+                c.files()
+                    .watch("f1", false, "", true, false, &channel)
+                    .await
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(channel.resource_id, "r1");
+    assert_eq!(channel.type_, "web_hook");
+    assert!(!channel.token.is_empty());
+    assert!(channels.next_renewal().unwrap() < chrono::Utc::now());
+
+    let mut notification = Notification {
+        channel_id: channel.id.to_string(),
+        channel_token: channel.token.to_string(),
+        channel_expiration: None,
+        message_number: 1,
+        resource_id: "r1".to_string(),
+        resource_state: ResourceState::Sync,
+        resource_uri: String::new(),
+        changed: vec![],
+    };
+    assert_eq!(channels.verify(&notification).unwrap().id, channel.id);
+    notification.channel_token = "forged".to_string();
+    assert!(channels.verify(&notification).is_err());
+
+    // The channel expires within the hour, so it is replaced.
+    let renewed = channels.renew_expiring().await.unwrap();
+    assert_eq!(renewed.len(), 1);
+    assert_ne!(renewed[0].id, channel.id);
+    assert_eq!(renewed[0].token, channel.token);
+    assert!(channels.get(&channel.id).is_none());
+
+    channels.stop_all().await.unwrap();
+    assert_eq!(channels.channels().count(), 0);
+
+    let stopped = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|r| r.url.path() == "/channels/stop")
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(stopped[0]["id"], channel.id.as_str());
+    assert_eq!(stopped[0]["resourceId"], "r1");
+    assert_eq!(stopped[1]["id"], renewed[0].id.as_str());
+}
+
+#[tokio::test]
+async fn test_push_channels_renew_errors() {
+    use crate::push::{ChannelState, Notification, PushChannels, RenewError, ResourceState};
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/files/f1/watch"))
+        .respond_with(watched)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/files/f2/watch"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(serde_json::json!({ "error": { "code": 404 } })),
+        )
+        .mount(&server)
+        .await;
+    // Stopping a channel fails the first time.
+    Mock::given(method("POST"))
+        .and(path("/channels/stop"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .set_body_json(serde_json::json!({ "error": { "code": 403 } })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/channels/stop"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let watch = |file: &'static str| {
+        move |c: crate::Client, channel: crate::types::Channel| async move {
+            c.files()
+                .watch(file, false, "", true, false, &channel)
+                .await
+        }
+    };
+
+    let client = crate::Client::new("", "", "", "", "").with_host(server.uri());
+    let mut channels = PushChannels::new(client.clone());
+    let ttl = std::time::Duration::from_secs(30 * 60);
+    let channel = channels
+        .watch("https://example.com/notifications", ttl, watch("f1"))
+        .await
+        .unwrap();
+
+    // A channel saved by another process, which can not be renewed.
+    let mut other = channel.clone();
+    other.id = "c2".to_string();
+    channels.track(
+        ChannelState {
+            channel: other,
+            ttl,
+            replaced: false,
+        },
+        watch("f2"),
+    );
+
+    // The first channel is renewed, but it can not be stopped yet.
+    let err = channels.renew_expiring().await.unwrap_err();
+    let err = err.downcast_ref::<RenewError>().unwrap();
+    assert_eq!(err.renewed.len(), 1);
+    let ids = err
+        .errors
+        .iter()
+        .map(|(id, _)| id.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![channel.id.as_str(), "c2"]);
+
+    // The old channel can still be verified, and is not renewed again.
+    let notification = Notification {
+        channel_id: channel.id.to_string(),
+        channel_token: channel.token.to_string(),
+        channel_expiration: None,
+        message_number: 2,
+        resource_id: "r1".to_string(),
+        resource_state: ResourceState::Update,
+        resource_uri: String::new(),
+        changed: vec![],
+    };
+    assert!(channels.verify(&notification).is_ok());
+    assert_eq!(channels.channels().count(), 3);
+
+    // The state is saved, and tracked again after a restart.
+    let state = serde_json::to_string(&channels.state()).unwrap();
+    let state: Vec<ChannelState> = serde_json::from_str(&state).unwrap();
+    assert_eq!(state, channels.state());
+    assert_eq!(state.iter().filter(|s| s.replaced).count(), 1);
+    let mut channels = PushChannels::new(client);
+    for s in state.into_iter().filter(|s| s.channel.id != "c2") {
+        channels.track(s, watch("f1"));
+    }
+
+    // Stopping the old channel is tried again.
+    let renewed = channels.renew_expiring().await.unwrap();
+    assert_eq!(renewed.len(), 1);
+    assert!(channels.verify(&notification).is_err());
+    assert_eq!(channels.channels().count(), 1);
+}
@@ -1,5 +1,15 @@
 #![allow(clippy::field_reassign_with_default)]
-use anyhow::{anyhow, Result};
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncRead, AsyncReadExt};
 
 #[async_trait::async_trait]
 pub trait PermissionOps {
@@ -157,23 +167,23 @@ impl FileOps for crate::files::Files {
         parent_id: &str,
         name: &str,
     ) -> Result<Vec<crate::types::File>> {
-        let mut query = format!("name = '{}'", name);
+        let mut query = Query::new().name_is(name);
         if !parent_id.is_empty() {
-            query = format!("{} and '{}' in parents", query, parent_id);
+            query = query.in_parents(parent_id);
         }
 
         self.list_all(
-            "drive",  // corpora
-            drive_id, // drive id
-            true,     // include_items_from_all_drives
-            "",       // include_permissions_for_view
-            false,    // include_team_drive_items
-            "",       // order_by
-            &query,   // query
-            "",       // spaces
-            true,     // supports_all_drives
-            false,    // supports_team_drives
-            "",       // team_drive_id
+            "drive",            // corpora
+            drive_id,           // drive id
+            true,               // include_items_from_all_drives
+            "",                 // include_permissions_for_view
+            false,              // include_team_drive_items
+            "",                 // order_by
+            &query.to_string(), // query
+            "",                 // spaces
+            true,               // supports_all_drives
+            false,              // supports_team_drives
+            "",                 // team_drive_id
         )
         .await
     }
@@ -191,34 +201,17 @@ impl FileOps for crate::files::Files {
     ) -> Result<crate::types::File> {
         // Create the file.
         let mut f: crate::types::File = Default::default();
-        let mut method = reqwest::Method::POST;
-        let mut uri = "https://www.googleapis.com/upload/drive/v3/files".to_string();
+        let mut file_id = String::new();
 
         // Check if the file exists.
         let files = self
             .get_by_name(drive_id, parent_id, name)
             .await
             .unwrap_or_default();
-        if files.is_empty() {
-            // Set the name,
-            f.name = name.to_string();
-            f.mime_type = mime_type.to_string();
-            if !parent_id.is_empty() {
-                f.parents = vec![parent_id.to_string()];
-            } else {
-                f.parents = vec![drive_id.to_string()];
-            }
-
-            uri += "?uploadType=resumable&supportsAllDrives=true&includeItemsFromAllDrives=true";
-
-            // Create the file.
-        } else if let Some(f) = files.get(0) {
-            method = reqwest::Method::PATCH;
-            let mut f = f.clone();
-            uri += &format!(
-                "/{}?uploadType=resumable&supportsAllDrives=true&includeItemsFromAllDrives=true",
-                f.id
-            );
+        if let Some(existing) = files.first() {
+            // Update the file.
+            f = existing.clone();
+            file_id = f.id.to_string();
 
             f.id = "".to_string();
             f.drive_id = "".to_string();
@@ -227,38 +220,32 @@ impl FileOps for crate::files::Files {
         } else {
             // Set the name,
             f.name = name.to_string();
-            f.mime_type = mime_type.to_string();
             if !parent_id.is_empty() {
                 f.parents = vec![parent_id.to_string()];
             } else {
                 f.parents = vec![drive_id.to_string()];
             }
-
-            uri += "?uploadType=resumable&supportsAllDrives=true&includeItemsFromAllDrives=true";
-
-            // Create the file.
         }
+        f.mime_type = mime_type.to_string();
 
-        // Build the request to get the URL upload location if we need to create the file.
-        let resp = self
-            .client
-            .request_raw(
-                method,
-                &uri,
-                Some(reqwest::Body::from(serde_json::to_vec(&f)?)),
-            )
+        // Get the URL upload location, then upload the file to that location.
+        let session = self
+            .start_upload(&file_id, &f, Some(contents.len() as u64))
             .await?;
-
-        // Get the "Location" header.
-        let location = match resp.headers().get("Location") {
-            Some(location) => location.to_str()?,
-            None => anyhow::bail!("No Location header"),
-        };
-
-        // Now upload the file to that location.
-        self.client
-            .request_with_mime(reqwest::Method::PUT, location, contents, mime_type)
-            .await
+        match self.upload(&session, contents, &Default::default()).await {
+            Err(e)
+                if e.downcast_ref::<UploadError>()
+                    .map(UploadError::is_session_expired)
+                    .unwrap_or_default() =>
+            {
+                // Start over in a new session.
+                let session = self
+                    .start_upload(&file_id, &f, Some(contents.len() as u64))
+                    .await?;
+                self.upload(&session, contents, &Default::default()).await
+            }
+            result => result,
+        }
     }
 
     /// Download a file by it's ID.
@@ -277,39 +264,35 @@ impl FileOps for crate::files::Files {
 
     /// Create a folder, if it doesn't exist, returns the ID of the folder.
     async fn create_folder(&self, drive_id: &str, parent_id: &str, name: &str) -> Result<String> {
-        let folder_mime_type = "application/vnd.google-apps.folder";
         let mut file: crate::types::File = Default::default();
         // Set the name,
         file.name = name.to_string();
-        file.mime_type = folder_mime_type.to_string();
+        file.mime_type = FOLDER_MIME_TYPE.to_string();
         if !parent_id.is_empty() {
             file.parents = vec![parent_id.to_string()];
         } else {
             file.parents = vec![drive_id.to_string()];
         }
 
-        let mut query = format!(
-            "name = '{}' and mimeType = 'application/vnd.google-apps.folder'",
-            name
-        );
+        let mut query = Query::new().name_is(name).mime_type(FOLDER_MIME_TYPE);
         if !parent_id.is_empty() {
-            query = format!("{} and '{}' in parents", query, parent_id);
+            query = query.in_parents(parent_id);
         }
 
         // Check if the folder exists.
         let folders = self
             .list_all(
-                "drive",  // corpora
-                drive_id, // drive id
-                true,     // include_items_from_all_drives
-                "",       // include_permissions_for_view
-                false,    // include_team_drive_items
-                "",       // order_by
-                &query,   // query
-                "",       // spaces
-                true,     // supports_all_drives
-                false,    // supports_team_drives
-                "",       // team_drive_id
+                "drive",            // corpora
+                drive_id,           // drive id
+                true,               // include_items_from_all_drives
+                "",                 // include_permissions_for_view
+                false,              // include_team_drive_items
+                "",                 // order_by
+                &query.to_string(), // query
+                "",                 // spaces
+                true,               // supports_all_drives
+                false,              // supports_team_drives
+                "",                 // team_drive_id
             )
             .await
             .unwrap_or_default();
@@ -376,6 +359,195 @@ impl FileOps for crate::files::Files {
     }
 }
 
+/// The MIME type of Google Drive folders.
+pub const FOLDER_MIME_TYPE: &str = "application/vnd.google-apps.folder";
+
+/// A query for searching files, in the syntax of the `q` parameter of
+/// `files().list` and `files().list_all`.
+///
+/// Every condition is quoted and escaped, and the conditions are joined with
+/// `and`:
+///
+/// ```ignore
+/// let query = Query::new()
+///     .name_contains("Bob's")
+///     .in_parents(parent_id)
+///     .trashed(false);
+/// let files = client.files().list_all("", "", false, "", false, "", &query.to_string(), "", false, false, "").await?;
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    terms: Vec<String>,
+}
+
+/// Compares the times of files to a time in a `Query`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    Before,
+    BeforeOrEqual,
+    After,
+    AfterOrEqual,
+}
+
+impl Comparison {
+    fn operator(&self) -> &'static str {
+        match self {
+            Comparison::Equal => "=",
+            Comparison::NotEqual => "!=",
+            Comparison::Before => "<",
+            Comparison::BeforeOrEqual => "<=",
+            Comparison::After => ">",
+            Comparison::AfterOrEqual => ">=",
+        }
+    }
+}
+
+impl Query {
+    /// Returns a query that matches every file.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Match files that also match a raw condition, which is not escaped.
+    pub fn raw(mut self, condition: &str) -> Self {
+        self.terms.push(condition.to_string());
+        self
+    }
+
+    /// Match files with exactly this name.
+    pub fn name_is(self, name: &str) -> Self {
+        self.raw(&format!("name = {}", quote_query(name)))
+    }
+
+    /// Match files whose name starts with a word of `text`.
+    pub fn name_contains(self, text: &str) -> Self {
+        self.raw(&format!("name contains {}", quote_query(text)))
+    }
+
+    /// Match files whose name, description, content or indexable text
+    /// contains `text`.
+    pub fn full_text_contains(self, text: &str) -> Self {
+        self.raw(&format!("fullText contains {}", quote_query(text)))
+    }
+
+    /// Match files of a MIME type.
+    pub fn mime_type(self, mime_type: &str) -> Self {
+        self.raw(&format!("mimeType = {}", quote_query(mime_type)))
+    }
+
+    /// Match files that are not of a MIME type.
+    pub fn not_mime_type(self, mime_type: &str) -> Self {
+        self.raw(&format!("mimeType != {}", quote_query(mime_type)))
+    }
+
+    /// Match folders.
+    pub fn folders(self) -> Self {
+        self.mime_type(FOLDER_MIME_TYPE)
+    }
+
+    /// Match files that are directly in the folder `parent_id`.
+    pub fn in_parents(self, parent_id: &str) -> Self {
+        self.raw(&format!("{} in parents", quote_query(parent_id)))
+    }
+
+    /// Match files that are, or are not, in the trash.
+    pub fn trashed(self, trashed: bool) -> Self {
+        self.raw(&format!("trashed = {}", trashed))
+    }
+
+    /// Match files whose modification time compares to `time`.
+    pub fn modified_time(
+        self,
+        comparison: Comparison,
+        time: chrono::DateTime<chrono::Utc>,
+    ) -> Self {
+        self.raw(&format!(
+            "modifiedTime {} {}",
+            comparison.operator(),
+            quote_query(&time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+        ))
+    }
+
+    /// Match files modified after `time`.
+    pub fn modified_after(self, time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.modified_time(Comparison::After, time)
+    }
+
+    /// Match files modified before `time`.
+    pub fn modified_before(self, time: chrono::DateTime<chrono::Utc>) -> Self {
+        self.modified_time(Comparison::Before, time)
+    }
+
+    /// Match files with a public custom property.
+    pub fn property(self, key: &str, value: &str) -> Self {
+        self.raw(&format!(
+            "properties has {{ key={} and value={} }}",
+            quote_query(key),
+            quote_query(value)
+        ))
+    }
+
+    /// Match files with a custom property private to the app.
+    pub fn app_property(self, key: &str, value: &str) -> Self {
+        self.raw(&format!(
+            "appProperties has {{ key={} and value={} }}",
+            quote_query(key),
+            quote_query(value)
+        ))
+    }
+
+    /// Match files that match this query or `other`.
+    pub fn or(self, other: Query) -> Self {
+        if self.terms.is_empty() || other.terms.is_empty() {
+            return Query::new();
+        }
+
+        Query {
+            terms: vec![format!("({} or {})", self.group(), other.group())],
+        }
+    }
+
+    /// Match files that do not match this query.
+    ///
+    /// An empty query is returned as is, and still matches every file.
+    pub fn negate(self) -> Self {
+        if self.terms.is_empty() {
+            return self;
+        }
+
+        Query {
+            terms: vec![format!("not {}", self.group())],
+        }
+    }
+
+    /// Returns true if the query has no conditions, and matches every file.
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns the conditions, in parentheses if there is more than one.
+    fn group(&self) -> String {
+        if self.terms.len() == 1 {
+            self.terms[0].to_string()
+        } else {
+            format!("({})", self)
+        }
+    }
+}
+
+impl std::fmt::Display for Query {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.terms.join(" and "))
+    }
+}
+
+/// Quote a value of a query, escaping its backslashes and single quotes.
+pub fn quote_query(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
 #[async_trait::async_trait]
 pub trait DriveOps {
     /// Get a drive by it's name.
@@ -402,3 +574,647 @@ impl DriveOps for crate::drives::Drives {
         Err(anyhow!("could not find drive with name: {:?}", name))
     }
 }
+
+#[async_trait::async_trait]
+pub trait ChangeOps {
+    /// Get a feed of the changes made since `page_token`.
+    ///
+    /// `page_token` is either a token returned by `get_start_page_token` or one
+    /// previously checkpointed from a `ChangeFeed`.
+    fn feed(&self, page_token: &str) -> ChangeFeed;
+
+    /// Get a feed that starts at the current start page token of the user's
+    /// corpus, or of the shared drive if `drive_id` is not empty.
+    async fn feed_from_now(&self, drive_id: &str) -> Result<ChangeFeed>;
+}
+
+#[async_trait::async_trait]
+impl ChangeOps for crate::changes::Changes {
+    /// Get a feed of the changes made since `page_token`.
+    ///
+    /// `page_token` is either a token returned by `get_start_page_token` or one
+    /// previously checkpointed from a `ChangeFeed`.
+    fn feed(&self, page_token: &str) -> ChangeFeed {
+        ChangeFeed::new(self.client.clone(), page_token)
+    }
+
+    /// Get a feed that starts at the current start page token of the user's
+    /// corpus, or of the shared drive if `drive_id` is not empty.
+    async fn feed_from_now(&self, drive_id: &str) -> Result<ChangeFeed> {
+        let token = self
+            .get_start_page_token(
+                drive_id,
+                !drive_id.is_empty(), // supports all drives
+                false,
+                "",
+            )
+            .await?;
+        if token.start_page_token.is_empty() {
+            return Err(anyhow!("did not get a start page token"));
+        }
+
+        Ok(self.feed(&token.start_page_token).with_drive_id(drive_id))
+    }
+}
+
+type ChangePage = Result<crate::types::ChangeList>;
+
+/// A stream of the changes to a user's corpus or a shared drive.
+///
+/// Drive links the pages of changes with `nextPageToken`, and gives a
+/// `newStartPageToken` on the last one, where the stream ends. `page_token` is
+/// the token of the page being read, and only moves on once all its changes
+/// were yielded: a feed started again from it can see a change twice, but
+/// never misses one.
+pub struct ChangeFeed {
+    client: crate::Client,
+    page_token: String,
+    drive_id: String,
+    all_drives: bool,
+    page_size: i64,
+    spaces: String,
+
+    changes: VecDeque<crate::types::Change>,
+    next_page: Option<String>,
+    new_start_page_token: Option<String>,
+    fetch: Option<Pin<Box<dyn Future<Output = ChangePage> + Send>>>,
+    done: bool,
+}
+
+impl ChangeFeed {
+    fn new(client: crate::Client, page_token: &str) -> Self {
+        ChangeFeed {
+            client,
+            page_token: page_token.to_string(),
+            drive_id: Default::default(),
+            all_drives: false,
+            page_size: 0,
+            spaces: Default::default(),
+            changes: Default::default(),
+            next_page: None,
+            new_start_page_token: None,
+            fetch: None,
+            done: false,
+        }
+    }
+
+    /// List the changes of a shared drive, rather than the user's corpus.
+    pub fn with_drive_id(mut self, drive_id: &str) -> Self {
+        self.drive_id = drive_id.to_string();
+        self
+    }
+
+    /// Include the changes to the shared drives the user is a member of, with
+    /// the changes to the user's corpus.
+    ///
+    /// The changes of a shared drive set with `with_drive_id` are always
+    /// included.
+    pub fn with_all_drives(mut self, all_drives: bool) -> Self {
+        self.all_drives = all_drives;
+        self
+    }
+
+    /// Set the maximum number of changes to request per page.
+    pub fn with_page_size(mut self, page_size: i64) -> Self {
+        self.page_size = page_size;
+        self
+    }
+
+    /// Set the comma-separated list of spaces to query within the user corpus.
+    pub fn with_spaces(mut self, spaces: &str) -> Self {
+        self.spaces = spaces.to_string();
+        self
+    }
+
+    /// The token of the page being read, to save and start a feed from after a
+    /// restart.
+    ///
+    /// Once the feed has ended, this is the new start page token.
+    pub fn page_token(&self) -> &str {
+        &self.page_token
+    }
+
+    /// The token to start the next feed from, once this one has ended.
+    pub fn new_start_page_token(&self) -> Option<&str> {
+        self.new_start_page_token.as_deref()
+    }
+
+    pub(crate) fn page_url(&self) -> String {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !self.drive_id.is_empty() {
+            query_args.push(("driveId".to_string(), self.drive_id.to_string()));
+        }
+        if self.all_drives || !self.drive_id.is_empty() {
+            query_args.push(("includeItemsFromAllDrives".to_string(), "true".to_string()));
+            query_args.push(("supportsAllDrives".to_string(), "true".to_string()));
+        }
+        if self.page_size > 0 {
+            query_args.push(("pageSize".to_string(), self.page_size.to_string()));
+        }
+        query_args.push(("pageToken".to_string(), self.page_token.to_string()));
+        if !self.spaces.is_empty() {
+            query_args.push(("spaces".to_string(), self.spaces.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+
+        format!("/changes?{}", query_)
+    }
+
+    fn handle_page(&mut self, page: crate::types::ChangeList) {
+        if !page.next_page_token.is_empty() && page.next_page_token != self.page_token {
+            self.next_page = Some(page.next_page_token);
+        } else if !page.new_start_page_token.is_empty() {
+            self.new_start_page_token = Some(page.new_start_page_token);
+        } else {
+            // We have no way to keep going, so stay where we are.
+            self.done = true;
+        }
+
+        self.changes = page.changes.into();
+        if self.changes.is_empty() {
+            self.advance();
+        }
+    }
+
+    fn pop_change(&mut self) -> Option<crate::types::Change> {
+        let change = self.changes.pop_front()?;
+
+        // Drive can only list changes from the start of a page.
+        if self.changes.is_empty() {
+            self.advance();
+        }
+
+        Some(change)
+    }
+
+    fn advance(&mut self) {
+        if let Some(next) = self.next_page.take() {
+            self.page_token = next;
+        } else if let Some(new_start) = &self.new_start_page_token {
+            self.page_token = new_start.to_string();
+            self.done = true;
+        }
+    }
+}
+
+impl futures::Stream for ChangeFeed {
+    type Item = Result<crate::types::Change>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(change) = self.pop_change() {
+                return Poll::Ready(Some(Ok(change)));
+            }
+
+            if self.fetch.is_none() {
+                if self.done {
+                    return Poll::Ready(None);
+                }
+
+                let url = self.page_url();
+                let client = self.client.clone();
+                self.fetch = Some(Box::pin(async move { client.get(&url, None).await }));
+            }
+
+            let page = futures::ready!(self.fetch.as_mut().unwrap().as_mut().poll(cx));
+            self.fetch = None;
+            match page {
+                Ok(page) => self.handle_page(page),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
+
+/// The size of the chunks of a resumable upload must be a multiple of this.
+pub const UPLOAD_CHUNK_ALIGNMENT: usize = 256 * 1024;
+
+/// A resumable upload session.
+///
+/// Sessions are valid for a week, so this can be persisted to resume an
+/// upload after the process restarts.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct UploadSession {
+    /// The URI of the session.
+    pub uri: String,
+    /// The total size of the upload, if it is known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    /// The MIME type of the contents, sent with every chunk.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub mime_type: String,
+}
+
+/// The state of a resumable upload session.
+#[derive(Debug, Clone)]
+pub enum UploadStatus {
+    /// The number of bytes that have been committed so far.
+    Incomplete(u64),
+    /// The upload is done.
+    Complete(Box<crate::types::File>),
+}
+
+/// A request to a resumable upload session failed.
+#[derive(Debug, Clone)]
+pub struct UploadError {
+    pub status: http::StatusCode,
+    pub body: String,
+}
+
+impl UploadError {
+    /// Returns true if the request can be sent again: the session is asked
+    /// for the committed offset and the upload resumes from there.
+    pub fn is_retryable(&self) -> bool {
+        self.status.is_server_error()
+            || self.status == http::StatusCode::REQUEST_TIMEOUT
+            || self.status == http::StatusCode::TOO_MANY_REQUESTS
+    }
+
+    /// Returns true if the session expired, the upload must then start over
+    /// in a new session.
+    pub fn is_session_expired(&self) -> bool {
+        self.status == http::StatusCode::NOT_FOUND || self.status == http::StatusCode::GONE
+    }
+}
+
+impl std::fmt::Display for UploadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "code: {}, error: {:?}", self.status, self.body)
+    }
+}
+
+impl std::error::Error for UploadError {}
+
+/// How to upload the contents of a resumable upload session.
+#[derive(Debug, Clone)]
+pub struct UploadOptions {
+    /// The number of bytes to send per request, a multiple of `UPLOAD_CHUNK_ALIGNMENT`.
+    pub chunk_size: usize,
+    /// How many times to resume after a request fails, before giving up.
+    pub max_retries: u32,
+    /// How long to wait before the first retry, this doubles after every retry.
+    pub retry_interval: Duration,
+}
+
+impl Default for UploadOptions {
+    fn default() -> Self {
+        UploadOptions {
+            chunk_size: 32 * UPLOAD_CHUNK_ALIGNMENT,
+            max_retries: 5,
+            retry_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+pub trait UploadOps {
+    /// Start a resumable upload session.
+    ///
+    /// If `file_id` is empty this creates a new file with the metadata in `file`,
+    /// otherwise it uploads a new revision of that file.
+    async fn start_upload(
+        &self,
+        file_id: &str,
+        file: &crate::types::File,
+        size: Option<u64>,
+    ) -> Result<UploadSession>;
+
+    /// Ask the session how much of the upload has been committed.
+    async fn upload_status(&self, session: &UploadSession) -> Result<UploadStatus>;
+
+    /// Upload all of `reader` to a new session.
+    ///
+    /// If a request fails with a network error or a retryable `UploadError`,
+    /// the session is asked for the committed offset and the upload resumes
+    /// from there. Other errors are returned right away; if the session
+    /// expired the upload must start over in a new session.
+    async fn upload<R>(
+        &self,
+        session: &UploadSession,
+        reader: R,
+        options: &UploadOptions,
+    ) -> Result<crate::types::File>
+    where
+        R: AsyncRead + Unpin + Send;
+
+    /// Resume an upload, from the offset the session has committed.
+    ///
+    /// `reader` must start at the beginning of the contents: the bytes that
+    /// were already committed are skipped.
+    async fn resume_upload<R>(
+        &self,
+        session: &UploadSession,
+        reader: R,
+        options: &UploadOptions,
+    ) -> Result<crate::types::File>
+    where
+        R: AsyncRead + Unpin + Send;
+}
+
+#[async_trait::async_trait]
+impl UploadOps for crate::files::Files {
+    /// Start a resumable upload session.
+    ///
+    /// If `file_id` is empty this creates a new file with the metadata in `file`,
+    /// otherwise it uploads a new revision of that file.
+    async fn start_upload(
+        &self,
+        file_id: &str,
+        file: &crate::types::File,
+        size: Option<u64>,
+    ) -> Result<UploadSession> {
+        let upload_host = self.client.host.replace("/drive/v3", "/upload/drive/v3");
+        let (method, uri) = if file_id.is_empty() {
+            (reqwest::Method::POST, format!("{}/files", upload_host))
+        } else {
+            (
+                reqwest::Method::PATCH,
+                format!(
+                    "{}/files/{}",
+                    upload_host,
+                    crate::progenitor_support::encode_path(file_id)
+                ),
+            )
+        };
+        let uri = format!("{}?uploadType=resumable&supportsAllDrives=true", uri);
+
+        let mut req = self
+            .client
+            .make_request(
+                &method,
+                &uri,
+                Some(reqwest::Body::from(serde_json::to_vec(file)?)),
+            )
+            .await?;
+        if !file.mime_type.is_empty() {
+            req.headers_mut().insert(
+                reqwest::header::HeaderName::from_static("x-upload-content-type"),
+                reqwest::header::HeaderValue::from_str(&file.mime_type)?,
+            );
+        }
+        if let Some(size) = size {
+            req.headers_mut().insert(
+                reqwest::header::HeaderName::from_static("x-upload-content-length"),
+                reqwest::header::HeaderValue::from(size),
+            );
+        }
+
+        let resp = self.client.client.execute(req).await?;
+        let status = resp.status();
+        if !status.is_success() {
+            bail!(
+                "code: {}, error: {:?}",
+                status,
+                String::from_utf8_lossy(&resp.bytes().await?)
+            );
+        }
+
+        // Get the "Location" header.
+        let uri = match resp.headers().get(reqwest::header::LOCATION) {
+            Some(location) => location.to_str()?.to_string(),
+            None => bail!("No Location header"),
+        };
+
+        Ok(UploadSession {
+            uri,
+            size,
+            mime_type: file.mime_type.to_string(),
+        })
+    }
+
+    /// Ask the session how much of the upload has been committed.
+    async fn upload_status(&self, session: &UploadSession) -> Result<UploadStatus> {
+        let range = match session.size {
+            Some(size) => format!("bytes */{}", size),
+            None => "bytes */*".to_string(),
+        };
+
+        self.put_chunk(session, &range, &[]).await
+    }
+
+    /// Upload all of `reader` to a new session.
+    ///
+    /// If a request fails with a network error or a retryable `UploadError`,
+    /// the session is asked for the committed offset and the upload resumes
+    /// from there. Other errors are returned right away; if the session
+    /// expired the upload must start over in a new session.
+    async fn upload<R>(
+        &self,
+        session: &UploadSession,
+        reader: R,
+        options: &UploadOptions,
+    ) -> Result<crate::types::File>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        self.upload_from(session, reader, 0, options).await
+    }
+
+    /// Resume an upload, from the offset the session has committed.
+    ///
+    /// `reader` must start at the beginning of the contents: the bytes that
+    /// were already committed are skipped.
+    async fn resume_upload<R>(
+        &self,
+        session: &UploadSession,
+        mut reader: R,
+        options: &UploadOptions,
+    ) -> Result<crate::types::File>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        let offset = match self.upload_status(session).await? {
+            UploadStatus::Complete(file) => return Ok(*file),
+            UploadStatus::Incomplete(offset) => offset,
+        };
+
+        let skipped =
+            tokio::io::copy(&mut (&mut reader).take(offset), &mut tokio::io::sink()).await?;
+        if skipped < offset {
+            bail!(
+                "the session has committed {} bytes, but the reader only has {}",
+                offset,
+                skipped
+            );
+        }
+
+        self.upload_from(session, reader, offset, options).await
+    }
+}
+
+impl crate::files::Files {
+    // `usize::is_multiple_of` is too new for the toolchains the crate builds on.
+    #[allow(unknown_lints, clippy::manual_is_multiple_of)]
+    async fn upload_from<R>(
+        &self,
+        session: &UploadSession,
+        mut reader: R,
+        mut offset: u64,
+        options: &UploadOptions,
+    ) -> Result<crate::types::File>
+    where
+        R: AsyncRead + Unpin + Send,
+    {
+        if options.chunk_size == 0 || options.chunk_size % UPLOAD_CHUNK_ALIGNMENT != 0 {
+            bail!(
+                "chunk size {} is not a multiple of {}",
+                options.chunk_size,
+                UPLOAD_CHUNK_ALIGNMENT
+            );
+        }
+
+        let mut chunk = Vec::with_capacity(options.chunk_size);
+        loop {
+            // Read the next chunk, we keep it around to send it again if the
+            // session did not commit all of it.
+            let chunk_start = offset;
+            chunk.clear();
+            while chunk.len() < options.chunk_size {
+                let n = (&mut reader)
+                    .take((options.chunk_size - chunk.len()) as u64)
+                    .read_to_end(&mut chunk)
+                    .await?;
+                if n == 0 {
+                    break;
+                }
+            }
+            let chunk_end = chunk_start + chunk.len() as u64;
+
+            // If the chunk is not full, we are at the end and know the size.
+            let size = if chunk.len() < options.chunk_size {
+                Some(chunk_end)
+            } else {
+                session.size
+            };
+            let total = match size {
+                Some(size) => size.to_string(),
+                None => "*".to_string(),
+            };
+
+            let mut retries = 0;
+            let mut interval = options.retry_interval;
+            loop {
+                let data = &chunk[(offset - chunk_start) as usize..];
+                let range = if data.is_empty() {
+                    format!("bytes */{}", total)
+                } else {
+                    format!("bytes {}-{}/{}", offset, chunk_end - 1, total)
+                };
+
+                let mut result = self.put_chunk(session, &range, data).await;
+                let status = loop {
+                    let e = match result {
+                        Ok(status) => break status,
+                        Err(e) => e,
+                    };
+                    if !is_retryable_upload_error(&e) {
+                        return Err(e);
+                    }
+                    retries += 1;
+                    if retries > options.max_retries {
+                        return Err(e);
+                    }
+                    tokio::time::sleep(interval).await;
+                    interval *= 2;
+
+                    // Find out how much made it before the failure, asking
+                    // again if that fails too.
+                    result = self.upload_status(session).await;
+                };
+
+                match status {
+                    UploadStatus::Complete(file) => return Ok(*file),
+                    UploadStatus::Incomplete(committed) => {
+                        if committed < chunk_start || committed > chunk_end {
+                            bail!(
+                                "the session committed {} bytes, expected between {} and {}",
+                                committed,
+                                chunk_start,
+                                chunk_end
+                            );
+                        }
+                        offset = committed;
+                    }
+                }
+
+                if data.is_empty() {
+                    bail!(
+                        "the session did not complete after sending all {} bytes",
+                        offset
+                    );
+                } else if offset == chunk_end {
+                    break;
+                }
+            }
+        }
+    }
+
+    async fn put_chunk(
+        &self,
+        session: &UploadSession,
+        range: &str,
+        data: &[u8],
+    ) -> Result<UploadStatus> {
+        let (url, auth) = self.client.url_and_auth(&session.uri).await?;
+        let mut req = self
+            .client
+            .client
+            .put(url)
+            .header(reqwest::header::CONTENT_RANGE, range)
+            .header(reqwest::header::CONTENT_LENGTH, data.len())
+            .body(data.to_vec());
+        if !data.is_empty() && !session.mime_type.is_empty() {
+            req = req.header(reqwest::header::CONTENT_TYPE, &session.mime_type);
+        }
+        if let Some(auth_str) = auth {
+            req = req.header(http::header::AUTHORIZATION, &*auth_str);
+        }
+
+        let resp = req.send().await?;
+        let status = resp.status();
+        if status == http::StatusCode::PERMANENT_REDIRECT {
+            // The `Range` header is missing if nothing was committed yet.
+            let committed = match resp.headers().get(reqwest::header::RANGE) {
+                Some(range) => parse_upload_range(range.to_str()?)?,
+                None => 0,
+            };
+            return Ok(UploadStatus::Incomplete(committed));
+        }
+
+        let body = resp.bytes().await?;
+        if !status.is_success() {
+            return Err(UploadError {
+                status,
+                body: String::from_utf8_lossy(&body).to_string(),
+            }
+            .into());
+        }
+
+        Ok(UploadStatus::Complete(Box::new(serde_json::from_slice(
+            &body,
+        )?)))
+    }
+}
+
+/// Returns true if an upload request can be sent again after `e`: network
+/// errors can, and so can some of the statuses of the session.
+fn is_retryable_upload_error(e: &anyhow::Error) -> bool {
+    match e.downcast_ref::<UploadError>() {
+        Some(e) => e.is_retryable(),
+        None => {
+            e.downcast_ref::<reqwest_middleware::Error>().is_some()
+                || e.downcast_ref::<reqwest::Error>().is_some()
+        }
+    }
+}
+
+/// Parse the number of bytes committed from a `Range: bytes=0-{last}` header.
+pub(crate) fn parse_upload_range(range: &str) -> Result<u64> {
+    let last = range
+        .trim()
+        .strip_prefix("bytes=")
+        .and_then(|r| r.split('-').nth(1))
+        .ok_or_else(|| anyhow!("invalid range header: {}", range))?;
+
+    Ok(last.parse::<u64>()? + 1)
+}
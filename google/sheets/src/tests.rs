@@ -1 +1,233 @@
+use crate::types::CellValue;
 
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+struct Server {
+    name: String,
+    cores: u32,
+    enabled: bool,
+    notes: Option<String>,
+}
+
+#[test]
+fn test_cell_value_deserialize() {
+    let values: crate::types::ValueRange = serde_json::from_str(
+        r##"{"range": "Sheet1!A1:E1", "values": [["a", 1.5, 2, true, "#DIV/0!", ""]]}"##,
+    )
+    .unwrap();
+
+    assert_eq!(
+        values.values,
+        vec![vec![
+            CellValue::String("a".to_string()),
+            CellValue::Number(1.5),
+            CellValue::Number(2.0),
+            CellValue::Bool(true),
+            // Text that looks like an error is still text.
+            CellValue::String("#DIV/0!".to_string()),
+            CellValue::Empty,
+        ]]
+    );
+
+    let error: crate::types::ErrorValue =
+        serde_json::from_str(r#"{"type": "DIVIDE_BY_ZERO", "message": "Divide by zero."}"#)
+            .unwrap();
+    assert_eq!(
+        CellValue::from(&error),
+        CellValue::Error("#DIV/0!".to_string())
+    );
+}
+
+#[test]
+fn test_cell_value_serialize() {
+    let cells = vec![
+        CellValue::from("a"),
+        CellValue::from(1.5),
+        CellValue::from(2),
+        CellValue::from(false),
+        CellValue::Empty,
+    ];
+
+    assert_eq!(
+        serde_json::to_string(&cells).unwrap(),
+        r#"["a",1.5,2,false,""]"#
+    );
+    assert_eq!(
+        cells.iter().map(|c| c.to_string()).collect::<Vec<_>>(),
+        vec!["a", "1.5", "2", "FALSE", ""]
+    );
+}
+
+fn cells(row: &[&str]) -> Vec<CellValue> {
+    row.iter().map(|c| CellValue::from(*c)).collect()
+}
+
+#[test]
+fn test_rows_from_values() {
+    let values = vec![
+        cells(&["name", "cores", "", "enabled", "notes"]),
+        vec![
+            "db-1".into(),
+            8.into(),
+            "ignored".into(),
+            true.into(),
+            "primary".into(),
+        ],
+        vec![],
+        // Trailing empty cells are not returned by the API.
+        vec!["db-2".into(), 4.into(), CellValue::Empty, false.into()],
+    ];
+
+    let rows: Vec<Server> = crate::traits::rows_from_values(&values).unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            Server {
+                name: "db-1".to_string(),
+                cores: 8,
+                enabled: true,
+                notes: Some("primary".to_string()),
+            },
+            Server {
+                name: "db-2".to_string(),
+                cores: 4,
+                enabled: false,
+                notes: None,
+            },
+        ]
+    );
+
+    let err = crate::traits::rows_from_values::<Server>(&[
+        cells(&["name", "cores"]),
+        cells(&["db-3", "many"]),
+    ])
+    .unwrap_err();
+    assert!(err.to_string().starts_with("parsing row 2 failed"));
+}
+
+#[test]
+fn test_values_from_rows() {
+    let existing = vec![
+        cells(&["name", "owner", "cores"]),
+        cells(&["db-1", "ops", "2"]),
+        cells(&["db-2", "ops", "2"]),
+        cells(&["db-3", "ops", "2"]),
+    ];
+    let rows = vec![Server {
+        name: "db-1".to_string(),
+        cores: 16,
+        enabled: true,
+        notes: None,
+    }];
+
+    let values = crate::traits::values_from_rows(&existing, &rows).unwrap();
+    assert_eq!(
+        values,
+        vec![
+            cells(&["name", "owner", "cores", "enabled", "notes"]),
+            vec![
+                "db-1".into(),
+                CellValue::Empty,
+                16.into(),
+                true.into(),
+                CellValue::Empty,
+            ],
+            vec![CellValue::Empty; 5],
+            vec![CellValue::Empty; 5],
+        ]
+    );
+
+    // Without a header, the columns follow the field names.
+    let values = crate::traits::values_from_rows(&[], &rows).unwrap();
+    assert_eq!(values[0], cells(&["cores", "enabled", "name", "notes"]));
+    assert_eq!(values.len(), 2);
+}
+
+fn a1(s: &str) -> crate::traits::A1Range {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_a1_range_parse() {
+    use crate::traits::A1Range;
+
+    assert_eq!(a1("Sheet1!B2"), A1Range::cell(1, 1).with_sheet("Sheet1"));
+    assert_eq!(
+        a1("'Q1 ''21'!A1:AB10"),
+        A1Range {
+            sheet: Some("Q1 '21".to_string()),
+            start_row: Some(0),
+            start_column: Some(0),
+            end_row: Some(10),
+            end_column: Some(28),
+        }
+    );
+    assert_eq!(
+        a1("A5:C"),
+        A1Range {
+            start_row: Some(4),
+            start_column: Some(0),
+            end_column: Some(3),
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        a1("Data!2:3"),
+        A1Range {
+            sheet: Some("Data".to_string()),
+            start_row: Some(1),
+            end_row: Some(3),
+            ..Default::default()
+        }
+    );
+    assert_eq!(a1("Data"), A1Range::sheet("Data"));
+    assert_eq!(a1("'A1'"), A1Range::sheet("A1"));
+    assert!("Sheet1!A0".parse::<A1Range>().is_err());
+    assert!("'Sheet1".parse::<A1Range>().is_err());
+}
+
+#[test]
+fn test_a1_range_format() {
+    use crate::traits::A1Range;
+
+    for s in [
+        "Sheet1!B2",
+        "'Q1 ''21'!A1:AB10",
+        "A5:C",
+        "Data!2:3",
+        "Data!A:A",
+        "Data",
+        "'A1'",
+        "'R1C1'!ZZ1:AAA2",
+    ] {
+        assert_eq!(a1(s).to_string(), s);
+    }
+
+    assert_eq!(a1("'Q1 ''21'!A1:AB10").to_r1c1(), "'Q1 ''21'!R1C1:R10C28");
+    assert_eq!(a1("Data!2:3").to_r1c1(), "Data!R2:R3");
+    assert_eq!(
+        A1Range::from_r1c1("Data!R2C3").unwrap(),
+        A1Range::cell(1, 2).with_sheet("Data")
+    );
+    assert_eq!(A1Range::from_r1c1("C1:C2").unwrap(), a1("A:B"));
+}
+
+#[test]
+fn test_column_names() {
+    for (index, name) in [(0, "A"), (25, "Z"), (26, "AA"), (701, "ZZ"), (702, "AAA")] {
+        assert_eq!(crate::traits::column_name(index), name);
+        assert_eq!(crate::traits::column_index(name), Some(index));
+    }
+    assert_eq!(crate::traits::column_index("a"), Some(0));
+    assert_eq!(crate::traits::column_index("A1"), None);
+}
+
+#[test]
+fn test_a1_range_to_grid_range() {
+    let grid = a1("Data!B2:C").to_grid_range(42);
+
+    assert_eq!(grid.sheet_id, 42);
+    assert_eq!(grid.start_row_index, 1);
+    assert_eq!(grid.start_column_index, 1);
+    assert_eq!(grid.end_row_index, 0);
+    assert_eq!(grid.end_column_index, 3);
+}
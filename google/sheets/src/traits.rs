@@ -1,4 +1,8 @@
-use anyhow::Result;
+use std::{fmt, str::FromStr};
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::types::CellValue;
 
 #[async_trait::async_trait]
 pub trait SpreadsheetOps {
@@ -6,6 +10,56 @@ pub trait SpreadsheetOps {
     /// The `cell_name` is something like `A1` and what is returned is a string representation of
     /// the cell's value.
     async fn cell_get(&self, sheet_id: &str, cell_name: &str) -> Result<String>;
+
+    /// Read the rows of a range whose first row is a header.
+    ///
+    /// Every row after the header is deserialized into `T` from an object mapping
+    /// each column header to the value of the cell. Empty cells are `null`, so
+    /// they can be read into `Option` fields, and blank rows are skipped.
+    async fn get_rows<T>(&self, sheet_id: &str, range: &str) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned;
+
+    /// Write `rows` to a range whose first row is a header, replacing the rows
+    /// that were there.
+    ///
+    /// Fields are written to the column with the matching header, and fields
+    /// without one get a new column at the end of the header. If the range has
+    /// no header yet, the columns are in the order of the field names.
+    async fn update_rows<T>(
+        &self,
+        sheet_id: &str,
+        range: &str,
+        rows: &[T],
+    ) -> Result<crate::types::UpdateValuesResponse>
+    where
+        T: serde::Serialize + Sync;
+
+    /// Update the value of a single cell.
+    async fn cell_update(
+        &self,
+        sheet_id: &str,
+        cell: &A1Range,
+        value: CellValue,
+    ) -> Result<crate::types::UpdateValuesResponse>;
+
+    /// Find the first row after the last non-empty row of `range`.
+    ///
+    /// The returned range spans that row, across the columns of `range`.
+    async fn next_empty_row(&self, sheet_id: &str, range: &A1Range) -> Result<A1Range>;
+
+    /// Append rows after the last non-empty row of `range`, inserting new rows
+    /// for them.
+    async fn append_rows(
+        &self,
+        sheet_id: &str,
+        range: &A1Range,
+        rows: Vec<Vec<CellValue>>,
+    ) -> Result<crate::types::AppendValuesResponse>;
+
+    /// Convert `range` to the `GridRange` used by `batch_update` requests,
+    /// looking up the id of its sheet.
+    async fn grid_range(&self, sheet_id: &str, range: &A1Range) -> Result<crate::types::GridRange>;
 }
 
 #[async_trait::async_trait]
@@ -22,8 +76,7 @@ impl SpreadsheetOps for crate::spreadsheets::Spreadsheets {
                 crate::types::Dimension::Rows,
                 crate::types::ValueRenderOption::FormattedValue,
             )
-            .await
-            .unwrap();
+            .await?;
 
         if let Some(v) = values.values.get(0) {
             if let Some(v) = v.get(0) {
@@ -33,4 +86,625 @@ impl SpreadsheetOps for crate::spreadsheets::Spreadsheets {
 
         Ok(String::new())
     }
+
+    /// Read the rows of a range whose first row is a header.
+    ///
+    /// Every row after the header is deserialized into `T` from an object mapping
+    /// each column header to the value of the cell. Empty cells are `null`, so
+    /// they can be read into `Option` fields, and blank rows are skipped.
+    async fn get_rows<T>(&self, sheet_id: &str, range: &str) -> Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let values = self
+            .values_get(
+                sheet_id,
+                range,
+                crate::types::DateTimeRenderOption::FormattedString,
+                crate::types::Dimension::Rows,
+                crate::types::ValueRenderOption::UnformattedValue,
+            )
+            .await?;
+
+        rows_from_values(&values.values)
+    }
+
+    /// Write `rows` to a range whose first row is a header, replacing the rows
+    /// that were there.
+    ///
+    /// Fields are written to the column with the matching header, and fields
+    /// without one get a new column at the end of the header. If the range has
+    /// no header yet, the columns are in the order of the field names.
+    async fn update_rows<T>(
+        &self,
+        sheet_id: &str,
+        range: &str,
+        rows: &[T],
+    ) -> Result<crate::types::UpdateValuesResponse>
+    where
+        T: serde::Serialize + Sync,
+    {
+        let existing = self
+            .values_get(
+                sheet_id,
+                range,
+                crate::types::DateTimeRenderOption::FormattedString,
+                crate::types::Dimension::Rows,
+                crate::types::ValueRenderOption::UnformattedValue,
+            )
+            .await?;
+
+        let body = crate::types::ValueRange {
+            major_dimension: Some(crate::types::Dimension::Rows),
+            range: range.to_string(),
+            values: values_from_rows(&existing.values, rows)?,
+        };
+
+        self.values_update(
+            sheet_id,
+            range,
+            false,
+            crate::types::DateTimeRenderOption::FormattedString,
+            crate::types::ValueRenderOption::FormattedValue,
+            crate::types::ValueInputOption::Raw,
+            &body,
+        )
+        .await
+    }
+
+    /// Update the value of a single cell.
+    async fn cell_update(
+        &self,
+        sheet_id: &str,
+        cell: &A1Range,
+        value: CellValue,
+    ) -> Result<crate::types::UpdateValuesResponse> {
+        let range = cell.to_string();
+        let body = crate::types::ValueRange {
+            major_dimension: Some(crate::types::Dimension::Rows),
+            range: range.to_string(),
+            values: vec![vec![value]],
+        };
+
+        self.values_update(
+            sheet_id,
+            &range,
+            false,
+            crate::types::DateTimeRenderOption::FormattedString,
+            crate::types::ValueRenderOption::FormattedValue,
+            crate::types::ValueInputOption::Raw,
+            &body,
+        )
+        .await
+    }
+
+    /// Find the first row after the last non-empty row of `range`.
+    ///
+    /// The returned range spans that row, across the columns of `range`.
+    async fn next_empty_row(&self, sheet_id: &str, range: &A1Range) -> Result<A1Range> {
+        let values = self
+            .values_get(
+                sheet_id,
+                &range.to_string(),
+                crate::types::DateTimeRenderOption::FormattedString,
+                crate::types::Dimension::Rows,
+                crate::types::ValueRenderOption::FormattedValue,
+            )
+            .await?;
+
+        // Trailing empty rows are not returned, so the next one follows the
+        // last row we got.
+        let row = range.start_row.unwrap_or_default() + values.values.len() as u64;
+        if let Some(end_row) = range.end_row {
+            if row >= end_row {
+                bail!("range {} has no empty rows", range);
+            }
+        }
+
+        Ok(A1Range {
+            start_row: Some(row),
+            end_row: Some(row + 1),
+            ..range.clone()
+        })
+    }
+
+    /// Append rows after the last non-empty row of `range`, inserting new rows
+    /// for them.
+    async fn append_rows(
+        &self,
+        sheet_id: &str,
+        range: &A1Range,
+        rows: Vec<Vec<CellValue>>,
+    ) -> Result<crate::types::AppendValuesResponse> {
+        let range = range.to_string();
+        let body = crate::types::ValueRange {
+            major_dimension: Some(crate::types::Dimension::Rows),
+            range: range.to_string(),
+            values: rows,
+        };
+
+        self.values_append(
+            sheet_id,
+            &range,
+            false,
+            crate::types::InsertDataOption::InsertRows,
+            crate::types::DateTimeRenderOption::FormattedString,
+            crate::types::ValueRenderOption::FormattedValue,
+            crate::types::ValueInputOption::Raw,
+            &body,
+        )
+        .await
+    }
+
+    /// Convert `range` to the `GridRange` used by `batch_update` requests,
+    /// looking up the id of its sheet.
+    async fn grid_range(&self, sheet_id: &str, range: &A1Range) -> Result<crate::types::GridRange> {
+        let spreadsheet = self.get(sheet_id, false, &[]).await?;
+
+        // Without a sheet name, ranges refer to the first visible sheet.
+        let sheet = spreadsheet
+            .sheets
+            .iter()
+            .filter_map(|s| s.properties.as_ref())
+            .find(|p| match &range.sheet {
+                Some(title) => p.title == *title,
+                None => !p.hidden,
+            });
+        match sheet {
+            Some(p) => Ok(range.to_grid_range(p.sheet_id)),
+            None => bail!("spreadsheet {} has no sheet for range {}", sheet_id, range),
+        }
+    }
+}
+
+/// Deserialize the rows after the header row of `values`.
+pub(crate) fn rows_from_values<T>(values: &[Vec<CellValue>]) -> Result<Vec<T>>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let (header, rows) = match values.split_first() {
+        Some(v) => v,
+        None => return Ok(Vec::new()),
+    };
+
+    let mut result = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        if row.iter().all(|c| c.is_empty()) {
+            continue;
+        }
+
+        let mut object = serde_json::Map::new();
+        for (column, name) in header.iter().enumerate() {
+            if name.is_empty() {
+                continue;
+            }
+
+            let value = row.get(column).map(cell_to_json).unwrap_or_default();
+            object.insert(name.to_string(), value);
+        }
+
+        match serde_json::from_value(serde_json::Value::Object(object)) {
+            Ok(r) => result.push(r),
+            // Rows are numbered from 1 and the header is the first one.
+            Err(e) => bail!("parsing row {} failed: {}", i + 2, e),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Build the values to write `rows` over `existing`, a header and the rows
+/// that follow it.
+///
+/// Cells of `existing` that are not overwritten are cleared.
+pub(crate) fn values_from_rows<T>(
+    existing: &[Vec<CellValue>],
+    rows: &[T],
+) -> Result<Vec<Vec<CellValue>>>
+where
+    T: serde::Serialize,
+{
+    let mut header: Vec<CellValue> = existing.first().cloned().unwrap_or_default();
+
+    let mut objects = Vec::with_capacity(rows.len());
+    for row in rows {
+        let object = match serde_json::to_value(row)? {
+            serde_json::Value::Object(o) => o,
+            v => bail!("rows must serialize to objects, not {}", v),
+        };
+
+        for name in object.keys() {
+            if !header.iter().any(|h| h.to_string() == *name) {
+                header.push(CellValue::String(name.to_string()));
+            }
+        }
+        objects.push(object);
+    }
+
+    let width = existing
+        .iter()
+        .map(|r| r.len())
+        .chain(std::iter::once(header.len()))
+        .max()
+        .unwrap_or_default();
+
+    let mut values = Vec::with_capacity(std::cmp::max(existing.len(), rows.len() + 1));
+    let mut header_row = header.clone();
+    header_row.resize(width, CellValue::Empty);
+    values.push(header_row);
+
+    for object in objects {
+        let mut row = Vec::with_capacity(width);
+        for name in &header {
+            let cell = match object.get(&name.to_string()) {
+                Some(v) if !name.is_empty() => json_to_cell(name, v)?,
+                _ => CellValue::Empty,
+            };
+            row.push(cell);
+        }
+        row.resize(width, CellValue::Empty);
+        values.push(row);
+    }
+
+    // Clear the rows that are left over from before.
+    while values.len() < existing.len() {
+        values.push(vec![CellValue::Empty; width]);
+    }
+
+    Ok(values)
+}
+
+fn cell_to_json(cell: &CellValue) -> serde_json::Value {
+    match cell {
+        CellValue::Empty => serde_json::Value::Null,
+        CellValue::String(s) | CellValue::Error(s) => serde_json::Value::String(s.to_string()),
+        // Keep whole numbers whole, so they can be read into integer fields.
+        CellValue::Number(n) if n.fract() == 0.0 && n.abs() < 9_007_199_254_740_992.0 => {
+            serde_json::Value::from(*n as i64)
+        }
+        CellValue::Number(n) => serde_json::Value::from(*n),
+        CellValue::Bool(b) => serde_json::Value::Bool(*b),
+    }
+}
+
+fn json_to_cell(name: &CellValue, value: &serde_json::Value) -> Result<CellValue> {
+    Ok(match value {
+        serde_json::Value::Null => CellValue::Empty,
+        serde_json::Value::Bool(b) => CellValue::Bool(*b),
+        serde_json::Value::Number(n) => match n.as_f64() {
+            Some(n) => CellValue::Number(n),
+            None => bail!("field {} is not a valid number: {}", name, n),
+        },
+        serde_json::Value::String(s) if s.is_empty() => CellValue::Empty,
+        serde_json::Value::String(s) => CellValue::String(s.to_string()),
+        v => bail!(
+            "field {} must be a string, number or boolean, not {}",
+            name,
+            v
+        ),
+    })
+}
+
+/// A range of cells, in A1 or R1C1 notation.
+///
+/// Rows and columns are zero-based, and the ends are exclusive like those of a
+/// `GridRange`. A bound that is `None` leaves the range open on that side, so
+/// `A:B` has no rows bounds and `Sheet1` has no bounds at all.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct A1Range {
+    /// The name of the sheet, or `None` for the first visible sheet.
+    pub sheet: Option<String>,
+    pub start_row: Option<u64>,
+    pub start_column: Option<u64>,
+    pub end_row: Option<u64>,
+    pub end_column: Option<u64>,
+}
+
+impl A1Range {
+    /// A range spanning a whole sheet.
+    pub fn sheet(name: &str) -> Self {
+        A1Range {
+            sheet: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// A range of a single cell.
+    pub fn cell(row: u64, column: u64) -> Self {
+        A1Range {
+            start_row: Some(row),
+            start_column: Some(column),
+            end_row: Some(row + 1),
+            end_column: Some(column + 1),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_sheet(mut self, name: &str) -> Self {
+        self.sheet = Some(name.to_string());
+        self
+    }
+
+    /// Returns true if the range is a single cell.
+    pub fn is_cell(&self) -> bool {
+        match (
+            self.start_row,
+            self.start_column,
+            self.end_row,
+            self.end_column,
+        ) {
+            (Some(sr), Some(sc), Some(er), Some(ec)) => er == sr + 1 && ec == sc + 1,
+            _ => false,
+        }
+    }
+
+    /// Parse a range in R1C1 notation, like `Sheet1!R1C1:R2C3`.
+    pub fn from_r1c1(s: &str) -> Result<Self> {
+        let parse = |r: &str| -> Option<(Option<u64>, Option<u64>)> {
+            let r = r.to_ascii_uppercase();
+            let (row, column) = match r.find('C') {
+                Some(i) => (&r[..i], Some(&r[i + 1..])),
+                None => (r.as_str(), None),
+            };
+            let row = match row {
+                "" => None,
+                row => Some(parse_index(row.strip_prefix('R')?)?),
+            };
+            let column = match column {
+                Some(c) => Some(parse_index(c)?),
+                None => None,
+            };
+            if row.is_none() && column.is_none() {
+                return None;
+            }
+            Some((row, column))
+        };
+
+        parse_range(s, "R1C1", parse)
+    }
+
+    /// Format the range in R1C1 notation.
+    pub fn to_r1c1(&self) -> String {
+        let format = |row: Option<u64>, column: Option<u64>| {
+            let mut r = String::new();
+            if let Some(row) = row {
+                r.push_str(&format!("R{}", row));
+            }
+            if let Some(column) = column {
+                r.push_str(&format!("C{}", column));
+            }
+            r
+        };
+
+        self.format(
+            format(
+                self.start_row.map(|r| r + 1),
+                self.start_column.map(|c| c + 1),
+            ),
+            format(self.end_row, self.end_column),
+        )
+    }
+
+    /// Convert the range to a `GridRange` of the sheet with the id `sheet_id`.
+    pub fn to_grid_range(&self, sheet_id: i64) -> crate::types::GridRange {
+        crate::types::GridRange {
+            sheet_id,
+            start_row_index: self.start_row.unwrap_or_default() as i64,
+            start_column_index: self.start_column.unwrap_or_default() as i64,
+            // Zero leaves the end unbounded.
+            end_row_index: self.end_row.unwrap_or_default() as i64,
+            end_column_index: self.end_column.unwrap_or_default() as i64,
+        }
+    }
+
+    fn format(&self, start: String, end: String) -> String {
+        let mut s = String::new();
+        if let Some(sheet) = &self.sheet {
+            s.push_str(&quote_sheet(sheet));
+            if start.is_empty() && end.is_empty() {
+                return s;
+            }
+            s.push('!');
+        }
+
+        s.push_str(&start);
+        if !self.is_cell() {
+            s.push(':');
+            s.push_str(&end);
+        }
+        s
+    }
+}
+
+impl fmt::Display for A1Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = |row: Option<u64>, column: Option<u64>| {
+            let mut r = column.map(column_name).unwrap_or_default();
+            if let Some(row) = row {
+                r.push_str(&row.to_string());
+            }
+            r
+        };
+
+        self.format(
+            format(self.start_row.map(|r| r + 1), self.start_column),
+            format(self.end_row, self.end_column.map(|c| c.saturating_sub(1))),
+        )
+        .fmt(f)
+    }
+}
+
+impl FromStr for A1Range {
+    type Err = anyhow::Error;
+
+    /// Parse a range in A1 notation, like `'My Sheet'!A1:B2`.
+    fn from_str(s: &str) -> Result<Self> {
+        let parse = |r: &str| -> Option<(Option<u64>, Option<u64>)> {
+            let i = r
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(r.len());
+            let (column, row) = r.split_at(i);
+            let column = match column {
+                "" => None,
+                // Sheets have at most 18278 columns, up to `ZZZ`, which
+                // keeps names like `Sheet1` from being read as cells.
+                c if c.len() <= 3 => Some(column_index(c)?),
+                _ => return None,
+            };
+            let row = match row {
+                "" => None,
+                row => Some(parse_index(row)?),
+            };
+            if row.is_none() && column.is_none() {
+                return None;
+            }
+            Some((row, column))
+        };
+
+        parse_range(s, "A1", parse)
+    }
+}
+
+/// Returns the name of a zero-based column: `A`, ..., `Z`, `AA`, ...
+pub fn column_name(mut column: u64) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (column % 26) as u8);
+        if column < 26 {
+            break;
+        }
+        column = column / 26 - 1;
+    }
+    name.reverse();
+
+    String::from_utf8(name).unwrap()
+}
+
+/// Returns the zero-based index of a column name like `AA`.
+pub fn column_index(name: &str) -> Option<u64> {
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut index: u64 = 0;
+    for c in name.chars() {
+        if !c.is_ascii_alphabetic() {
+            return None;
+        }
+        let digit = (c.to_ascii_uppercase() as u8 - b'A') as u64 + 1;
+        index = index.checked_mul(26)?.checked_add(digit)?;
+    }
+
+    Some(index - 1)
+}
+
+/// Parse a one-based row or column number into a zero-based index.
+fn parse_index(s: &str) -> Option<u64> {
+    if !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    s.parse::<u64>().ok()?.checked_sub(1)
+}
+
+/// Split a range into its sheet name and its cells.
+fn split_sheet(s: &str) -> Result<(Option<String>, Option<&str>)> {
+    let s = s.trim();
+    if let Some(quoted) = s.strip_prefix('\'') {
+        // A quote inside a quoted name is doubled.
+        let mut name = String::new();
+        let mut chars = quoted.char_indices();
+        while let Some((i, c)) = chars.next() {
+            if c != '\'' {
+                name.push(c);
+                continue;
+            }
+            match quoted[i + 1..].chars().next() {
+                Some('\'') => {
+                    name.push('\'');
+                    chars.next();
+                }
+                None => return Ok((Some(name), None)),
+                Some('!') => return Ok((Some(name), Some(&quoted[i + 2..]))),
+                Some(_) => break,
+            }
+        }
+        bail!("invalid quoted sheet name: {}", s);
+    }
+
+    match s.split_once('!') {
+        Some((sheet, cells)) => Ok((Some(sheet.to_string()), Some(cells))),
+        None if s.is_empty() => Ok((None, None)),
+        None => Ok((Some(s.to_string()), None)),
+    }
+}
+
+/// Parse a range, with `parse` returning the zero-based row and column of a
+/// reference in `notation`.
+fn parse_range<F>(s: &str, notation: &str, parse: F) -> Result<A1Range>
+where
+    F: Fn(&str) -> Option<(Option<u64>, Option<u64>)>,
+{
+    let (sheet, cells) = split_sheet(s)?;
+    match cells {
+        Some(cells) => from_refs(sheet, cells, parse)
+            .ok_or_else(|| anyhow!("invalid {} range: {}", notation, s)),
+        // Without a `!` this is either a range or the name of a sheet.
+        None => match sheet {
+            Some(sheet) if s.trim().starts_with('\'') => Ok(A1Range::sheet(&sheet)),
+            Some(sheet) => {
+                Ok(from_refs(None, &sheet, parse).unwrap_or_else(|| A1Range::sheet(&sheet)))
+            }
+            None => bail!("empty {} range", notation),
+        },
+    }
+}
+
+/// Build a range from `start:end` or a single cell, with `parse` returning the
+/// zero-based row and column of a reference.
+fn from_refs<F>(sheet: Option<String>, cells: &str, parse: F) -> Option<A1Range>
+where
+    F: Fn(&str) -> Option<(Option<u64>, Option<u64>)>,
+{
+    let (start_row, start_column, end_row, end_column) = match cells.split_once(':') {
+        Some((start, end)) => {
+            let (start_row, start_column) = parse(start)?;
+            let (end_row, end_column) = parse(end)?;
+            (
+                start_row,
+                start_column,
+                end_row.map(|r| r + 1),
+                end_column.map(|c| c + 1),
+            )
+        }
+        None => match parse(cells)? {
+            (Some(row), Some(column)) => (Some(row), Some(column), Some(row + 1), Some(column + 1)),
+            _ => return None,
+        },
+    };
+
+    Some(A1Range {
+        sheet,
+        start_row,
+        start_column,
+        end_row,
+        end_column,
+    })
+}
+
+/// Quote a sheet name if it is not made of letters, digits and underscores,
+/// or if it could be mistaken for a cell.
+fn quote_sheet(name: &str) -> String {
+    let plain = !name.is_empty()
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .parse::<A1Range>()
+            .map(|r| r.sheet.is_some())
+            .unwrap_or(false)
+        && A1Range::from_r1c1(&format!("x!{}", name)).is_err();
+    if plain {
+        name.to_string()
+    } else {
+        format!("'{}'", name.replace('\'', "''"))
+    }
 }
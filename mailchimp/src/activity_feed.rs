@@ -41,4 +41,50 @@ impl ActivityFeed {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * Get latest chimp chatter.
+     *
+     * This function performs a `GET` to the `/activity-feed/chimp-chatter` endpoint.
+     *
+     * As opposed to `get_chimp_chatter`, this function returns all the pages of the request at once.
+     *
+     * Return the Chimp Chatter for this account ordered by most recent.
+     */
+    pub async fn get_all_chimp_chatter(&self) -> Result<Vec<crate::types::ChimpChatter>> {
+        let url = "/activity-feed/chimp-chatter".to_string();
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetActivityFeedChimpChatterResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.chimp_chatter)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * Get latest chimp chatter.
+     *
+     * This function performs a `GET` to the `/activity-feed/chimp-chatter` endpoint.
+     *
+     * As opposed to `get_chimp_chatter`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Return the Chimp Chatter for this account ordered by most recent.
+     */
+    pub fn get_all_chimp_chatter_stream(
+        &self,
+    ) -> impl futures::Stream<Item = Result<crate::types::ChimpChatter>> + '_ {
+        let url = "/activity-feed/chimp-chatter".to_string();
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetActivityFeedChimpChatterResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.chimp_chatter)
+        });
+
+        // Return our response data.
+        resp
+    }
 }
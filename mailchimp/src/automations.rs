@@ -77,6 +77,112 @@ impl Automations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List automations.
+     *
+     * This function performs a `GET` to the `/automations` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * Get a summary of an account's classic automations.
+     */
+    pub async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        status: crate::types::Status,
+    ) -> Result<Vec<crate::types::Automations>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_create_time {
+            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = before_start_time {
+            query_args.push(("before_start_time".to_string(), date.to_rfc3339()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if let Some(date) = since_create_time {
+            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = since_start_time {
+            query_args.push(("since_start_time".to_string(), date.to_rfc3339()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/automations?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetAutomationsResponse = self.client.get(&url, None).await?;
+            Ok(resp.automations)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List automations.
+     *
+     * This function performs a `GET` to the `/automations` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get a summary of an account's classic automations.
+     */
+    pub fn get_all_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_start_time: Option<chrono::DateTime<chrono::Utc>>,
+        status: crate::types::Status,
+    ) -> impl futures::Stream<Item = Result<crate::types::Automations>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_create_time {
+            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = before_start_time {
+            query_args.push(("before_start_time".to_string(), date.to_rfc3339()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if let Some(date) = since_create_time {
+            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = since_start_time {
+            query_args.push(("since_start_time".to_string(), date.to_rfc3339()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/automations?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetAutomationsResponse = self.client.get(&url, None).await?;
+            Ok(resp.automations)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add automation.
      *
@@ -52,6 +52,72 @@ impl BatchWebhooks {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List batch webhooks.
+     *
+     * This function performs a `GET` to the `/batch-webhooks` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * Get all webhooks that have been configured for batches.
+     */
+    pub async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<Vec<crate::types::Webhooks>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/batch-webhooks?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::BatchWebhooks = self.client.get(&url, None).await?;
+            Ok(resp.webhooks)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List batch webhooks.
+     *
+     * This function performs a `GET` to the `/batch-webhooks` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get all webhooks that have been configured for batches.
+     */
+    pub fn get_all_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> impl futures::Stream<Item = Result<crate::types::Webhooks>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/batch-webhooks?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::BatchWebhooks = self.client.get(&url, None).await?;
+            Ok(resp.webhooks)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add batch webhook.
      *
@@ -52,6 +52,72 @@ impl CampaignFolders {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List campaign folders.
+     *
+     * This function performs a `GET` to the `/campaign-folders` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * Get all folders used to organize campaigns.
+     */
+    pub async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<Vec<crate::types::CampaignFolder>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/campaign-folders?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CampaignFolders = self.client.get(&url, None).await?;
+            Ok(resp.folders)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List campaign folders.
+     *
+     * This function performs a `GET` to the `/campaign-folders` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get all folders used to organize campaigns.
+     */
+    pub fn get_all_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> impl futures::Stream<Item = Result<crate::types::CampaignFolder>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/campaign-folders?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CampaignFolders = self.client.get(&url, None).await?;
+            Ok(resp.folders)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add campaign folder.
      *
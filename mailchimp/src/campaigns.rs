@@ -107,6 +107,160 @@ impl Campaigns {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List campaigns.
+     *
+     * This function performs a `GET` to the `/campaigns` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * Get all campaigns in an account.
+     */
+    pub async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: crate::types::CampaignType,
+        status: crate::types::GetCampaignsStatus,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        list_id: &str,
+        folder_id: &str,
+        member_id: &str,
+        sort_field: crate::types::SortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<Vec<crate::types::Campaign>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_create_time {
+            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = before_send_time {
+            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+        }
+        if !list_id.is_empty() {
+            query_args.push(("list_id".to_string(), list_id.to_string()));
+        }
+        if !member_id.is_empty() {
+            query_args.push(("member_id".to_string(), member_id.to_string()));
+        }
+        if let Some(date) = since_create_time {
+            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = since_send_time {
+            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/campaigns?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetCampaignsResponse = self.client.get(&url, None).await?;
+            Ok(resp.campaigns)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List campaigns.
+     *
+     * This function performs a `GET` to the `/campaigns` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get all campaigns in an account.
+     */
+    pub fn get_all_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: crate::types::CampaignType,
+        status: crate::types::GetCampaignsStatus,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        before_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_create_time: Option<chrono::DateTime<chrono::Utc>>,
+        list_id: &str,
+        folder_id: &str,
+        member_id: &str,
+        sort_field: crate::types::SortField,
+        sort_dir: crate::types::SortDir,
+    ) -> impl futures::Stream<Item = Result<crate::types::Campaign>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_create_time {
+            query_args.push(("before_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = before_send_time {
+            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+        }
+        if !list_id.is_empty() {
+            query_args.push(("list_id".to_string(), list_id.to_string()));
+        }
+        if !member_id.is_empty() {
+            query_args.push(("member_id".to_string(), member_id.to_string()));
+        }
+        if let Some(date) = since_create_time {
+            query_args.push(("since_create_time".to_string(), date.to_rfc3339()));
+        }
+        if let Some(date) = since_send_time {
+            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/campaigns?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetCampaignsResponse = self.client.get(&url, None).await?;
+            Ok(resp.campaigns)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add campaign.
      *
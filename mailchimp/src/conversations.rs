@@ -70,6 +70,102 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List conversations.
+     *
+     * This function performs a `GET` to the `/conversations` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * Get a list of conversations for the account. Conversations has been deprecated in favor of Inbox and these endpoints don't include Inbox data. Past Conversations are still available via this endpoint, but new campaign replies and other Inbox messages aren’t available using this endpoint.
+     */
+    pub async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        has_unread_messages: crate::types::IsRead,
+        list_id: &str,
+        campaign_id: &str,
+    ) -> Result<Vec<crate::types::Conversation>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !campaign_id.is_empty() {
+            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !has_unread_messages.to_string().is_empty() {
+            query_args.push((
+                "has_unread_messages".to_string(),
+                has_unread_messages.to_string(),
+            ));
+        }
+        if !list_id.is_empty() {
+            query_args.push(("list_id".to_string(), list_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::TrackedConversations = self.client.get(&url, None).await?;
+            Ok(resp.conversations)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List conversations.
+     *
+     * This function performs a `GET` to the `/conversations` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get a list of conversations for the account. Conversations has been deprecated in favor of Inbox and these endpoints don't include Inbox data. Past Conversations are still available via this endpoint, but new campaign replies and other Inbox messages aren’t available using this endpoint.
+     */
+    pub fn get_all_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        has_unread_messages: crate::types::IsRead,
+        list_id: &str,
+        campaign_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::Conversation>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !campaign_id.is_empty() {
+            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !has_unread_messages.to_string().is_empty() {
+            query_args.push((
+                "has_unread_messages".to_string(),
+                has_unread_messages.to_string(),
+            ));
+        }
+        if !list_id.is_empty() {
+            query_args.push(("list_id".to_string(), list_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::TrackedConversations = self.client.get(&url, None).await?;
+            Ok(resp.conversations)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Get conversation.
      *
@@ -72,6 +72,104 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List account orders.
+     *
+     * This function performs a `GET` to the `/ecommerce/orders` endpoint.
+     *
+     * As opposed to `get_order`, this function returns all the pages of the request at once.
+     *
+     * Get information about an account's orders.
+     */
+    pub async fn get_all_orders(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        outreach_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+    ) -> Result<Vec<crate::types::Orders>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !campaign_id.is_empty() {
+            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+        }
+        if !customer_id.is_empty() {
+            query_args.push(("customer_id".to_string(), customer_id.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if has_outreach {
+            query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
+        }
+        if !outreach_id.is_empty() {
+            query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/ecommerce/orders?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OrdersData = self.client.get(&url, None).await?;
+            Ok(resp.orders)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List account orders.
+     *
+     * This function performs a `GET` to the `/ecommerce/orders` endpoint.
+     *
+     * As opposed to `get_order`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about an account's orders.
+     */
+    pub fn get_all_orders_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        outreach_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+    ) -> impl futures::Stream<Item = Result<crate::types::Orders>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !campaign_id.is_empty() {
+            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+        }
+        if !customer_id.is_empty() {
+            query_args.push(("customer_id".to_string(), customer_id.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if has_outreach {
+            query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
+        }
+        if !outreach_id.is_empty() {
+            query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/ecommerce/orders?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OrdersData = self.client.get(&url, None).await?;
+            Ok(resp.orders)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * List stores.
      *
@@ -112,6 +210,72 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List stores.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores` endpoint.
+     *
+     * As opposed to `get_store`, this function returns all the pages of the request at once.
+     *
+     * Get information about all stores in the account.
+     */
+    pub async fn get_all_stores(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<Vec<crate::types::Stores>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/ecommerce/stores?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ECommerceStores = self.client.get(&url, None).await?;
+            Ok(resp.stores)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List stores.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores` endpoint.
+     *
+     * As opposed to `get_store`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about all stores in the account.
+     */
+    pub fn get_all_stores_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> impl futures::Stream<Item = Result<crate::types::Stores>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/ecommerce/stores?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ECommerceStores = self.client.get(&url, None).await?;
+            Ok(resp.stores)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add store.
      *
@@ -257,6 +421,82 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List carts.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts` endpoint.
+     *
+     * As opposed to `get_stores_cart`, this function returns all the pages of the request at once.
+     *
+     * Get information about a store's carts.
+     */
+    pub async fn get_all_stores_carts(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> Result<Vec<crate::types::Carts>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/carts?{}",
+            crate::progenitor_support::encode_path(store_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CartsData = self.client.get(&url, None).await?;
+            Ok(resp.carts)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List carts.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts` endpoint.
+     *
+     * As opposed to `get_stores_cart`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about a store's carts.
+     */
+    pub fn get_all_stores_carts_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::Carts>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/carts?{}",
+            crate::progenitor_support::encode_path(store_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CartsData = self.client.get(&url, None).await?;
+            Ok(resp.carts)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add cart.
      *
@@ -422,6 +662,86 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List cart line items.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines` endpoint.
+     *
+     * As opposed to `get_stores_carts_line`, this function returns all the pages of the request at once.
+     *
+     * Get information about a cart's line items.
+     */
+    pub async fn get_all_stores_carts_lines(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+    ) -> Result<Vec<crate::types::ECommerceCartLineItem>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/carts/{}/lines?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(cart_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CartLines = self.client.get(&url, None).await?;
+            Ok(resp.lines)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List cart line items.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/carts/{cart_id}/lines` endpoint.
+     *
+     * As opposed to `get_stores_carts_line`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about a cart's line items.
+     */
+    pub fn get_all_stores_carts_lines_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        cart_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::ECommerceCartLineItem>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/carts/{}/lines?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(cart_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CartLines = self.client.get(&url, None).await?;
+            Ok(resp.lines)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add cart line item.
      *
@@ -543,15 +863,108 @@ impl Ecommerce {
         body: &crate::types::ECommerceCartLineItemDataType,
     ) -> Result<crate::types::ECommerceCartLineItem> {
         let url = format!(
-            "/ecommerce/stores/{}/carts/{}/lines/{}",
+            "/ecommerce/stores/{}/carts/{}/lines/{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(cart_id),
+            crate::progenitor_support::encode_path(line_id),
+        );
+
+        self.client
+            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .await
+    }
+
+    /**
+     * List customers.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/customers` endpoint.
+     *
+     * Get information about a store's customers.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
+     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `store_id: &str` -- The name of the folder.
+     * * `email_address: &str` -- Restrict the response to customers with the email address.
+     */
+    pub async fn get_stores_customer(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        email_address: &str,
+    ) -> Result<crate::types::Customers> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !email_address.is_empty() {
+            query_args.push(("email_address".to_string(), email_address.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/customers?{}",
+            crate::progenitor_support::encode_path(store_id),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List customers.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/customers` endpoint.
+     *
+     * As opposed to `get_stores_customer`, this function returns all the pages of the request at once.
+     *
+     * Get information about a store's customers.
+     */
+    pub async fn get_all_stores_customers(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        email_address: &str,
+    ) -> Result<Vec<crate::types::Customer>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !email_address.is_empty() {
+            query_args.push(("email_address".to_string(), email_address.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/customers?{}",
             crate::progenitor_support::encode_path(store_id),
-            crate::progenitor_support::encode_path(cart_id),
-            crate::progenitor_support::encode_path(line_id),
+            query_
         );
 
-        self.client
-            .patch(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::Customers = self.client.get(&url, None).await?;
+            Ok(resp.customers)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
     }
 
     /**
@@ -559,30 +972,18 @@ impl Ecommerce {
      *
      * This function performs a `GET` to the `/ecommerce/stores/{store_id}/customers` endpoint.
      *
-     * Get information about a store's customers.
-     *
-     * **Parameters:**
+     * As opposed to `get_stores_customer`, this function returns a stream of all the items, requesting the pages as they are needed.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `store_id: &str` -- The name of the folder.
-     * * `email_address: &str` -- Restrict the response to customers with the email address.
+     * Get information about a store's customers.
      */
-    pub async fn get_stores_customer(
+    pub fn get_all_stores_customers_stream(
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
         email_address: &str,
-    ) -> Result<crate::types::Customers> {
+    ) -> impl futures::Stream<Item = Result<crate::types::Customer>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
-        }
         if !email_address.is_empty() {
             query_args.push(("email_address".to_string(), email_address.to_string()));
         }
@@ -592,9 +993,6 @@ impl Ecommerce {
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
             "/ecommerce/stores/{}/customers?{}",
@@ -602,7 +1000,13 @@ impl Ecommerce {
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::Customers = self.client.get(&url, None).await?;
+            Ok(resp.customers)
+        });
+
+        // Return our response data.
+        resp
     }
 
     /**
@@ -796,6 +1200,82 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List promo rules.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules` endpoint.
+     *
+     * As opposed to `get_stores_promorule`, this function returns all the pages of the request at once.
+     *
+     * Get information about a store's promo rules.
+     */
+    pub async fn get_all_stores_promorules(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> Result<Vec<crate::types::PromoRules>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/promo-rules?{}",
+            crate::progenitor_support::encode_path(store_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::PromoRulesData = self.client.get(&url, None).await?;
+            Ok(resp.promo_rules)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List promo rules.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules` endpoint.
+     *
+     * As opposed to `get_stores_promorule`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about a store's promo rules.
+     */
+    pub fn get_all_stores_promorules_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::PromoRules>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/promo-rules?{}",
+            crate::progenitor_support::encode_path(store_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::PromoRulesData = self.client.get(&url, None).await?;
+            Ok(resp.promo_rules)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add promo rule.
      *
@@ -965,6 +1445,86 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List promo codes.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes` endpoint.
+     *
+     * As opposed to `get_stores_promocode`, this function returns all the pages of the request at once.
+     *
+     * Get information about a store's promo codes.
+     */
+    pub async fn get_all_stores_promocodes(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        promo_rule_id: &str,
+        store_id: &str,
+    ) -> Result<Vec<crate::types::PromoCodes>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/promo-rules/{}/promo-codes?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(promo_rule_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::PromoCodesData = self.client.get(&url, None).await?;
+            Ok(resp.promo_codes)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List promo codes.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/promo-rules/{promo_rule_id}/promo-codes` endpoint.
+     *
+     * As opposed to `get_stores_promocode`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about a store's promo codes.
+     */
+    pub fn get_all_stores_promocodes_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        promo_rule_id: &str,
+        store_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::PromoCodes>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/promo-rules/{}/promo-codes?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(promo_rule_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::PromoCodesData = self.client.get(&url, None).await?;
+            Ok(resp.promo_codes)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add promo code.
      *
@@ -1116,25 +1676,130 @@ impl Ecommerce {
      * * `campaign_id: &str` -- Restrict results to orders with a specific `campaign_id` value.
      * * `outreach_id: &str` -- Restrict results to orders with a specific `outreach_id` value.
      */
-    pub async fn get_stores_order(
+    pub async fn get_stores_order(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        store_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+        campaign_id: &str,
+        outreach_id: &str,
+    ) -> Result<crate::types::OrdersDataType> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !campaign_id.is_empty() {
+            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+        }
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !customer_id.is_empty() {
+            query_args.push(("customer_id".to_string(), customer_id.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if has_outreach {
+            query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !outreach_id.is_empty() {
+            query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/orders?{}",
+            crate::progenitor_support::encode_path(store_id),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List orders.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders` endpoint.
+     *
+     * As opposed to `get_stores_order`, this function returns all the pages of the request at once.
+     *
+     * Get information about a store's orders.
+     */
+    pub async fn get_all_stores_orders(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        customer_id: &str,
+        has_outreach: bool,
+        campaign_id: &str,
+        outreach_id: &str,
+    ) -> Result<Vec<crate::types::Orders>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !campaign_id.is_empty() {
+            query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
+        }
+        if !customer_id.is_empty() {
+            query_args.push(("customer_id".to_string(), customer_id.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if has_outreach {
+            query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
+        }
+        if !outreach_id.is_empty() {
+            query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/orders?{}",
+            crate::progenitor_support::encode_path(store_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OrdersDataType = self.client.get(&url, None).await?;
+            Ok(resp.orders)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List orders.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders` endpoint.
+     *
+     * As opposed to `get_stores_order`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about a store's orders.
+     */
+    pub fn get_all_stores_orders_stream(
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         store_id: &str,
         customer_id: &str,
         has_outreach: bool,
         campaign_id: &str,
         outreach_id: &str,
-    ) -> Result<crate::types::OrdersDataType> {
+    ) -> impl futures::Stream<Item = Result<crate::types::Orders>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !campaign_id.is_empty() {
             query_args.push(("campaign_id".to_string(), campaign_id.to_string()));
         }
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
-        }
         if !customer_id.is_empty() {
             query_args.push(("customer_id".to_string(), customer_id.to_string()));
         }
@@ -1147,9 +1812,6 @@ impl Ecommerce {
         if has_outreach {
             query_args.push(("has_outreach".to_string(), has_outreach.to_string()));
         }
-        if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
         if !outreach_id.is_empty() {
             query_args.push(("outreach_id".to_string(), outreach_id.to_string()));
         }
@@ -1160,7 +1822,13 @@ impl Ecommerce {
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OrdersDataType = self.client.get(&url, None).await?;
+            Ok(resp.orders)
+        });
+
+        // Return our response data.
+        resp
     }
 
     /**
@@ -1328,6 +1996,86 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List order line items.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines` endpoint.
+     *
+     * As opposed to `get_stores_orders_line`, this function returns all the pages of the request at once.
+     *
+     * Get information about an order's line items.
+     */
+    pub async fn get_all_stores_orders_lines(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+    ) -> Result<Vec<crate::types::Lines>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/orders/{}/lines?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(order_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OrderLines = self.client.get(&url, None).await?;
+            Ok(resp.lines)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List order line items.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/orders/{order_id}/lines` endpoint.
+     *
+     * As opposed to `get_stores_orders_line`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about an order's line items.
+     */
+    pub fn get_all_stores_orders_lines_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        order_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::Lines>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/orders/{}/lines?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(order_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OrderLines = self.client.get(&url, None).await?;
+            Ok(resp.lines)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add order line item.
      *
@@ -1506,6 +2254,82 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List product.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products` endpoint.
+     *
+     * As opposed to `get_stores_product`, this function returns all the pages of the request at once.
+     *
+     * Get information about a store's products.
+     */
+    pub async fn get_all_stores_products(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> Result<Vec<crate::types::ECommerceProduct>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/products?{}",
+            crate::progenitor_support::encode_path(store_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ProductsData = self.client.get(&url, None).await?;
+            Ok(resp.products)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List product.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products` endpoint.
+     *
+     * As opposed to `get_stores_product`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about a store's products.
+     */
+    pub fn get_all_stores_products_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::ECommerceProduct>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/products?{}",
+            crate::progenitor_support::encode_path(store_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ProductsData = self.client.get(&url, None).await?;
+            Ok(resp.products)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add product.
      *
@@ -1671,6 +2495,86 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List product variants.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants` endpoint.
+     *
+     * As opposed to `get_stores_products_variant`, this function returns all the pages of the request at once.
+     *
+     * Get information about a product's variants.
+     */
+    pub async fn get_all_stores_products_variants(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<Vec<crate::types::Variants>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}/variants?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(product_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::EcommerceProductVariants = self.client.get(&url, None).await?;
+            Ok(resp.variants)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List product variants.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/variants` endpoint.
+     *
+     * As opposed to `get_stores_products_variant`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about a product's variants.
+     */
+    pub fn get_all_stores_products_variants_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::Variants>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}/variants?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(product_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::EcommerceProductVariants = self.client.get(&url, None).await?;
+            Ok(resp.variants)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add product variant.
      *
@@ -1884,6 +2788,86 @@ impl Ecommerce {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List product images.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/images` endpoint.
+     *
+     * As opposed to `get_stores_products_image`, this function returns all the pages of the request at once.
+     *
+     * Get information about a product's images.
+     */
+    pub async fn get_all_stores_products_images(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+    ) -> Result<Vec<crate::types::Images>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}/images?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(product_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::EcommerceProductImages = self.client.get(&url, None).await?;
+            Ok(resp.images)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List product images.
+     *
+     * This function performs a `GET` to the `/ecommerce/stores/{store_id}/products/{product_id}/images` endpoint.
+     *
+     * As opposed to `get_stores_products_image`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about a product's images.
+     */
+    pub fn get_all_stores_products_images_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        store_id: &str,
+        product_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::Images>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/ecommerce/stores/{}/products/{}/images?{}",
+            crate::progenitor_support::encode_path(store_id),
+            crate::progenitor_support::encode_path(product_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::EcommerceProductImages = self.client.get(&url, None).await?;
+            Ok(resp.images)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add product image.
      *
@@ -62,6 +62,88 @@ impl FacebookAds {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List facebook ads.
+     *
+     * This function performs a `GET` to the `/facebook-ads` endpoint.
+     *
+     * As opposed to `get_all`, this function returns all the pages of the request at once.
+     *
+     * Get list of Facebook ads.
+     */
+    pub async fn get_all_pages(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<Vec<crate::types::FacebookAdsAllOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/facebook-ads?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetAllFacebookAdsResponse = self.client.get(&url, None).await?;
+            Ok(resp.facebook_ads)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List facebook ads.
+     *
+     * This function performs a `GET` to the `/facebook-ads` endpoint.
+     *
+     * As opposed to `get_all`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get list of Facebook ads.
+     */
+    pub fn get_all_pages_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> impl futures::Stream<Item = Result<crate::types::FacebookAdsAllOf>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/facebook-ads?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetAllFacebookAdsResponse = self.client.get(&url, None).await?;
+            Ok(resp.facebook_ads)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Get facebook ad info.
      *
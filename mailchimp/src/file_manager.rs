@@ -85,6 +85,126 @@ impl FileManager {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List stored files.
+     *
+     * This function performs a `GET` to the `/file-manager/files` endpoint.
+     *
+     * As opposed to `get_file`, this function returns all the pages of the request at once.
+     *
+     * Get a list of available images and files stored in the File Manager for the account.
+     */
+    pub async fn get_all_files(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: &str,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+        sort_field: crate::types::GetFileManagerFilesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<Vec<crate::types::Files>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_created_at.is_empty() {
+            query_args.push((
+                "before_created_at".to_string(),
+                before_created_at.to_string(),
+            ));
+        }
+        if !created_by.is_empty() {
+            query_args.push(("created_by".to_string(), created_by.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !since_created_at.is_empty() {
+            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/file-manager/files?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::FileManager = self.client.get(&url, None).await?;
+            Ok(resp.files)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List stored files.
+     *
+     * This function performs a `GET` to the `/file-manager/files` endpoint.
+     *
+     * As opposed to `get_file`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get a list of available images and files stored in the File Manager for the account.
+     */
+    pub fn get_all_files_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: &str,
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+        sort_field: crate::types::GetFileManagerFilesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> impl futures::Stream<Item = Result<crate::types::Files>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_created_at.is_empty() {
+            query_args.push((
+                "before_created_at".to_string(),
+                before_created_at.to_string(),
+            ));
+        }
+        if !created_by.is_empty() {
+            query_args.push(("created_by".to_string(), created_by.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !since_created_at.is_empty() {
+            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/file-manager/files?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::FileManager = self.client.get(&url, None).await?;
+            Ok(resp.files)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add file.
      *
@@ -239,6 +359,103 @@ impl FileManager {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List folders.
+     *
+     * This function performs a `GET` to the `/file-manager/folders` endpoint.
+     *
+     * As opposed to `get_folder`, this function returns all the pages of the request at once.
+     *
+     * Get a list of all folders in the File Manager.
+     */
+    pub async fn get_all_folders(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+    ) -> Result<Vec<crate::types::FileManagerFoldersGalleryFolder>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_created_at.is_empty() {
+            query_args.push((
+                "before_created_at".to_string(),
+                before_created_at.to_string(),
+            ));
+        }
+        if !created_by.is_empty() {
+            query_args.push(("created_by".to_string(), created_by.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !since_created_at.is_empty() {
+            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/file-manager/folders?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::FileManagerFolders = self.client.get(&url, None).await?;
+            Ok(resp.folders)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List folders.
+     *
+     * This function performs a `GET` to the `/file-manager/folders` endpoint.
+     *
+     * As opposed to `get_folder`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get a list of all folders in the File Manager.
+     */
+    pub fn get_all_folders_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        created_by: &str,
+        before_created_at: &str,
+        since_created_at: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::FileManagerFoldersGalleryFolder>> + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_created_at.is_empty() {
+            query_args.push((
+                "before_created_at".to_string(),
+                before_created_at.to_string(),
+            ));
+        }
+        if !created_by.is_empty() {
+            query_args.push(("created_by".to_string(), created_by.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !since_created_at.is_empty() {
+            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/file-manager/folders?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::FileManagerFolders = self.client.get(&url, None).await?;
+            Ok(resp.folders)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add folder.
      *
@@ -115,6 +115,180 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * Get lists info.
+     *
+     * This function performs a `GET` to the `/lists` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * Get information about all lists in the account.
+     */
+    pub async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        before_date_created: &str,
+        since_date_created: &str,
+        before_campaign_last_sent: &str,
+        since_campaign_last_sent: &str,
+        email: &str,
+        sort_field: crate::types::GetListsSortField,
+        sort_dir: crate::types::SortDir,
+        has_ecommerce_store: bool,
+        include_total_contacts: bool,
+    ) -> Result<Vec<crate::types::Lists>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_campaign_last_sent.is_empty() {
+            query_args.push((
+                "before_campaign_last_sent".to_string(),
+                before_campaign_last_sent.to_string(),
+            ));
+        }
+        if !before_date_created.is_empty() {
+            query_args.push((
+                "before_date_created".to_string(),
+                before_date_created.to_string(),
+            ));
+        }
+        if !email.is_empty() {
+            query_args.push(("email".to_string(), email.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if has_ecommerce_store {
+            query_args.push((
+                "has_ecommerce_store".to_string(),
+                has_ecommerce_store.to_string(),
+            ));
+        }
+        if include_total_contacts {
+            query_args.push((
+                "include_total_contacts".to_string(),
+                include_total_contacts.to_string(),
+            ));
+        }
+        if !since_campaign_last_sent.is_empty() {
+            query_args.push((
+                "since_campaign_last_sent".to_string(),
+                since_campaign_last_sent.to_string(),
+            ));
+        }
+        if !since_date_created.is_empty() {
+            query_args.push((
+                "since_date_created".to_string(),
+                since_date_created.to_string(),
+            ));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/lists?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::SubscriberLists = self.client.get(&url, None).await?;
+            Ok(resp.lists)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * Get lists info.
+     *
+     * This function performs a `GET` to the `/lists` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about all lists in the account.
+     */
+    pub fn get_all_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        before_date_created: &str,
+        since_date_created: &str,
+        before_campaign_last_sent: &str,
+        since_campaign_last_sent: &str,
+        email: &str,
+        sort_field: crate::types::GetListsSortField,
+        sort_dir: crate::types::SortDir,
+        has_ecommerce_store: bool,
+        include_total_contacts: bool,
+    ) -> impl futures::Stream<Item = Result<crate::types::Lists>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_campaign_last_sent.is_empty() {
+            query_args.push((
+                "before_campaign_last_sent".to_string(),
+                before_campaign_last_sent.to_string(),
+            ));
+        }
+        if !before_date_created.is_empty() {
+            query_args.push((
+                "before_date_created".to_string(),
+                before_date_created.to_string(),
+            ));
+        }
+        if !email.is_empty() {
+            query_args.push(("email".to_string(), email.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if has_ecommerce_store {
+            query_args.push((
+                "has_ecommerce_store".to_string(),
+                has_ecommerce_store.to_string(),
+            ));
+        }
+        if include_total_contacts {
+            query_args.push((
+                "include_total_contacts".to_string(),
+                include_total_contacts.to_string(),
+            ));
+        }
+        if !since_campaign_last_sent.is_empty() {
+            query_args.push((
+                "since_campaign_last_sent".to_string(),
+                since_campaign_last_sent.to_string(),
+            ));
+        }
+        if !since_date_created.is_empty() {
+            query_args.push((
+                "since_date_created".to_string(),
+                since_date_created.to_string(),
+            ));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/lists?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::SubscriberLists = self.client.get(&url, None).await?;
+            Ok(resp.lists)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add list.
      *
@@ -304,6 +478,82 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List abuse reports.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/abuse-reports` endpoint.
+     *
+     * As opposed to `get_abuse_report`, this function returns all the pages of the request at once.
+     *
+     * Get all abuse reports for a specific list.
+     */
+    pub async fn get_all_abuse_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> Result<Vec<crate::types::AbuseReports>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/abuse-reports?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::AbuseComplaints = self.client.get(&url, None).await?;
+            Ok(resp.abuse_reports)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List abuse reports.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/abuse-reports` endpoint.
+     *
+     * As opposed to `get_abuse_report`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get all abuse reports for a specific list.
+     */
+    pub fn get_all_abuse_reports_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::AbuseReports>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/abuse-reports?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::AbuseComplaints = self.client.get(&url, None).await?;
+            Ok(resp.abuse_reports)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Get abuse report.
      *
@@ -482,26 +732,22 @@ impl Lists {
     }
 
     /**
-     * Get growth history by month.
-     *
-     * This function performs a `GET` to the `/lists/{list_id}/growth-history/{month}` endpoint.
+     * List growth history data.
      *
-     * Get a summary of a specific list's growth activity for a specific month and year.
+     * This function performs a `GET` to the `/lists/{list_id}/growth-history` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_growth_history`, this function returns all the pages of the request at once.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `list_id: &str` -- The unique ID for the list.
-     * * `month: &str` -- A specific month of list growth history.
+     * Get a month-by-month summary of a specific list's growth activity.
      */
-    pub async fn get_growth_history_lists(
+    pub async fn get_all_growth_history(
         &self,
         fields: &[String],
         exclude_fields: &[String],
         list_id: &str,
-        month: &str,
-    ) -> Result<crate::types::History> {
+        sort_field: crate::types::GetListsGrowthHistorySortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<Vec<crate::types::History>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -509,32 +755,128 @@ impl Lists {
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/lists/{}/growth-history/{}?{}",
+            "/lists/{}/growth-history?{}",
             crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(month),
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GrowthHistory = self.client.get(&url, None).await?;
+            Ok(resp.history)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
     }
 
     /**
-     * List interest categories.
-     *
-     * This function performs a `GET` to the `/lists/{list_id}/interest-categories` endpoint.
+     * List growth history data.
      *
-     * Get information about a list's interest categories.
+     * This function performs a `GET` to the `/lists/{list_id}/growth-history` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_growth_history`, this function returns a stream of all the items, requesting the pages as they are needed.
      *
-     * * `list_id: &str` -- The unique ID for the list.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `type_: &str` -- Restrict results a type of interest group.
+     * Get a month-by-month summary of a specific list's growth activity.
+     */
+    pub fn get_all_growth_history_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        sort_field: crate::types::GetListsGrowthHistorySortField,
+        sort_dir: crate::types::SortDir,
+    ) -> impl futures::Stream<Item = Result<crate::types::History>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/growth-history?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GrowthHistory = self.client.get(&url, None).await?;
+            Ok(resp.history)
+        });
+
+        // Return our response data.
+        resp
+    }
+
+    /**
+     * Get growth history by month.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/growth-history/{month}` endpoint.
+     *
+     * Get a summary of a specific list's growth activity for a specific month and year.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `list_id: &str` -- The unique ID for the list.
+     * * `month: &str` -- A specific month of list growth history.
+     */
+    pub async fn get_growth_history_lists(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        month: &str,
+    ) -> Result<crate::types::History> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/growth-history/{}?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(month),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List interest categories.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories` endpoint.
+     *
+     * Get information about a list's interest categories.
+     *
+     * **Parameters:**
+     *
+     * * `list_id: &str` -- The unique ID for the list.
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
+     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `type_: &str` -- Restrict results a type of interest group.
      */
     pub async fn get_interest_categorie(
         &self,
@@ -571,6 +913,90 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List interest categories.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories` endpoint.
+     *
+     * As opposed to `get_interest_categorie`, this function returns all the pages of the request at once.
+     *
+     * Get information about a list's interest categories.
+     */
+    pub async fn get_all_interest_categories(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: &str,
+    ) -> Result<Vec<crate::types::Categories>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/interest-categories?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::InterestGroupings = self.client.get(&url, None).await?;
+            Ok(resp.categories)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List interest categories.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories` endpoint.
+     *
+     * As opposed to `get_interest_categorie`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about a list's interest categories.
+     */
+    pub fn get_all_interest_categories_stream(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::Categories>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/interest-categories?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::InterestGroupings = self.client.get(&url, None).await?;
+            Ok(resp.categories)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add interest category.
      *
@@ -740,6 +1166,86 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List interests in category.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests` endpoint.
+     *
+     * As opposed to `get_interest_categories_interest`, this function returns all the pages of the request at once.
+     *
+     * Get a list of this category's interests.
+     */
+    pub async fn get_all_interest_categories_interests(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<Vec<crate::types::InterestsInterest>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/interest-categories/{}/interests?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(interest_category_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::InterestsData = self.client.get(&url, None).await?;
+            Ok(resp.interests)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List interests in category.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/interest-categories/{interest_category_id}/interests` endpoint.
+     *
+     * As opposed to `get_interest_categories_interest`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get a list of this category's interests.
+     */
+    pub fn get_all_interest_categories_interests_stream(
+        &self,
+        list_id: &str,
+        interest_category_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> impl futures::Stream<Item = Result<crate::types::InterestsInterest>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/interest-categories/{}/interests?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(interest_category_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::InterestsData = self.client.get(&url, None).await?;
+            Ok(resp.interests)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add interest in category.
      *
@@ -971,55 +1477,219 @@ impl Lists {
     }
 
     /**
-     * Add segment.
-     *
-     * This function performs a `POST` to the `/lists/{list_id}/segments` endpoint.
-     *
-     * Create a new segment in a specific list.
-     *
-     * **Parameters:**
-     *
-     * * `list_id: &str` -- The unique ID for the list.
-     */
-    pub async fn post_segment(
-        &self,
-        list_id: &str,
-        body: &crate::types::ListData,
-    ) -> Result<crate::types::Segments> {
-        let url = format!(
-            "/lists/{}/segments",
-            crate::progenitor_support::encode_path(list_id),
-        );
-
-        self.client
-            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
-            .await
-    }
-
-    /**
-     * Get segment info.
-     *
-     * This function performs a `GET` to the `/lists/{list_id}/segments/{segment_id}` endpoint.
+     * List segments.
      *
-     * Get information about a specific segment.
+     * This function performs a `GET` to the `/lists/{list_id}/segments` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `preview_segment`, this function returns all the pages of the request at once.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `list_id: &str` -- The unique ID for the list.
-     * * `segment_id: &str` -- The unique id for the segment.
-     * * `include_cleaned: bool` -- Whether the webhook is triggered when a list subscriber is added.
-     * * `include_transactional: bool` -- Whether the webhook is triggered when a list subscriber is added.
-     * * `include_unsubscribed: bool` -- Whether the webhook is triggered when a list subscriber is added.
+     * Get information about all available segments for a specific list.
      */
-    pub async fn get_segment(
+    pub async fn get_all_preview_segments(
         &self,
         fields: &[String],
         exclude_fields: &[String],
         list_id: &str,
-        segment_id: &str,
-        include_cleaned: bool,
+        type_: &str,
+        since_created_at: &str,
+        before_created_at: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+        since_updated_at: &str,
+        before_updated_at: &str,
+    ) -> Result<Vec<crate::types::Segments>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_created_at.is_empty() {
+            query_args.push((
+                "before_created_at".to_string(),
+                before_created_at.to_string(),
+            ));
+        }
+        if !before_updated_at.is_empty() {
+            query_args.push((
+                "before_updated_at".to_string(),
+                before_updated_at.to_string(),
+            ));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if include_cleaned {
+            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+        }
+        if include_transactional {
+            query_args.push((
+                "include_transactional".to_string(),
+                include_transactional.to_string(),
+            ));
+        }
+        if include_unsubscribed {
+            query_args.push((
+                "include_unsubscribed".to_string(),
+                include_unsubscribed.to_string(),
+            ));
+        }
+        if !since_created_at.is_empty() {
+            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+        }
+        if !since_updated_at.is_empty() {
+            query_args.push(("since_updated_at".to_string(), since_updated_at.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/segments?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfSegments = self.client.get(&url, None).await?;
+            Ok(resp.segments)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List segments.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments` endpoint.
+     *
+     * As opposed to `preview_segment`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about all available segments for a specific list.
+     */
+    pub fn get_all_preview_segments_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        type_: &str,
+        since_created_at: &str,
+        before_created_at: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+        since_updated_at: &str,
+        before_updated_at: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::Segments>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_created_at.is_empty() {
+            query_args.push((
+                "before_created_at".to_string(),
+                before_created_at.to_string(),
+            ));
+        }
+        if !before_updated_at.is_empty() {
+            query_args.push((
+                "before_updated_at".to_string(),
+                before_updated_at.to_string(),
+            ));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if include_cleaned {
+            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+        }
+        if include_transactional {
+            query_args.push((
+                "include_transactional".to_string(),
+                include_transactional.to_string(),
+            ));
+        }
+        if include_unsubscribed {
+            query_args.push((
+                "include_unsubscribed".to_string(),
+                include_unsubscribed.to_string(),
+            ));
+        }
+        if !since_created_at.is_empty() {
+            query_args.push(("since_created_at".to_string(), since_created_at.to_string()));
+        }
+        if !since_updated_at.is_empty() {
+            query_args.push(("since_updated_at".to_string(), since_updated_at.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/segments?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfSegments = self.client.get(&url, None).await?;
+            Ok(resp.segments)
+        });
+
+        // Return our response data.
+        resp
+    }
+
+    /**
+     * Add segment.
+     *
+     * This function performs a `POST` to the `/lists/{list_id}/segments` endpoint.
+     *
+     * Create a new segment in a specific list.
+     *
+     * **Parameters:**
+     *
+     * * `list_id: &str` -- The unique ID for the list.
+     */
+    pub async fn post_segment(
+        &self,
+        list_id: &str,
+        body: &crate::types::ListData,
+    ) -> Result<crate::types::Segments> {
+        let url = format!(
+            "/lists/{}/segments",
+            crate::progenitor_support::encode_path(list_id),
+        );
+
+        self.client
+            .post(&url, Some(reqwest::Body::from(serde_json::to_vec(body)?)))
+            .await
+    }
+
+    /**
+     * Get segment info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments/{segment_id}` endpoint.
+     *
+     * Get information about a specific segment.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `list_id: &str` -- The unique ID for the list.
+     * * `segment_id: &str` -- The unique id for the segment.
+     * * `include_cleaned: bool` -- Whether the webhook is triggered when a list subscriber is added.
+     * * `include_transactional: bool` -- Whether the webhook is triggered when a list subscriber is added.
+     * * `include_unsubscribed: bool` -- Whether the webhook is triggered when a list subscriber is added.
+     */
+    pub async fn get_segment(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
         include_transactional: bool,
         include_unsubscribed: bool,
     ) -> Result<crate::types::Segments> {
@@ -1206,6 +1876,122 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List members in segment.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments/{segment_id}/members` endpoint.
+     *
+     * As opposed to `get_segments_member`, this function returns all the pages of the request at once.
+     *
+     * Get information about members in a saved segment.
+     */
+    pub async fn get_all_segments_members(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> Result<Vec<crate::types::ListMembers>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if include_cleaned {
+            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+        }
+        if include_transactional {
+            query_args.push((
+                "include_transactional".to_string(),
+                include_transactional.to_string(),
+            ));
+        }
+        if include_unsubscribed {
+            query_args.push((
+                "include_unsubscribed".to_string(),
+                include_unsubscribed.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/segments/{}/members?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(segment_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::SegmentMembers = self.client.get(&url, None).await?;
+            Ok(resp.members)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List members in segment.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/segments/{segment_id}/members` endpoint.
+     *
+     * As opposed to `get_segments_member`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about members in a saved segment.
+     */
+    pub fn get_all_segments_members_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        list_id: &str,
+        segment_id: &str,
+        include_cleaned: bool,
+        include_transactional: bool,
+        include_unsubscribed: bool,
+    ) -> impl futures::Stream<Item = Result<crate::types::ListMembers>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if include_cleaned {
+            query_args.push(("include_cleaned".to_string(), include_cleaned.to_string()));
+        }
+        if include_transactional {
+            query_args.push((
+                "include_transactional".to_string(),
+                include_transactional.to_string(),
+            ));
+        }
+        if include_unsubscribed {
+            query_args.push((
+                "include_unsubscribed".to_string(),
+                include_unsubscribed.to_string(),
+            ));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/segments/{}/members?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(segment_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::SegmentMembers = self.client.get(&url, None).await?;
+            Ok(resp.members)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add member to segment.
      *
@@ -1255,44 +2041,305 @@ impl Lists {
         subscriber_hash: &str,
     ) -> Result<()> {
         let url = format!(
-            "/lists/{}/segments/{}/members/{}",
+            "/lists/{}/segments/{}/members/{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(segment_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
+        );
+
+        self.client.delete(&url, None).await
+    }
+
+    /**
+     * Search for tags on a list by name.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/tag-search` endpoint.
+     *
+     * Search for tags on a list by name. If no name is provided, will return all tags on the list.
+     *
+     * **Parameters:**
+     *
+     * * `list_id: &str` -- The unique ID for the list.
+     * * `name: &str` -- The search query used to filter tags.  The search query will be compared to each tag as a prefix, so all tags that have a name starting with this field will be returned.
+     */
+    pub async fn search_tags_name(
+        &self,
+        list_id: &str,
+        name: &str,
+    ) -> Result<crate::types::TagSearchResults> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !name.is_empty() {
+            query_args.push(("name".to_string(), name.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/tag-search?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List members info.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members` endpoint.
+     *
+     * Get information about members in a specific Mailchimp list.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
+     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `list_id: &str` -- The unique ID for the list.
+     * * `email_type: &str` -- The name of the folder.
+     * * `status: crate::types::GetListsMembersStatus` -- The subscriber's status.
+     * * `since_timestamp_opt: &str` -- Restrict results to subscribers who opted-in after the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `before_timestamp_opt: &str` -- Restrict results to subscribers who opted-in before the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `since_last_changed: &str` -- Restrict results to subscribers whose information changed after the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `before_last_changed: &str` -- Restrict results to subscribers whose information changed before the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `unique_email_id: &str` -- A unique identifier for the email address across all Mailchimp lists.
+     * * `vip_only: bool` -- A filter to return only the list's VIP members. Passing `true` will restrict results to VIP list members, passing `false` will return all list members.
+     * * `interest_category_id: &str` -- The unique id for the interest category.
+     * * `interest_ids: &str` -- Used to filter list members by interests. Must be accompanied by interest_category_id and interest_match. The value must be a comma separated list of interest ids present for any supplied interest categories.
+     * * `interest_match: crate::types::InterestMatch` -- Used to filter list members by interests. Must be accompanied by interest_category_id and interest_ids. "any" will match a member with any of the interest supplied, "all" will only match members with every interest supplied, and "none" will match members without any of the interest supplied.
+     * * `sort_field: crate::types::GetListsMembersSortField` -- Returns files sorted by the specified field.
+     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
+     * * `since_last_campaign: bool` -- Filter subscribers by those subscribed/unsubscribed/pending/cleaned since last email campaign send. Member status is required to use this filter.
+     * * `unsubscribed_since: &str` -- Filter subscribers by those unsubscribed since a specific date. Using any status other than unsubscribed with this filter will result in an error.
+     */
+    pub async fn get_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        list_id: &str,
+        email_type: &str,
+        status: crate::types::GetListsMembersStatus,
+        since_timestamp_opt: &str,
+        before_timestamp_opt: &str,
+        since_last_changed: &str,
+        before_last_changed: &str,
+        unique_email_id: &str,
+        vip_only: bool,
+        interest_category_id: &str,
+        interest_ids: &str,
+        interest_match: crate::types::InterestMatch,
+        sort_field: crate::types::GetListsMembersSortField,
+        sort_dir: crate::types::SortDir,
+        since_last_campaign: bool,
+        unsubscribed_since: &str,
+    ) -> Result<crate::types::ListMembersDataType> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_last_changed.is_empty() {
+            query_args.push((
+                "before_last_changed".to_string(),
+                before_last_changed.to_string(),
+            ));
+        }
+        if !before_timestamp_opt.is_empty() {
+            query_args.push((
+                "before_timestamp_opt".to_string(),
+                before_timestamp_opt.to_string(),
+            ));
+        }
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !email_type.is_empty() {
+            query_args.push(("email_type".to_string(), email_type.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !interest_category_id.is_empty() {
+            query_args.push((
+                "interest_category_id".to_string(),
+                interest_category_id.to_string(),
+            ));
+        }
+        if !interest_ids.is_empty() {
+            query_args.push(("interest_ids".to_string(), interest_ids.to_string()));
+        }
+        if !interest_match.to_string().is_empty() {
+            query_args.push(("interest_match".to_string(), interest_match.to_string()));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if since_last_campaign {
+            query_args.push((
+                "since_last_campaign".to_string(),
+                since_last_campaign.to_string(),
+            ));
+        }
+        if !since_last_changed.is_empty() {
+            query_args.push((
+                "since_last_changed".to_string(),
+                since_last_changed.to_string(),
+            ));
+        }
+        if !since_timestamp_opt.is_empty() {
+            query_args.push((
+                "since_timestamp_opt".to_string(),
+                since_timestamp_opt.to_string(),
+            ));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !unique_email_id.is_empty() {
+            query_args.push(("unique_email_id".to_string(), unique_email_id.to_string()));
+        }
+        if !unsubscribed_since.is_empty() {
+            query_args.push((
+                "unsubscribed_since".to_string(),
+                unsubscribed_since.to_string(),
+            ));
+        }
+        if vip_only {
+            query_args.push(("vip_only".to_string(), vip_only.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/members?{}",
             crate::progenitor_support::encode_path(list_id),
-            crate::progenitor_support::encode_path(segment_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
+            query_
         );
 
-        self.client.delete(&url, None).await
+        self.client.get(&url, None).await
     }
 
     /**
-     * Search for tags on a list by name.
-     *
-     * This function performs a `GET` to the `/lists/{list_id}/tag-search` endpoint.
+     * List members info.
      *
-     * Search for tags on a list by name. If no name is provided, will return all tags on the list.
+     * This function performs a `GET` to the `/lists/{list_id}/members` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_member`, this function returns all the pages of the request at once.
      *
-     * * `list_id: &str` -- The unique ID for the list.
-     * * `name: &str` -- The search query used to filter tags.  The search query will be compared to each tag as a prefix, so all tags that have a name starting with this field will be returned.
+     * Get information about members in a specific Mailchimp list.
      */
-    pub async fn search_tags_name(
+    pub async fn get_all_members(
         &self,
+        fields: &[String],
+        exclude_fields: &[String],
         list_id: &str,
-        name: &str,
-    ) -> Result<crate::types::TagSearchResults> {
+        email_type: &str,
+        status: crate::types::GetListsMembersStatus,
+        since_timestamp_opt: &str,
+        before_timestamp_opt: &str,
+        since_last_changed: &str,
+        before_last_changed: &str,
+        unique_email_id: &str,
+        vip_only: bool,
+        interest_category_id: &str,
+        interest_ids: &str,
+        interest_match: crate::types::InterestMatch,
+        sort_field: crate::types::GetListsMembersSortField,
+        sort_dir: crate::types::SortDir,
+        since_last_campaign: bool,
+        unsubscribed_since: &str,
+    ) -> Result<Vec<crate::types::ListMembersData>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !name.is_empty() {
-            query_args.push(("name".to_string(), name.to_string()));
+        if !before_last_changed.is_empty() {
+            query_args.push((
+                "before_last_changed".to_string(),
+                before_last_changed.to_string(),
+            ));
+        }
+        if !before_timestamp_opt.is_empty() {
+            query_args.push((
+                "before_timestamp_opt".to_string(),
+                before_timestamp_opt.to_string(),
+            ));
+        }
+        if !email_type.is_empty() {
+            query_args.push(("email_type".to_string(), email_type.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !interest_category_id.is_empty() {
+            query_args.push((
+                "interest_category_id".to_string(),
+                interest_category_id.to_string(),
+            ));
+        }
+        if !interest_ids.is_empty() {
+            query_args.push(("interest_ids".to_string(), interest_ids.to_string()));
+        }
+        if !interest_match.to_string().is_empty() {
+            query_args.push(("interest_match".to_string(), interest_match.to_string()));
+        }
+        if since_last_campaign {
+            query_args.push((
+                "since_last_campaign".to_string(),
+                since_last_campaign.to_string(),
+            ));
+        }
+        if !since_last_changed.is_empty() {
+            query_args.push((
+                "since_last_changed".to_string(),
+                since_last_changed.to_string(),
+            ));
+        }
+        if !since_timestamp_opt.is_empty() {
+            query_args.push((
+                "since_timestamp_opt".to_string(),
+                since_timestamp_opt.to_string(),
+            ));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        if !status.to_string().is_empty() {
+            query_args.push(("status".to_string(), status.to_string()));
+        }
+        if !unique_email_id.is_empty() {
+            query_args.push(("unique_email_id".to_string(), unique_email_id.to_string()));
+        }
+        if !unsubscribed_since.is_empty() {
+            query_args.push((
+                "unsubscribed_since".to_string(),
+                unsubscribed_since.to_string(),
+            ));
+        }
+        if vip_only {
+            query_args.push(("vip_only".to_string(), vip_only.to_string()));
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/lists/{}/tag-search?{}",
+            "/lists/{}/members?{}",
             crate::progenitor_support::encode_path(list_id),
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ListMembersDataType = self.client.get(&url, None).await?;
+            Ok(resp.members)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
     }
 
     /**
@@ -1300,37 +2347,14 @@ impl Lists {
      *
      * This function performs a `GET` to the `/lists/{list_id}/members` endpoint.
      *
-     * Get information about members in a specific Mailchimp list.
-     *
-     * **Parameters:**
+     * As opposed to `get_member`, this function returns a stream of all the items, requesting the pages as they are needed.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `list_id: &str` -- The unique ID for the list.
-     * * `email_type: &str` -- The name of the folder.
-     * * `status: crate::types::GetListsMembersStatus` -- The subscriber's status.
-     * * `since_timestamp_opt: &str` -- Restrict results to subscribers who opted-in after the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_timestamp_opt: &str` -- Restrict results to subscribers who opted-in before the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `since_last_changed: &str` -- Restrict results to subscribers whose information changed after the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `before_last_changed: &str` -- Restrict results to subscribers whose information changed before the set timeframe. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
-     * * `unique_email_id: &str` -- A unique identifier for the email address across all Mailchimp lists.
-     * * `vip_only: bool` -- A filter to return only the list's VIP members. Passing `true` will restrict results to VIP list members, passing `false` will return all list members.
-     * * `interest_category_id: &str` -- The unique id for the interest category.
-     * * `interest_ids: &str` -- Used to filter list members by interests. Must be accompanied by interest_category_id and interest_match. The value must be a comma separated list of interest ids present for any supplied interest categories.
-     * * `interest_match: crate::types::InterestMatch` -- Used to filter list members by interests. Must be accompanied by interest_category_id and interest_ids. "any" will match a member with any of the interest supplied, "all" will only match members with every interest supplied, and "none" will match members without any of the interest supplied.
-     * * `sort_field: crate::types::GetListsMembersSortField` -- Returns files sorted by the specified field.
-     * * `sort_dir: crate::types::SortDir` -- Determines the order direction for sorted results.
-     * * `since_last_campaign: bool` -- Filter subscribers by those subscribed/unsubscribed/pending/cleaned since last email campaign send. Member status is required to use this filter.
-     * * `unsubscribed_since: &str` -- Filter subscribers by those unsubscribed since a specific date. Using any status other than unsubscribed with this filter will result in an error.
+     * Get information about members in a specific Mailchimp list.
      */
-    pub async fn get_member(
+    pub fn get_all_members_stream(
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         list_id: &str,
         email_type: &str,
         status: crate::types::GetListsMembersStatus,
@@ -1347,7 +2371,7 @@ impl Lists {
         sort_dir: crate::types::SortDir,
         since_last_campaign: bool,
         unsubscribed_since: &str,
-    ) -> Result<crate::types::ListMembersDataType> {
+    ) -> impl futures::Stream<Item = Result<crate::types::ListMembersData>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !before_last_changed.is_empty() {
             query_args.push((
@@ -1361,9 +2385,6 @@ impl Lists {
                 before_timestamp_opt.to_string(),
             ));
         }
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
-        }
         if !email_type.is_empty() {
             query_args.push(("email_type".to_string(), email_type.to_string()));
         }
@@ -1385,9 +2406,6 @@ impl Lists {
         if !interest_match.to_string().is_empty() {
             query_args.push(("interest_match".to_string(), interest_match.to_string()));
         }
-        if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
         if since_last_campaign {
             query_args.push((
                 "since_last_campaign".to_string(),
@@ -1434,7 +2452,13 @@ impl Lists {
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ListMembersDataType = self.client.get(&url, None).await?;
+            Ok(resp.members)
+        });
+
+        // Return our response data.
+        resp
     }
 
     /**
@@ -1676,41 +2700,130 @@ impl Lists {
      * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `activity_filters: &[String]` -- A comma-separated list of activity filters that correspond to a set of activity types, e.g "?activity_filters=open,bounce,click".
      */
-    pub async fn get_members_activity_feed(
+    pub async fn get_members_activity_feed(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        activity_filters: &[String],
+    ) -> Result<crate::types::MemberActivityEventsData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !activity_filters.is_empty() {
+            query_args.push(("activity_filters".to_string(), activity_filters.join(" ")));
+        }
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/members/{}/activity-feed?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List member tags.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/tags` endpoint.
+     *
+     * Get the tags on a list member.
+     *
+     * **Parameters:**
+     *
+     * * `list_id: &str` -- The unique ID for the list.
+     * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
+     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     */
+    pub async fn get_member_tag(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+    ) -> Result<crate::types::CollectionOfTags> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/members/{}/tags?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List member tags.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/tags` endpoint.
+     *
+     * As opposed to `get_member_tag`, this function returns all the pages of the request at once.
+     *
+     * Get the tags on a list member.
+     */
+    pub async fn get_all_member_tags(
         &self,
         list_id: &str,
         subscriber_hash: &str,
         fields: &[String],
         exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-        activity_filters: &[String],
-    ) -> Result<crate::types::MemberActivityEventsData> {
+    ) -> Result<Vec<String>> {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if !activity_filters.is_empty() {
-            query_args.push(("activity_filters".to_string(), activity_filters.join(" ")));
-        }
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
-        }
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/lists/{}/members/{}/activity-feed?{}",
+            "/lists/{}/members/{}/tags?{}",
             crate::progenitor_support::encode_path(list_id),
             crate::progenitor_support::encode_path(subscriber_hash),
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfTags = self.client.get(&url, None).await?;
+            Ok(resp.tags)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
     }
 
     /**
@@ -1718,39 +2831,24 @@ impl Lists {
      *
      * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/tags` endpoint.
      *
-     * Get the tags on a list member.
-     *
-     * **Parameters:**
+     * As opposed to `get_member_tag`, this function returns a stream of all the items, requesting the pages as they are needed.
      *
-     * * `list_id: &str` -- The unique ID for the list.
-     * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * Get the tags on a list member.
      */
-    pub async fn get_member_tag(
+    pub fn get_all_member_tags_stream(
         &self,
         list_id: &str,
         subscriber_hash: &str,
         fields: &[String],
         exclude_fields: &[String],
-        count: i64,
-        offset: i64,
-    ) -> Result<crate::types::CollectionOfTags> {
+    ) -> impl futures::Stream<Item = Result<String>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
-        }
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
             "/lists/{}/members/{}/tags?{}",
@@ -1759,7 +2857,13 @@ impl Lists {
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfTags = self.client.get(&url, None).await?;
+            Ok(resp.tags)
+        });
+
+        // Return our response data.
+        resp
     }
 
     /**
@@ -1840,6 +2944,86 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List member events.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/events` endpoint.
+     *
+     * As opposed to `get_members_event`, this function returns all the pages of the request at once.
+     *
+     * Get events for a contact.
+     */
+    pub async fn get_all_members_events(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<Vec<crate::types::Event>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/members/{}/events?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfEvents = self.client.get(&url, None).await?;
+            Ok(resp.events)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List member events.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/events` endpoint.
+     *
+     * As opposed to `get_members_event`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get events for a contact.
+     */
+    pub fn get_all_members_events_stream(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> impl futures::Stream<Item = Result<crate::types::Event>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/members/{}/events?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfEvents = self.client.get(&url, None).await?;
+            Ok(resp.events)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add event.
      *
@@ -1967,6 +3151,102 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List recent member notes.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/notes` endpoint.
+     *
+     * As opposed to `get_members_note`, this function returns all the pages of the request at once.
+     *
+     * Get recent notes for a specific list member.
+     */
+    pub async fn get_all_members_notes(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        sort_field: crate::types::GetListsMembersNotesSortField,
+        sort_dir: crate::types::SortDir,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<Vec<crate::types::CollectionOfNotesMember>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/members/{}/notes?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfNotes = self.client.get(&url, None).await?;
+            Ok(resp.notes)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List recent member notes.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/members/{subscriber_hash}/notes` endpoint.
+     *
+     * As opposed to `get_members_note`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get recent notes for a specific list member.
+     */
+    pub fn get_all_members_notes_stream(
+        &self,
+        list_id: &str,
+        subscriber_hash: &str,
+        sort_field: crate::types::GetListsMembersNotesSortField,
+        sort_dir: crate::types::SortDir,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> impl futures::Stream<Item = Result<crate::types::CollectionOfNotesMember>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/members/{}/notes?{}",
+            crate::progenitor_support::encode_path(list_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfNotes = self.client.get(&url, None).await?;
+            Ok(resp.notes)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add member note.
      *
@@ -2181,6 +3461,98 @@ impl Lists {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List merge fields.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/merge-fields` endpoint.
+     *
+     * As opposed to `get_merge_field`, this function returns all the pages of the request at once.
+     *
+     * Get a list of all merge fields ([audience fields](https://mailchimp.com/help/getting-started-with-merge-tags/)) for a list.
+     */
+    pub async fn get_all_merge_fields(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: &str,
+        required: bool,
+    ) -> Result<Vec<crate::types::MergeField>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if required {
+            query_args.push(("required".to_string(), required.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/merge-fields?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfMergeFields = self.client.get(&url, None).await?;
+            Ok(resp.merge_fields)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List merge fields.
+     *
+     * This function performs a `GET` to the `/lists/{list_id}/merge-fields` endpoint.
+     *
+     * As opposed to `get_merge_field`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get a list of all merge fields ([audience fields](https://mailchimp.com/help/getting-started-with-merge-tags/)) for a list.
+     */
+    pub fn get_all_merge_fields_stream(
+        &self,
+        list_id: &str,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: &str,
+        required: bool,
+    ) -> impl futures::Stream<Item = Result<crate::types::MergeField>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if required {
+            query_args.push(("required".to_string(), required.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/lists/{}/merge-fields?{}",
+            crate::progenitor_support::encode_path(list_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CollectionOfMergeFields = self.client.get(&url, None).await?;
+            Ok(resp.merge_fields)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add merge field.
      *
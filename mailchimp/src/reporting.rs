@@ -62,6 +62,91 @@ impl Reporting {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List facebook ads reports.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads` endpoint.
+     *
+     * As opposed to `get_facebook_ads`, this function returns all the pages of the request at once.
+     *
+     * Get reports of Facebook ads.
+     */
+    pub async fn get_all_facebook_ads(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<Vec<crate::types::GetReportingFacebookAdsResponseAllOf>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/reporting/facebook-ads?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetReportingFacebookAdsResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.facebook_ads)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List facebook ads reports.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads` endpoint.
+     *
+     * As opposed to `get_facebook_ads`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get reports of Facebook ads.
+     */
+    pub fn get_all_facebook_ads_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        sort_field: crate::types::GetAllFacebookAdsSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> impl futures::Stream<Item = Result<crate::types::GetReportingFacebookAdsResponseAllOf>> + '_
+    {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/reporting/facebook-ads?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetReportingFacebookAdsResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.facebook_ads)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Get facebook ad report.
      *
@@ -149,6 +234,92 @@ impl Reporting {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List facebook ecommerce report.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads/{outreach_id}/ecommerce-product-activity` endpoint.
+     *
+     * As opposed to `get_facebook_ads_ecommerce_product_activity`, this function returns all the pages of the request at once.
+     *
+     * Get breakdown of product activity for an outreach.
+     */
+    pub async fn get_all_facebook_ads_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        outreach_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Result<Vec<crate::types::Products>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reporting/facebook-ads/{}/ecommerce-product-activity?{}",
+            crate::progenitor_support::encode_path(outreach_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetReportsEcommerceProductActivityResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.products)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List facebook ecommerce report.
+     *
+     * This function performs a `GET` to the `/reporting/facebook-ads/{outreach_id}/ecommerce-product-activity` endpoint.
+     *
+     * As opposed to `get_facebook_ads_ecommerce_product_activity`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get breakdown of product activity for an outreach.
+     */
+    pub fn get_all_facebook_ads_ecommerce_product_activity_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        outreach_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> impl futures::Stream<Item = Result<crate::types::Products>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reporting/facebook-ads/{}/ecommerce-product-activity?{}",
+            crate::progenitor_support::encode_path(outreach_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetReportsEcommerceProductActivityResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.products)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Get landing page report.
      *
@@ -224,4 +395,72 @@ impl Reporting {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * List landing pages reports.
+     *
+     * This function performs a `GET` to the `/reporting/landing-pages` endpoint.
+     *
+     * As opposed to `get_landing_pages`, this function returns all the pages of the request at once.
+     *
+     * Get reports of landing pages.
+     */
+    pub async fn get_all_landing_pages(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> Result<Vec<crate::types::LandingPages>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/reporting/landing-pages?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetReportingLandingPagesResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.landing_pages)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List landing pages reports.
+     *
+     * This function performs a `GET` to the `/reporting/landing-pages` endpoint.
+     *
+     * As opposed to `get_landing_pages`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get reports of landing pages.
+     */
+    pub fn get_all_landing_pages_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+    ) -> impl futures::Stream<Item = Result<crate::types::LandingPages>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/reporting/landing-pages?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetReportingLandingPagesResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.landing_pages)
+        });
+
+        // Return our response data.
+        resp
+    }
 }
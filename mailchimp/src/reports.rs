@@ -67,6 +67,96 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List campaign reports.
+     *
+     * This function performs a `GET` to the `/reports` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * Get campaign reports.
+     */
+    pub async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: crate::types::CampaignType,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> Result<Vec<crate::types::Reports>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_send_time {
+            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if let Some(date) = since_send_time {
+            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/reports?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CampaignReportsData = self.client.get(&url, None).await?;
+            Ok(resp.reports)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List campaign reports.
+     *
+     * This function performs a `GET` to the `/reports` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get campaign reports.
+     */
+    pub fn get_all_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        type_: crate::types::CampaignType,
+        before_send_time: Option<chrono::DateTime<chrono::Utc>>,
+        since_send_time: Option<chrono::DateTime<chrono::Utc>>,
+    ) -> impl futures::Stream<Item = Result<crate::types::Reports>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if let Some(date) = before_send_time {
+            query_args.push(("before_send_time".to_string(), date.to_rfc3339()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if let Some(date) = since_send_time {
+            query_args.push(("since_send_time".to_string(), date.to_rfc3339()));
+        }
+        if !type_.to_string().is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/reports?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::CampaignReportsData = self.client.get(&url, None).await?;
+            Ok(resp.reports)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Get campaign report.
      *
@@ -261,26 +351,20 @@ impl Reports {
     }
 
     /**
-     * Get campaign link details.
-     *
-     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}` endpoint.
+     * List campaign details.
      *
-     * Get click details for a specific link in a campaign.
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_click_detail`, this function returns all the pages of the request at once.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `link_id: &str` -- The name of the folder.
+     * Get information about clicks on specific links in your Mailchimp campaigns.
      */
-    pub async fn get_click_detail_reports(
+    pub async fn get_all_click_details(
         &self,
         fields: &[String],
         exclude_fields: &[String],
         campaign_id: &str,
-        link_id: &str,
-    ) -> Result<crate::types::UrlsClicked> {
+    ) -> Result<Vec<crate::types::UrlsClicked>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -290,70 +374,64 @@ impl Reports {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/reports/{}/click-details/{}?{}",
+            "/reports/{}/click-details?{}",
             crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(link_id),
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ClickDetailReport = self.client.get(&url, None).await?;
+            Ok(resp.urls_clicked)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
     }
 
     /**
-     * List clicked link subscribers.
-     *
-     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members` endpoint.
+     * List campaign details.
      *
-     * Get information about list members who clicked on a specific link in a campaign.
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_click_detail`, this function returns a stream of all the items, requesting the pages as they are needed.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
-     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
-     * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `link_id: &str` -- The name of the folder.
+     * Get information about clicks on specific links in your Mailchimp campaigns.
      */
-    pub async fn get_click_details_member(
+    pub fn get_all_click_details_stream(
         &self,
         fields: &[String],
         exclude_fields: &[String],
-        count: i64,
-        offset: i64,
         campaign_id: &str,
-        link_id: &str,
-    ) -> Result<crate::types::ClickDetailMembers> {
+    ) -> impl futures::Stream<Item = Result<crate::types::UrlsClicked>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
-        if count > 0 {
-            query_args.push(("count".to_string(), count.to_string()));
-        }
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
         }
         if !fields.is_empty() {
             query_args.push(("fields".to_string(), fields.join(" ")));
         }
-        if offset > 0 {
-            query_args.push(("offset".to_string(), offset.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/reports/{}/click-details/{}/members?{}",
+            "/reports/{}/click-details?{}",
             crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(link_id),
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ClickDetailReport = self.client.get(&url, None).await?;
+            Ok(resp.urls_clicked)
+        });
+
+        // Return our response data.
+        resp
     }
 
     /**
-     * Get clicked link subscriber.
+     * Get campaign link details.
      *
-     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members/{subscriber_hash}` endpoint.
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}` endpoint.
      *
-     * Get information about a specific subscriber who clicked a link in a specific campaign.
+     * Get click details for a specific link in a campaign.
      *
      * **Parameters:**
      *
@@ -361,16 +439,14 @@ impl Reports {
      * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
      * * `link_id: &str` -- The name of the folder.
-     * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
      */
-    pub async fn get_click_details_member_reports(
+    pub async fn get_click_detail_reports(
         &self,
         fields: &[String],
         exclude_fields: &[String],
         campaign_id: &str,
         link_id: &str,
-        subscriber_hash: &str,
-    ) -> Result<crate::types::ClickDetailMember> {
+    ) -> Result<crate::types::UrlsClicked> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -380,10 +456,9 @@ impl Reports {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/reports/{}/click-details/{}/members/{}?{}",
+            "/reports/{}/click-details/{}?{}",
             crate::progenitor_support::encode_path(campaign_id),
             crate::progenitor_support::encode_path(link_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
             query_
         );
 
@@ -391,11 +466,11 @@ impl Reports {
     }
 
     /**
-     * List campaign open details.
+     * List clicked link subscribers.
      *
-     * This function performs a `GET` to the `/reports/{campaign_id}/open-details` endpoint.
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members` endpoint.
      *
-     * Get detailed information about any campaign emails that were opened by a list member.
+     * Get information about list members who clicked on a specific link in a campaign.
      *
      * **Parameters:**
      *
@@ -404,17 +479,17 @@ impl Reports {
      * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
      * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `since: &str` -- Restrict results to campaign open events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `link_id: &str` -- The name of the folder.
      */
-    pub async fn get_open_detail(
+    pub async fn get_click_details_member(
         &self,
         fields: &[String],
         exclude_fields: &[String],
         count: i64,
         offset: i64,
         campaign_id: &str,
-        since: &str,
-    ) -> Result<crate::types::OpenDetailReport> {
+        link_id: &str,
+    ) -> Result<crate::types::ClickDetailMembers> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -428,13 +503,11 @@ impl Reports {
         if offset > 0 {
             query_args.push(("offset".to_string(), offset.to_string()));
         }
-        if !since.is_empty() {
-            query_args.push(("since".to_string(), since.to_string()));
-        }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/reports/{}/open-details?{}",
+            "/reports/{}/click-details/{}/members?{}",
             crate::progenitor_support::encode_path(campaign_id),
+            crate::progenitor_support::encode_path(link_id),
             query_
         );
 
@@ -442,26 +515,21 @@ impl Reports {
     }
 
     /**
-     * Get opened campaign subscriber.
-     *
-     * This function performs a `GET` to the `/reports/{campaign_id}/open-details/{subscriber_hash}` endpoint.
+     * List clicked link subscribers.
      *
-     * Get information about a specific subscriber who opened a campaign.
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_click_details_member`, this function returns all the pages of the request at once.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
+     * Get information about list members who clicked on a specific link in a campaign.
      */
-    pub async fn get_open_details_member(
+    pub async fn get_all_click_details_members(
         &self,
         fields: &[String],
         exclude_fields: &[String],
         campaign_id: &str,
-        subscriber_hash: &str,
-    ) -> Result<crate::types::OpenActivity> {
+        link_id: &str,
+    ) -> Result<Vec<crate::types::ClickDetailMember>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -471,34 +539,37 @@ impl Reports {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/reports/{}/open-details/{}?{}",
+            "/reports/{}/click-details/{}/members?{}",
             crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
+            crate::progenitor_support::encode_path(link_id),
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ClickDetailMembers = self.client.get(&url, None).await?;
+            Ok(resp.members)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
     }
 
     /**
-     * List domain performance stats.
-     *
-     * This function performs a `GET` to the `/reports/{campaign_id}/domain-performance` endpoint.
+     * List clicked link subscribers.
      *
-     * Get statistics for the top-performing email domains in a campaign.
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_click_details_member`, this function returns a stream of all the items, requesting the pages as they are needed.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `campaign_id: &str` -- The unique id for the campaign.
+     * Get information about list members who clicked on a specific link in a campaign.
      */
-    pub async fn get_domain_performance(
+    pub fn get_all_click_details_members_stream(
         &self,
         fields: &[String],
         exclude_fields: &[String],
         campaign_id: &str,
-    ) -> Result<crate::types::DomainPerformance> {
+        link_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::ClickDetailMember>> + '_ {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -508,33 +579,44 @@ impl Reports {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/reports/{}/domain-performance?{}",
+            "/reports/{}/click-details/{}/members?{}",
             crate::progenitor_support::encode_path(campaign_id),
+            crate::progenitor_support::encode_path(link_id),
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::ClickDetailMembers = self.client.get(&url, None).await?;
+            Ok(resp.members)
+        });
+
+        // Return our response data.
+        resp
     }
 
     /**
-     * List EepURL activity.
+     * Get clicked link subscriber.
      *
-     * This function performs a `GET` to the `/reports/{campaign_id}/eepurl` endpoint.
+     * This function performs a `GET` to the `/reports/{campaign_id}/click-details/{link_id}/members/{subscriber_hash}` endpoint.
      *
-     * Get a summary of social activity for the campaign, tracked by EepURL.
+     * Get information about a specific subscriber who clicked a link in a specific campaign.
      *
      * **Parameters:**
      *
      * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
      * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `link_id: &str` -- The name of the folder.
+     * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
      */
-    pub async fn get_eepurl(
+    pub async fn get_click_details_member_reports(
         &self,
         fields: &[String],
         exclude_fields: &[String],
         campaign_id: &str,
-    ) -> Result<crate::types::EepurlActivity> {
+        link_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::ClickDetailMember> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -544,8 +626,10 @@ impl Reports {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/reports/{}/eepurl?{}",
+            "/reports/{}/click-details/{}/members/{}?{}",
             crate::progenitor_support::encode_path(campaign_id),
+            crate::progenitor_support::encode_path(link_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
             query_
         );
 
@@ -553,11 +637,11 @@ impl Reports {
     }
 
     /**
-     * List email activity.
+     * List campaign open details.
      *
-     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity` endpoint.
+     * This function performs a `GET` to the `/reports/{campaign_id}/open-details` endpoint.
      *
-     * Get a list of member's subscriber activity in a specific campaign.
+     * Get detailed information about any campaign emails that were opened by a list member.
      *
      * **Parameters:**
      *
@@ -566,9 +650,9 @@ impl Reports {
      * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
      * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
      * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `since: &str` -- Restrict results to email activity events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * * `since: &str` -- Restrict results to campaign open events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
      */
-    pub async fn get_email_activity(
+    pub async fn get_open_detail(
         &self,
         fields: &[String],
         exclude_fields: &[String],
@@ -576,7 +660,7 @@ impl Reports {
         offset: i64,
         campaign_id: &str,
         since: &str,
-    ) -> Result<crate::types::EmailActivityData> {
+    ) -> Result<crate::types::OpenDetailReport> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if count > 0 {
             query_args.push(("count".to_string(), count.to_string()));
@@ -595,7 +679,7 @@ impl Reports {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/reports/{}/email-activity?{}",
+            "/reports/{}/open-details?{}",
             crate::progenitor_support::encode_path(campaign_id),
             query_
         );
@@ -604,28 +688,21 @@ impl Reports {
     }
 
     /**
-     * Get subscriber email activity.
-     *
-     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity/{subscriber_hash}` endpoint.
+     * List campaign open details.
      *
-     * Get a specific list member's activity in a campaign including opens, clicks, and bounces.
+     * This function performs a `GET` to the `/reports/{campaign_id}/open-details` endpoint.
      *
-     * **Parameters:**
+     * As opposed to `get_open_detail`, this function returns all the pages of the request at once.
      *
-     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
-     * * `campaign_id: &str` -- The unique id for the campaign.
-     * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
-     * * `since: &str` -- Restrict results to email activity events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     * Get detailed information about any campaign emails that were opened by a list member.
      */
-    pub async fn get_email_activity_reports(
+    pub async fn get_all_open_details(
         &self,
         fields: &[String],
         exclude_fields: &[String],
         campaign_id: &str,
-        subscriber_hash: &str,
         since: &str,
-    ) -> Result<crate::types::EmailActivity> {
+    ) -> Result<Vec<crate::types::OpenActivity>> {
         let mut query_args: Vec<(String, String)> = Default::default();
         if !exclude_fields.is_empty() {
             query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
@@ -638,21 +715,358 @@ impl Reports {
         }
         let query_ = serde_urlencoded::to_string(&query_args).unwrap();
         let url = format!(
-            "/reports/{}/email-activity/{}?{}",
+            "/reports/{}/open-details?{}",
             crate::progenitor_support::encode_path(campaign_id),
-            crate::progenitor_support::encode_path(subscriber_hash),
             query_
         );
 
-        self.client.get(&url, None).await
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OpenDetailReport = self.client.get(&url, None).await?;
+            Ok(resp.members)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
     }
 
     /**
-     * List top open activities.
+     * List campaign open details.
      *
-     * This function performs a `GET` to the `/reports/{campaign_id}/locations` endpoint.
+     * This function performs a `GET` to the `/reports/{campaign_id}/open-details` endpoint.
      *
-     * Get top open locations for a specific campaign.
+     * As opposed to `get_open_detail`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get detailed information about any campaign emails that were opened by a list member.
+     */
+    pub fn get_all_open_details_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        since: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::OpenActivity>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !since.is_empty() {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/open-details?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OpenDetailReport = self.client.get(&url, None).await?;
+            Ok(resp.members)
+        });
+
+        // Return our response data.
+        resp
+    }
+
+    /**
+     * Get opened campaign subscriber.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/open-details/{subscriber_hash}` endpoint.
+     *
+     * Get information about a specific subscriber who opened a campaign.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
+     */
+    pub async fn get_open_details_member(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+    ) -> Result<crate::types::OpenActivity> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/open-details/{}?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List domain performance stats.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/domain-performance` endpoint.
+     *
+     * Get statistics for the top-performing email domains in a campaign.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `campaign_id: &str` -- The unique id for the campaign.
+     */
+    pub async fn get_domain_performance(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::DomainPerformance> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/domain-performance?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List EepURL activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/eepurl` endpoint.
+     *
+     * Get a summary of social activity for the campaign, tracked by EepURL.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `campaign_id: &str` -- The unique id for the campaign.
+     */
+    pub async fn get_eepurl(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<crate::types::EepurlActivity> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/eepurl?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List email activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity` endpoint.
+     *
+     * Get a list of member's subscriber activity in a specific campaign.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `count: i64` -- The number of records to return. Default value is 10. Maximum value is 1000.
+     * * `offset: i64` -- Used for [pagination](https://mailchimp.com/developer/marketing/docs/methods-parameters/#pagination), this it the number of records from a collection to skip. Default value is 0.
+     * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `since: &str` -- Restrict results to email activity events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     */
+    pub async fn get_email_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        count: i64,
+        offset: i64,
+        campaign_id: &str,
+        since: &str,
+    ) -> Result<crate::types::EmailActivityData> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if offset > 0 {
+            query_args.push(("offset".to_string(), offset.to_string()));
+        }
+        if !since.is_empty() {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/email-activity?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List email activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity` endpoint.
+     *
+     * As opposed to `get_email_activity`, this function returns all the pages of the request at once.
+     *
+     * Get a list of member's subscriber activity in a specific campaign.
+     */
+    pub async fn get_all_email_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        since: &str,
+    ) -> Result<Vec<crate::types::EmailActivity>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !since.is_empty() {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/email-activity?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::EmailActivityData = self.client.get(&url, None).await?;
+            Ok(resp.emails)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List email activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity` endpoint.
+     *
+     * As opposed to `get_email_activity`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get a list of member's subscriber activity in a specific campaign.
+     */
+    pub fn get_all_email_activity_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        since: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::EmailActivity>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !since.is_empty() {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/email-activity?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::EmailActivityData = self.client.get(&url, None).await?;
+            Ok(resp.emails)
+        });
+
+        // Return our response data.
+        resp
+    }
+
+    /**
+     * Get subscriber email activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/email-activity/{subscriber_hash}` endpoint.
+     *
+     * Get a specific list member's activity in a campaign including opens, clicks, and bounces.
+     *
+     * **Parameters:**
+     *
+     * * `fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `exclude_fields: &[String]` -- A comma-separated list of fields to return. Reference parameters of sub-objects with dot notation.
+     * * `campaign_id: &str` -- The unique id for the campaign.
+     * * `subscriber_hash: &str` -- The MD5 hash of the lowercase version of the list member's email address.
+     * * `since: &str` -- Restrict results to email activity events that occur after a specific time. Uses ISO 8601 time format: 2015-10-21T15:41:36+00:00.
+     */
+    pub async fn get_email_activity_reports(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        subscriber_hash: &str,
+        since: &str,
+    ) -> Result<crate::types::EmailActivity> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !since.is_empty() {
+            query_args.push(("since".to_string(), since.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/email-activity/{}?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            crate::progenitor_support::encode_path(subscriber_hash),
+            query_
+        );
+
+        self.client.get(&url, None).await
+    }
+
+    /**
+     * List top open activities.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/locations` endpoint.
+     *
+     * Get top open locations for a specific campaign.
      *
      * **Parameters:**
      *
@@ -693,6 +1107,82 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List top open activities.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/locations` endpoint.
+     *
+     * As opposed to `get_location`, this function returns all the pages of the request at once.
+     *
+     * Get top open locations for a specific campaign.
+     */
+    pub async fn get_all_locations(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<Vec<crate::types::OpenLocations>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/locations?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OpenLocationsData = self.client.get(&url, None).await?;
+            Ok(resp.locations)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List top open activities.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/locations` endpoint.
+     *
+     * As opposed to `get_location`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get top open locations for a specific campaign.
+     */
+    pub fn get_all_locations_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::OpenLocations>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/locations?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::OpenLocationsData = self.client.get(&url, None).await?;
+            Ok(resp.locations)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * List campaign recipients.
      *
@@ -739,6 +1229,82 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List campaign recipients.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/sent-to` endpoint.
+     *
+     * As opposed to `get_sent`, this function returns all the pages of the request at once.
+     *
+     * Get information about campaign recipients.
+     */
+    pub async fn get_all_sent(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<Vec<crate::types::SentTo>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/sent-to?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::SentData = self.client.get(&url, None).await?;
+            Ok(resp.sent_to)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List campaign recipients.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/sent-to` endpoint.
+     *
+     * As opposed to `get_sent`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about campaign recipients.
+     */
+    pub fn get_all_sent_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::SentTo>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/sent-to?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::SentData = self.client.get(&url, None).await?;
+            Ok(resp.sent_to)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Get campaign recipient info.
      *
@@ -860,6 +1426,82 @@ impl Reports {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List unsubscribed members.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/unsubscribed` endpoint.
+     *
+     * As opposed to `get_unsubscribed`, this function returns all the pages of the request at once.
+     *
+     * Get information about members who have unsubscribed from a specific campaign.
+     */
+    pub async fn get_all_unsubscribed(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> Result<Vec<crate::types::Unsubscribes>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/unsubscribed?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::UnsubscribesData = self.client.get(&url, None).await?;
+            Ok(resp.unsubscribes)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List unsubscribed members.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/unsubscribed` endpoint.
+     *
+     * As opposed to `get_unsubscribed`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get information about members who have unsubscribed from a specific campaign.
+     */
+    pub fn get_all_unsubscribed_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::Unsubscribes>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/unsubscribed?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::UnsubscribesData = self.client.get(&url, None).await?;
+            Ok(resp.unsubscribes)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Get unsubscribed member.
      *
@@ -949,4 +1591,90 @@ impl Reports {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * List campaign product activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/ecommerce-product-activity` endpoint.
+     *
+     * As opposed to `get_ecommerce_product_activity`, this function returns all the pages of the request at once.
+     *
+     * Get breakdown of product activity for a campaign
+     */
+    pub async fn get_all_ecommerce_product_activity(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> Result<Vec<crate::types::Products>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/ecommerce-product-activity?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetReportsEcommerceProductActivityResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.products)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List campaign product activity.
+     *
+     * This function performs a `GET` to the `/reports/{campaign_id}/ecommerce-product-activity` endpoint.
+     *
+     * As opposed to `get_ecommerce_product_activity`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get breakdown of product activity for a campaign
+     */
+    pub fn get_all_ecommerce_product_activity_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        campaign_id: &str,
+        sort_field: crate::types::GetReportsEcommerceProductActivitySortField,
+    ) -> impl futures::Stream<Item = Result<crate::types::Products>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!(
+            "/reports/{}/ecommerce-product-activity?{}",
+            crate::progenitor_support::encode_path(campaign_id),
+            query_
+        );
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::GetReportsEcommerceProductActivityResponse =
+                self.client.get(&url, None).await?;
+            Ok(resp.products)
+        });
+
+        // Return our response data.
+        resp
+    }
 }
@@ -98,6 +98,148 @@ impl Templates {
         self.client.get(&url, None).await
     }
 
+    /**
+     * List templates.
+     *
+     * This function performs a `GET` to the `/templates` endpoint.
+     *
+     * As opposed to `get`, this function returns all the pages of the request at once.
+     *
+     * Get a list of an account's available templates.
+     */
+    pub async fn get_all(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        created_by: &str,
+        since_date_created: &str,
+        before_date_created: &str,
+        type_: &str,
+        category: &str,
+        folder_id: &str,
+        sort_field: crate::types::GetTemplatesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> Result<Vec<crate::types::Templates>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_date_created.is_empty() {
+            query_args.push((
+                "before_date_created".to_string(),
+                before_date_created.to_string(),
+            ));
+        }
+        if !category.is_empty() {
+            query_args.push(("category".to_string(), category.to_string()));
+        }
+        if !created_by.is_empty() {
+            query_args.push(("created_by".to_string(), created_by.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+        }
+        if !since_date_created.is_empty() {
+            query_args.push((
+                "since_date_created".to_string(),
+                since_date_created.to_string(),
+            ));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/templates?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::TemplatesData = self.client.get(&url, None).await?;
+            Ok(resp.templates)
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * List templates.
+     *
+     * This function performs a `GET` to the `/templates` endpoint.
+     *
+     * As opposed to `get`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get a list of an account's available templates.
+     */
+    pub fn get_all_stream(
+        &self,
+        fields: &[String],
+        exclude_fields: &[String],
+        created_by: &str,
+        since_date_created: &str,
+        before_date_created: &str,
+        type_: &str,
+        category: &str,
+        folder_id: &str,
+        sort_field: crate::types::GetTemplatesSortField,
+        sort_dir: crate::types::SortDir,
+    ) -> impl futures::Stream<Item = Result<crate::types::Templates>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !before_date_created.is_empty() {
+            query_args.push((
+                "before_date_created".to_string(),
+                before_date_created.to_string(),
+            ));
+        }
+        if !category.is_empty() {
+            query_args.push(("category".to_string(), category.to_string()));
+        }
+        if !created_by.is_empty() {
+            query_args.push(("created_by".to_string(), created_by.to_string()));
+        }
+        if !exclude_fields.is_empty() {
+            query_args.push(("exclude_fields".to_string(), exclude_fields.join(" ")));
+        }
+        if !fields.is_empty() {
+            query_args.push(("fields".to_string(), fields.join(" ")));
+        }
+        if !folder_id.is_empty() {
+            query_args.push(("folder_id".to_string(), folder_id.to_string()));
+        }
+        if !since_date_created.is_empty() {
+            query_args.push((
+                "since_date_created".to_string(),
+                since_date_created.to_string(),
+            ));
+        }
+        if !sort_dir.to_string().is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !sort_field.to_string().is_empty() {
+            query_args.push(("sort_field".to_string(), sort_field.to_string()));
+        }
+        if !type_.is_empty() {
+            query_args.push(("type".to_string(), type_.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/templates?{}", query_);
+
+        let resp = crate::utils::offset_pages(&url, move |url| async move {
+            let resp: crate::types::TemplatesData = self.client.get(&url, None).await?;
+            Ok(resp.templates)
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * Add template.
      *
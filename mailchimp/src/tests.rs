@@ -1 +1,227 @@
+use futures::TryStreamExt;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
 
+use crate::traits::BatchOps;
+
+async fn offset_pages(url: &str, page_sizes: &[i64]) -> (Vec<i64>, Vec<String>) {
+    let requested = std::sync::Mutex::new(Vec::new());
+    let items = crate::utils::offset_pages(url, |url| {
+        let mut requested = requested.lock().unwrap();
+        requested.push(url);
+        let page = page_sizes
+            .get(requested.len() - 1)
+            .copied()
+            .unwrap_or_default();
+        let start = requested.len() as i64 * 10_000;
+        async move { Ok((start..start + page).collect()) }
+    })
+    .try_collect()
+    .await
+    .unwrap();
+
+    (items, requested.into_inner().unwrap())
+}
+
+#[tokio::test]
+async fn test_offset_pages_stops_on_short_page() {
+    let max = crate::utils::MAX_COUNT;
+    let (items, requested) = offset_pages("/lists?fields=lists.id", &[max, 2, max]).await;
+
+    assert_eq!(items.len() as i64, max + 2);
+    assert_eq!(items[max as usize..], [20_000, 20_001]);
+    assert_eq!(
+        requested,
+        vec![
+            "/lists?fields=lists.id&count=1000&offset=0",
+            "/lists?fields=lists.id&count=1000&offset=1000",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_offset_pages_stops_on_empty_page() {
+    let (items, requested) = offset_pages("/lists?", &[crate::utils::MAX_COUNT]).await;
+
+    assert_eq!(items.len() as i64, crate::utils::MAX_COUNT);
+    assert_eq!(
+        requested,
+        vec![
+            "/lists?count=1000&offset=0",
+            "/lists?count=1000&offset=1000"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_offset_pages_without_query() {
+    let (items, requested) = offset_pages("/activity-feed/chimp-chatter", &[]).await;
+
+    assert!(items.is_empty());
+    assert_eq!(
+        requested,
+        vec!["/activity-feed/chimp-chatter?count=1000&offset=0"]
+    );
+}
+
+fn batch_results_archive(files: &[(&str, &str)]) -> Vec<u8> {
+    let mut archive = tar::Builder::new(flate2::write::GzEncoder::new(
+        Vec::new(),
+        flate2::Compression::default(),
+    ));
+    for (path, body) in files {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(body.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        archive
+            .append_data(&mut header, path, body.as_bytes())
+            .unwrap();
+    }
+
+    archive.into_inner().unwrap().finish().unwrap()
+}
+
+#[tokio::test]
+async fn test_batches_run() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/batches"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"id": "b1", "status": "pending"})),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/batches/b1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({"id": "b1", "status": "started"})),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/batches/b1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "b1",
+            "status": "finished",
+            "response_body_url": format!("{}/results.tar.gz", server.uri()),
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/results.tar.gz"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(batch_results_archive(&[
+            (
+                "a1b2c3/1.json",
+                r#"[{"status_code":200,"operation_id":"list","response":"{\"id\":\"l1\",\"name\":\"Friends\"}"}]"#,
+            ),
+            (
+                "a1b2c3/2.json",
+                r#"[{"status_code":404,"operation_id":"missing","response":"{\"title\":\"Resource Not Found\"}"}]"#,
+            ),
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("", "", "", "token", "").with_host(server.uri());
+    let results = client
+        .batches()
+        .run_with_backoff(
+            vec![crate::types::Operations {
+                body: "".to_string(),
+                method: crate::types::HttpMethod::Get,
+                operation_id: "list".to_string(),
+                params: None,
+                path: "/lists/l1".to_string(),
+            }],
+            &crate::traits::BatchBackoff {
+                initial_interval: std::time::Duration::from_millis(10),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].operation_id, "list");
+    assert!(results[0].is_success());
+    let list: crate::types::Lists = results[0].parse().unwrap();
+    assert_eq!(list.name, "Friends");
+    assert_eq!(results[1].status_code, 404);
+    assert!(results[1].parse::<crate::types::Lists>().is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_batches_wait_retries_polls() {
+    let server = MockServer::start().await;
+    // The client retries the request 3 times itself, so this fails the
+    // first poll.
+    Mock::given(method("GET"))
+        .and(path("/batches/b1"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(4)
+        .expect(4)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/batches/b1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "id": "b1",
+            "status": "finished",
+            "response_body_url": format!("{}/results.tar.gz", server.uri()),
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/results.tar.gz"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_bytes(batch_results_archive(&[(
+                "b1/1.json",
+                r#"[{"status_code":200,"operation_id":"list","response":"{}"}]"#,
+            )])),
+        )
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("", "", "", "token", "").with_host(server.uri());
+    let results = client
+        .batches()
+        .wait("b1", &Default::default())
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].operation_id, "list");
+}
+
+#[tokio::test]
+async fn test_batches_wait_fails_on_client_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/batches/b1"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_string(r#"{"title":"Resource Not Found"}"#),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("", "", "", "token", "").with_host(server.uri());
+    let err = client
+        .batches()
+        .wait("b1", &Default::default())
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().starts_with("code: 404"), "{}", err);
+}
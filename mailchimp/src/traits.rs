@@ -0,0 +1,261 @@
+use std::{io::Read, time::Duration};
+
+use anyhow::{anyhow, bail, Result};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[async_trait::async_trait]
+pub trait BatchOps {
+    /// Submit a batch of operations, wait for it to finish and return the
+    /// result of every operation.
+    ///
+    /// The status of the batch is polled with the default `BatchBackoff`.
+    async fn run(&self, operations: Vec<crate::types::Operations>) -> Result<Vec<BatchResult>>;
+
+    /// Submit a batch of operations, wait for it to finish and return the
+    /// result of every operation, polling the status of the batch with `backoff`.
+    async fn run_with_backoff(
+        &self,
+        operations: Vec<crate::types::Operations>,
+        backoff: &BatchBackoff,
+    ) -> Result<Vec<BatchResult>>;
+
+    /// Wait for a batch that was already submitted to finish and return the
+    /// result of every operation.
+    async fn wait(&self, batch_id: &str, backoff: &BatchBackoff) -> Result<Vec<BatchResult>>;
+
+    /// Download and unpack the results of a finished batch.
+    async fn results(&self, batch: &crate::types::Batch) -> Result<Vec<BatchResult>>;
+}
+
+#[async_trait::async_trait]
+impl BatchOps for crate::batches::Batches {
+    /// Submit a batch of operations, wait for it to finish and return the
+    /// result of every operation.
+    ///
+    /// The status of the batch is polled with the default `BatchBackoff`.
+    async fn run(&self, operations: Vec<crate::types::Operations>) -> Result<Vec<BatchResult>> {
+        self.run_with_backoff(operations, &Default::default()).await
+    }
+
+    /// Submit a batch of operations, wait for it to finish and return the
+    /// result of every operation, polling the status of the batch with `backoff`.
+    async fn run_with_backoff(
+        &self,
+        operations: Vec<crate::types::Operations>,
+        backoff: &BatchBackoff,
+    ) -> Result<Vec<BatchResult>> {
+        let batch = self
+            .post(&crate::types::PostBatchesRequest { operations })
+            .await?;
+        if batch.id.is_empty() {
+            bail!("submitting the batch did not return an id");
+        }
+
+        self.wait(&batch.id, backoff).await
+    }
+
+    /// Wait for a batch that was already submitted to finish and return the
+    /// result of every operation.
+    ///
+    /// Polls that fail with a connection error, a `429` or a `5xx` are
+    /// retried until `max_elapsed`, any other error is returned right away.
+    async fn wait(&self, batch_id: &str, backoff: &BatchBackoff) -> Result<Vec<BatchResult>> {
+        let start = std::time::Instant::now();
+        let mut interval = backoff.initial_interval;
+
+        loop {
+            let last = match self.poll(batch_id).await? {
+                BatchPoll::Status(batch) => {
+                    if batch.status == Some(crate::types::BatchOperationsStatus::Finished) {
+                        return self.results(&batch).await;
+                    }
+
+                    format!("last status: {}", batch.status.unwrap_or_default())
+                }
+                BatchPoll::Retry(e) => {
+                    log::warn!("polling batch {} failed, retrying: {}", batch_id, e);
+                    format!("last error: {}", e)
+                }
+            };
+
+            if let Some(max_elapsed) = backoff.max_elapsed {
+                if start.elapsed() + interval > max_elapsed {
+                    bail!(
+                        "batch {} did not finish within {:?}, {}",
+                        batch_id,
+                        max_elapsed,
+                        last
+                    );
+                }
+            }
+
+            tokio::time::sleep(interval).await;
+            interval = std::cmp::min(interval * 2, backoff.max_interval);
+        }
+    }
+
+    /// Download and unpack the results of a finished batch.
+    async fn results(&self, batch: &crate::types::Batch) -> Result<Vec<BatchResult>> {
+        if batch.response_body_url.is_empty() {
+            bail!("batch {} does not have any results to download", batch.id);
+        }
+
+        // The results are stored outside of the API, so we must not send our
+        // credentials along.
+        let resp = self
+            .client
+            .client
+            .get(&batch.response_body_url)
+            .send()
+            .await?;
+        let status = resp.status();
+        if !status.is_success() {
+            bail!(
+                "code: {}, downloading the results of batch {} failed",
+                status,
+                batch.id
+            );
+        }
+
+        parse_results(&resp.bytes().await?)
+    }
+}
+
+/// The outcome of polling the status of a batch.
+enum BatchPoll {
+    Status(crate::types::Batch),
+    /// The status could not be read this time, but may be on the next poll.
+    Retry(anyhow::Error),
+}
+
+impl crate::batches::Batches {
+    async fn poll(&self, batch_id: &str) -> Result<BatchPoll> {
+        let url = format!(
+            "/batches/{}",
+            crate::progenitor_support::encode_path(batch_id)
+        );
+        let resp = match self
+            .client
+            .request_raw(reqwest::Method::GET, &url, None)
+            .await
+        {
+            Ok(resp) => resp,
+            // The request never got a response.
+            Err(e) if e.is::<reqwest_middleware::Error>() => return Ok(BatchPoll::Retry(e)),
+            Err(e) => return Err(e),
+        };
+
+        let status = resp.status();
+        if status.is_success() {
+            return Ok(BatchPoll::Status(resp.json().await?));
+        }
+
+        let error = anyhow!(
+            "code: {}, error: {:?}",
+            status,
+            resp.text().await.unwrap_or_default()
+        );
+        if status == http::StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+            Ok(BatchPoll::Retry(error))
+        } else {
+            Err(error)
+        }
+    }
+}
+
+/// How often to poll the status of a batch while waiting for it to finish.
+#[derive(Debug, Clone)]
+pub struct BatchBackoff {
+    /// How long to wait after the first poll.
+    pub initial_interval: Duration,
+    /// The interval doubles after every poll, up to this.
+    pub max_interval: Duration,
+    /// Give up if the batch has not finished after this long, an hour by
+    /// default. `None` waits until the batch finishes.
+    pub max_elapsed: Option<Duration>,
+}
+
+impl Default for BatchBackoff {
+    fn default() -> Self {
+        BatchBackoff {
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(60),
+            max_elapsed: Some(Duration::from_secs(60 * 60)),
+        }
+    }
+}
+
+/// The result of a single operation in a batch.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, JsonSchema)]
+pub struct BatchResult {
+    /// The HTTP status code returned for the operation.
+    #[serde(default)]
+    pub status_code: u16,
+    /// The `operation_id` given when the operation was submitted.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub operation_id: String,
+    /// The JSON body returned for the operation.
+    #[serde(
+        default,
+        skip_serializing_if = "String::is_empty",
+        deserialize_with = "crate::utils::deserialize_null_string::deserialize"
+    )]
+    pub response: String,
+}
+
+impl BatchResult {
+    /// Returns true if the operation succeeded.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status_code)
+    }
+
+    /// Parse the body returned for the operation.
+    ///
+    /// This returns an error with the body if the operation did not succeed.
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        if !self.is_success() {
+            return Err(anyhow!(
+                "code: {}, error: {:?}",
+                self.status_code,
+                self.response
+            ));
+        }
+
+        if self.response.is_empty() {
+            Ok(serde_json::from_str("null")?)
+        } else {
+            Ok(serde_json::from_str(&self.response)?)
+        }
+    }
+}
+
+/// Unpack a batch results archive: a gzipped tarball of JSON files, each of
+/// which holds an array of operation results.
+pub(crate) fn parse_results(archive: &[u8]) -> Result<Vec<BatchResult>> {
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(archive));
+
+    let mut results = Vec::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file()
+            || !entry.path()?.to_string_lossy().ends_with(".json")
+        {
+            continue;
+        }
+
+        let mut body = String::new();
+        entry.read_to_string(&mut body)?;
+        let mut r: Vec<BatchResult> = serde_json::from_str(&body)?;
+        results.append(&mut r);
+    }
+
+    Ok(results)
+}
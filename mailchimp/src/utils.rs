@@ -1,3 +1,60 @@
+/// The largest number of records MailChimp returns in a single page.
+pub const MAX_COUNT: i64 = 1000;
+
+/// Returns a stream of every record in a collection paginated with `count`
+/// and `offset`.
+///
+/// Pages of `MAX_COUNT` records are requested as the stream is polled, until
+/// a page comes back with fewer records. `total_items` is not used, as
+/// `fields` can leave it out of the pages. `get_page` is given the URL of each
+/// page and returns its records.
+pub fn offset_pages<'a, D, F, Fut>(
+    url: &str,
+    get_page: F,
+) -> impl futures::Stream<Item = anyhow::Result<D>> + 'a
+where
+    D: 'a,
+    F: Fn(String) -> Fut + 'a,
+    Fut: std::future::Future<Output = anyhow::Result<Vec<D>>> + 'a,
+{
+    use futures::TryStreamExt;
+
+    let url = if !url.contains('?') {
+        format!("{}?", url)
+    } else if url.ends_with('?') || url.ends_with('&') {
+        url.to_string()
+    } else {
+        format!("{}&", url)
+    };
+
+    futures::stream::try_unfold(Some(0), move |offset: Option<i64>| {
+        let page = offset.map(|offset| {
+            let page = get_page(format!("{}count={}&offset={}", url, MAX_COUNT, offset));
+            (offset, page)
+        });
+
+        async move {
+            let (offset, page) = match page {
+                Some(page) => page,
+                None => return Ok::<_, anyhow::Error>(None),
+            };
+
+            let items = page.await?;
+            let next = if (items.len() as i64) < MAX_COUNT {
+                None
+            } else {
+                Some(offset + items.len() as i64)
+            };
+
+            Ok(Some((
+                futures::stream::iter(items.into_iter().map(Ok::<D, anyhow::Error>)),
+                next,
+            )))
+        }
+    })
+    .try_flatten()
+}
+
 use std::{fmt, str::FromStr};
 
 use serde::de::{self, Visitor};
@@ -17,6 +74,70 @@ pub fn next_link(l: &hyperx::header::Link) -> Option<String> {
     })
 }
 
+/// Set the page size query parameter on a URL, unless it is already set.
+pub fn with_page_size(url: &str, param: &str, page_size: Option<u32>) -> String {
+    let page_size = match page_size {
+        Some(page_size) => page_size,
+        None => return url.to_string(),
+    };
+
+    match reqwest::Url::parse(url) {
+        Ok(mut u) => {
+            if !u.query_pairs().any(|(k, _)| k == param) {
+                u.query_pairs_mut()
+                    .append_pair(param, &page_size.to_string());
+            }
+            u.to_string()
+        }
+        Err(_) => url.to_string(),
+    }
+}
+
+/// Follow the `rel="next"` links starting at `url` until there are none left,
+/// a page comes back empty, or the limits in `pagination` are reached.
+pub async fn unfold<D, F, Fut>(
+    url: String,
+    pagination: &crate::Pagination,
+    mut get_page: F,
+) -> anyhow::Result<Vec<D>>
+where
+    F: FnMut(String) -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<(Option<hyperx::header::Link>, Vec<D>)>>,
+{
+    if pagination.max_items == Some(0) || pagination.max_pages == Some(0) {
+        return Ok(Vec::new());
+    }
+
+    let mut global_items = Vec::new();
+    let mut pages = 0;
+    let mut next = Some(url);
+    while let Some(url) = next.take() {
+        let (link, mut items) = get_page(url).await?;
+        pages += 1;
+        if items.is_empty() {
+            break;
+        }
+        global_items.append(&mut items);
+
+        if let Some(max_items) = pagination.max_items {
+            if global_items.len() >= max_items {
+                global_items.truncate(max_items);
+                break;
+            }
+        }
+        if let Some(max_pages) = pagination.max_pages {
+            if pages >= max_pages {
+                break;
+            }
+        }
+
+        // We need to get the next link.
+        next = link.as_ref().and_then(next_link);
+    }
+
+    Ok(global_items)
+}
+
 pub mod date_format {
     use chrono::NaiveDate;
     use serde::{self, Deserialize, Deserializer};
@@ -638,3 +759,6 @@ pub mod deserialize_null_vector {
         Ok(Default::default())
     }
 }
+
+/// The query parameter used to set the number of items per page.
+pub const PAGE_SIZE_PARAM: &str = "limit";
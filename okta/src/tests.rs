@@ -1 +1,244 @@
+use crate::traits::LogOps;
 
+fn log_events(uuids: &[&str]) -> Vec<crate::types::LogEvent> {
+    uuids
+        .iter()
+        .map(|uuid| {
+            serde_json::from_value(serde_json::json!({
+                "uuid": uuid,
+                "eventType": "user.session.start",
+            }))
+            .unwrap()
+        })
+        .collect()
+}
+
+fn next_link(url: &str) -> Option<hyperx::header::Link> {
+    Some(format!("<{}>; rel=\"next\"", url).parse().unwrap())
+}
+
+#[test]
+fn test_log_tail_cursor_moves_after_page() {
+    let mut tail = crate::Client::new("")
+        .with_host("https://example.okta.com")
+        .logs()
+        .tail(None, "");
+    assert_eq!(
+        tail.cursor().next,
+        "https://example.okta.com/api/v1/logs?sortOrder=ASCENDING"
+    );
+
+    tail.handle_page(
+        next_link("https://example.okta.com/api/v1/logs?after=2"),
+        log_events(&["1", "2"]),
+    );
+
+    assert_eq!(tail.pop_event().unwrap().uuid, "1");
+    // We are still in the middle of the page, so resuming must refetch it.
+    assert_eq!(
+        tail.cursor().next,
+        "https://example.okta.com/api/v1/logs?sortOrder=ASCENDING"
+    );
+    assert_eq!(tail.pop_event().unwrap().uuid, "2");
+    assert_eq!(
+        tail.cursor().next,
+        "https://example.okta.com/api/v1/logs?after=2"
+    );
+    assert!(tail.pop_event().is_none());
+}
+
+#[test]
+fn test_log_tail_deduplicates() {
+    let cursor = crate::traits::LogCursor {
+        next: "https://example.okta.com/api/v1/logs?after=1".to_string(),
+        seen: vec!["1".to_string()].into(),
+    };
+    let mut tail = crate::Client::new("").logs().resume(cursor);
+
+    // A resumed page replays an event we already yielded.
+    tail.handle_page(
+        next_link("https://example.okta.com/api/v1/logs?after=3"),
+        log_events(&["1", "2", "3"]),
+    );
+
+    assert_eq!(tail.pop_event().unwrap().uuid, "2");
+    assert_eq!(tail.pop_event().unwrap().uuid, "3");
+    assert!(tail.pop_event().is_none());
+    assert_eq!(
+        tail.cursor().seen,
+        vec!["1".to_string(), "2".to_string(), "3".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_log_tail_empty_page() {
+    let mut tail = crate::Client::new("").logs().tail(None, "");
+
+    tail.handle_page(
+        next_link("https://example.okta.com/api/v1/logs?after=1"),
+        vec![],
+    );
+
+    assert!(tail.pop_event().is_none());
+    assert_eq!(
+        tail.cursor().next,
+        "https://example.okta.com/api/v1/logs?after=1"
+    );
+}
+
+#[test]
+fn test_log_cursor_round_trip() {
+    let cursor = crate::traits::LogCursor {
+        next: "https://example.okta.com/api/v1/logs?after=1".to_string(),
+        seen: vec!["1".to_string()].into(),
+    };
+
+    let json = serde_json::to_string(&cursor).unwrap();
+    let parsed: crate::traits::LogCursor = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, cursor);
+}
+
+async fn unfold_pages(
+    pagination: crate::Pagination,
+    pages: &[(&str, Option<&str>, Vec<i64>)],
+) -> (Vec<i64>, Vec<String>) {
+    let requested = std::sync::Mutex::new(Vec::new());
+    let items = crate::utils::unfold(
+        "https://example.okta.com/api/v1/users".to_string(),
+        &pagination,
+        |url| {
+            requested.lock().unwrap().push(url.to_string());
+            let (_, next, items) = pages.iter().find(|(u, _, _)| *u == url).unwrap().clone();
+            async move { Ok((next.and_then(next_link), items)) }
+        },
+    )
+    .await
+    .unwrap();
+
+    (items, requested.into_inner().unwrap())
+}
+
+#[tokio::test]
+async fn test_unfold_stops_without_next_link() {
+    let (items, requested) = unfold_pages(
+        Default::default(),
+        &[
+            (
+                "https://example.okta.com/api/v1/users",
+                Some("https://example.okta.com/api/v1/users?after=2"),
+                vec![1, 2],
+            ),
+            (
+                "https://example.okta.com/api/v1/users?after=2",
+                None,
+                vec![3],
+            ),
+        ],
+    )
+    .await;
+
+    assert_eq!(items, vec![1, 2, 3]);
+    assert_eq!(requested.len(), 2);
+}
+
+#[tokio::test]
+async fn test_unfold_stops_on_empty_page() {
+    let (items, requested) = unfold_pages(
+        Default::default(),
+        &[
+            (
+                "https://example.okta.com/api/v1/users",
+                Some("https://example.okta.com/api/v1/users?after=1"),
+                vec![1],
+            ),
+            (
+                "https://example.okta.com/api/v1/users?after=1",
+                Some("https://example.okta.com/api/v1/users?after=1"),
+                vec![],
+            ),
+        ],
+    )
+    .await;
+
+    assert_eq!(items, vec![1]);
+    assert_eq!(requested.len(), 2);
+}
+
+#[tokio::test]
+async fn test_unfold_max_items_and_pages() {
+    let pages = [
+        (
+            "https://example.okta.com/api/v1/users",
+            Some("https://example.okta.com/api/v1/users?after=2"),
+            vec![1, 2],
+        ),
+        (
+            "https://example.okta.com/api/v1/users?after=2",
+            Some("https://example.okta.com/api/v1/users?after=4"),
+            vec![3, 4],
+        ),
+        (
+            "https://example.okta.com/api/v1/users?after=4",
+            None,
+            vec![5],
+        ),
+    ];
+
+    let (items, requested) = unfold_pages(
+        crate::Pagination {
+            max_items: Some(3),
+            ..Default::default()
+        },
+        &pages,
+    )
+    .await;
+    assert_eq!(items, vec![1, 2, 3]);
+    assert_eq!(requested.len(), 2);
+
+    let (items, requested) = unfold_pages(
+        crate::Pagination {
+            max_pages: Some(1),
+            ..Default::default()
+        },
+        &pages,
+    )
+    .await;
+    assert_eq!(items, vec![1, 2]);
+    assert_eq!(requested.len(), 1);
+
+    // No page is requested for no items.
+    let (items, requested) = unfold_pages(
+        crate::Pagination {
+            max_items: Some(0),
+            ..Default::default()
+        },
+        &pages,
+    )
+    .await;
+    assert!(items.is_empty());
+    assert!(requested.is_empty());
+}
+
+#[test]
+fn test_with_page_size() {
+    assert_eq!(
+        crate::utils::with_page_size(
+            "https://example.okta.com/api/v1/users?q=eric",
+            crate::utils::PAGE_SIZE_PARAM,
+            Some(200)
+        ),
+        "https://example.okta.com/api/v1/users?q=eric&limit=200"
+    );
+    assert_eq!(
+        crate::utils::with_page_size(
+            "https://example.okta.com/api/v1/users?limit=10",
+            crate::utils::PAGE_SIZE_PARAM,
+            Some(200)
+        ),
+        "https://example.okta.com/api/v1/users?limit=10"
+    );
+    assert_eq!(
+        crate::utils::with_page_size("https://example.okta.com/api/v1/users?", "limit", None),
+        "https://example.okta.com/api/v1/users?"
+    );
+}
@@ -0,0 +1,221 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// How many event `uuid`s we remember to drop duplicates across polls.
+const SEEN_WINDOW: usize = 1000;
+
+pub trait LogOps {
+    /// Tail the System Log, starting at `since`.
+    ///
+    /// This uses the System Log polling mode: it follows the `next` links
+    /// returned by Okta and sleeps when a page is empty, so the stream never
+    /// ends on its own.
+    fn tail(&self, since: Option<chrono::DateTime<chrono::Utc>>, filter: &str) -> LogTail;
+
+    /// Resume tailing the System Log from a cursor previously returned by
+    /// `LogTail::cursor`.
+    fn resume(&self, cursor: LogCursor) -> LogTail;
+}
+
+impl LogOps for crate::logs::Logs {
+    /// Tail the System Log, starting at `since`.
+    ///
+    /// This uses the System Log polling mode: it follows the `next` links
+    /// returned by Okta and sleeps when a page is empty, so the stream never
+    /// ends on its own.
+    fn tail(&self, since: Option<chrono::DateTime<chrono::Utc>>, filter: &str) -> LogTail {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !filter.is_empty() {
+            query_args.push(("filter".to_string(), filter.to_string()));
+        }
+        if let Some(date) = since {
+            query_args.push(("since".to_string(), date.to_rfc3339()));
+        }
+        // Polling requires the events in ascending order.
+        query_args.push(("sortOrder".to_string(), "ASCENDING".to_string()));
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+
+        self.resume(LogCursor {
+            next: format!("{}/api/v1/logs?{}", self.client.host, query_),
+            seen: Default::default(),
+        })
+    }
+
+    /// Resume tailing the System Log from a cursor previously returned by
+    /// `LogTail::cursor`.
+    fn resume(&self, cursor: LogCursor) -> LogTail {
+        LogTail::new(self.client.clone(), cursor)
+    }
+}
+
+/// A position in the System Log that can be persisted and used to resume
+/// tailing after a restart.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct LogCursor {
+    /// The URL of the page to fetch next.
+    pub next: String,
+    /// The `uuid`s of the most recently yielded events.
+    #[serde(default)]
+    pub seen: VecDeque<String>,
+}
+
+type Page = Result<(Option<hyperx::header::Link>, Vec<crate::types::LogEvent>)>;
+
+/// A stream of System Log events, de-duplicated by `uuid`.
+///
+/// Between items, `cursor` returns the position to checkpoint: resuming from
+/// it never skips an event that has not been yielded yet.
+pub struct LogTail {
+    client: crate::Client,
+    cursor: LogCursor,
+    limit: i64,
+    poll_interval: Duration,
+
+    events: VecDeque<crate::types::LogEvent>,
+    next_page: Option<String>,
+    fetch: Option<Pin<Box<dyn Future<Output = Page> + Send>>>,
+    sleep: Option<Pin<Box<tokio::time::Sleep>>>,
+    done: bool,
+}
+
+impl LogTail {
+    fn new(client: crate::Client, cursor: LogCursor) -> Self {
+        LogTail {
+            client,
+            cursor,
+            limit: 0,
+            poll_interval: Duration::from_secs(10),
+            events: Default::default(),
+            next_page: None,
+            fetch: None,
+            sleep: None,
+            done: false,
+        }
+    }
+
+    /// Set how long to wait before polling again after an empty page.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set the number of events to request per page.
+    pub fn with_limit(mut self, limit: i64) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// The cursor to checkpoint.
+    pub fn cursor(&self) -> &LogCursor {
+        &self.cursor
+    }
+
+    fn page_url(&self) -> Result<reqwest::Url> {
+        let mut url = reqwest::Url::parse(&self.cursor.next)?;
+        if self.limit > 0 && !url.query_pairs().any(|(k, _)| k == "limit") {
+            url.query_pairs_mut()
+                .append_pair("limit", &self.limit.to_string());
+        }
+        Ok(url)
+    }
+
+    pub(crate) fn handle_page(
+        &mut self,
+        link: Option<hyperx::header::Link>,
+        events: Vec<crate::types::LogEvent>,
+    ) {
+        let next = link.as_ref().and_then(crate::utils::next_link);
+
+        if events.is_empty() {
+            // Nothing new yet, wait before polling again.
+            match next {
+                Some(next) => {
+                    self.cursor.next = next;
+                    self.sleep = Some(Box::pin(tokio::time::sleep(self.poll_interval)));
+                }
+                None => self.done = true,
+            }
+            return;
+        }
+
+        self.events = events
+            .into_iter()
+            .filter(|e| !self.cursor.seen.contains(&e.uuid))
+            .collect();
+        if self.events.is_empty() {
+            // We have already seen everything on this page, move on.
+            match next {
+                Some(next) => self.cursor.next = next,
+                None => self.done = true,
+            }
+        } else {
+            self.next_page = next;
+        }
+    }
+
+    pub(crate) fn pop_event(&mut self) -> Option<crate::types::LogEvent> {
+        let event = self.events.pop_front()?;
+
+        if !event.uuid.is_empty() {
+            if self.cursor.seen.len() >= SEEN_WINDOW {
+                self.cursor.seen.pop_front();
+            }
+            self.cursor.seen.push_back(event.uuid.to_string());
+        }
+
+        // Only move the cursor once the whole page has been yielded.
+        if self.events.is_empty() {
+            match self.next_page.take() {
+                Some(next) => self.cursor.next = next,
+                None => self.done = true,
+            }
+        }
+
+        Some(event)
+    }
+}
+
+impl futures::Stream for LogTail {
+    type Item = Result<crate::types::LogEvent>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(event) = self.pop_event() {
+                return Poll::Ready(Some(Ok(event)));
+            }
+
+            if let Some(sleep) = self.sleep.as_mut() {
+                futures::ready!(sleep.as_mut().poll(cx));
+                self.sleep = None;
+            }
+
+            if self.fetch.is_none() {
+                if self.done {
+                    return Poll::Ready(None);
+                }
+
+                let url = match self.page_url() {
+                    Ok(url) => url,
+                    Err(e) => return Poll::Ready(Some(Err(e))),
+                };
+                let client = self.client.clone();
+                self.fetch = Some(Box::pin(async move { client.get_pages_url(&url).await }));
+            }
+
+            let page = futures::ready!(self.fetch.as_mut().unwrap().as_mut().poll(cx));
+            self.fetch = None;
+            match page {
+                Ok((link, events)) => self.handle_page(link, events),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::BTreeMap;
+
+use anyhow::{anyhow, bail, Result};
+
+/// A mail received by Inbound Parse, from the `multipart/form-data` request
+/// SendGrid posts to the URL of the host.
+///
+/// With "POST the raw, full MIME message" enabled, SendGrid only posts the
+/// envelope and the checks of the mail along with the message, in `raw`. The
+/// headers, content and attachments are then parsed from `raw`, and are left
+/// empty if it is not a valid MIME message.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InboundEmail {
+    /// The raw headers of the mail.
+    pub headers: String,
+    pub from: String,
+    pub to: String,
+    pub cc: String,
+    pub subject: String,
+    /// The plain text content.
+    pub text: String,
+    /// The HTML content.
+    pub html: String,
+    /// Who the mail was actually sent from and to, by SMTP.
+    pub envelope: Envelope,
+    pub sender_ip: String,
+    /// The result of the DKIM checks of the mail.
+    pub dkim: String,
+    /// The result of the SPF check of the mail.
+    pub spf: String,
+    pub spam_score: Option<f64>,
+    pub spam_report: String,
+    /// The charset of each field, which the fields were decoded from.
+    pub charsets: BTreeMap<String, String>,
+    pub attachments: Vec<InboundAttachment>,
+    /// The full MIME message, only in raw mode.
+    pub raw: Option<bytes::Bytes>,
+    /// The fields that are not modeled.
+    pub fields: BTreeMap<String, String>,
+}
+
+/// The SMTP envelope of an `InboundEmail`.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Envelope {
+    #[serde(default)]
+    pub to: Vec<String>,
+    #[serde(default)]
+    pub from: String,
+}
+
+/// A file attached to an `InboundEmail`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InboundAttachment {
+    /// The form field of the attachment, like `attachment1`.
+    pub field: String,
+    pub filename: String,
+    pub content_type: String,
+    /// The content ID the HTML content refers to an inline attachment with.
+    pub content_id: String,
+    pub content: bytes::Bytes,
+}
+
+#[derive(serde::Deserialize, Default)]
+struct AttachmentInfo {
+    #[serde(default)]
+    filename: String,
+    #[serde(default, rename = "type")]
+    type_: String,
+    #[serde(default, rename = "content-id")]
+    content_id: String,
+}
+
+impl InboundEmail {
+    /// Parse the body of an Inbound Parse request, with the value of its
+    /// `Content-Type` header.
+    pub fn parse(content_type: &str, body: &[u8]) -> Result<Self> {
+        let boundary = match header_params(content_type)
+            .into_iter()
+            .find(|(name, _)| name == "boundary")
+        {
+            Some((_, b)) => b,
+            None => bail!("the request is not multipart: {}", content_type),
+        };
+
+        let parts = parse_form_data(body, &boundary)?;
+
+        // The charsets tell how to decode the other fields.
+        let mut charsets: BTreeMap<String, String> = BTreeMap::new();
+        if let Some(part) = parts.iter().find(|p| p.name == "charsets") {
+            charsets = serde_json::from_slice(&part.body)?;
+        }
+
+        let mut email = InboundEmail {
+            charsets,
+            ..Default::default()
+        };
+        let mut attachment_info: BTreeMap<String, AttachmentInfo> = BTreeMap::new();
+        let mut content_ids: BTreeMap<String, String> = BTreeMap::new();
+
+        for part in parts {
+            if part.filename.is_some() {
+                email.attachments.push(InboundAttachment {
+                    field: part.name,
+                    filename: part.filename.unwrap_or_default(),
+                    content_type: part.content_type,
+                    content_id: String::new(),
+                    content: bytes::Bytes::from(part.body),
+                });
+                continue;
+            }
+
+            if part.name == "email" {
+                email.raw = Some(bytes::Bytes::from(part.body));
+                continue;
+            }
+
+            let value = decode(&part.body, email.charsets.get(&part.name));
+            match part.name.as_str() {
+                "headers" => email.headers = value,
+                "from" => email.from = value,
+                "to" => email.to = value,
+                "cc" => email.cc = value,
+                "subject" => email.subject = value,
+                "text" => email.text = value,
+                "html" => email.html = value,
+                "sender_ip" => email.sender_ip = value,
+                "dkim" => email.dkim = value,
+                "SPF" => email.spf = value,
+                "spam_report" => email.spam_report = value,
+                "spam_score" => email.spam_score = value.trim().parse().ok(),
+                "envelope" => email.envelope = serde_json::from_str(&value)?,
+                "attachment-info" => attachment_info = serde_json::from_str(&value)?,
+                "content-ids" => content_ids = serde_json::from_str(&value)?,
+                "charsets" | "attachments" => {}
+                _ => {
+                    email.fields.insert(part.name, value);
+                }
+            }
+        }
+
+        for a in email.attachments.iter_mut() {
+            if let Some(info) = attachment_info.get(&a.field) {
+                if a.filename.is_empty() {
+                    a.filename = info.filename.to_string();
+                }
+                if a.content_type.is_empty() {
+                    a.content_type = info.type_.to_string();
+                }
+                a.content_id = info.content_id.to_string();
+            }
+            if a.content_id.is_empty() {
+                if let Some((id, _)) = content_ids.iter().find(|(_, f)| **f == a.field) {
+                    a.content_id = id.to_string();
+                }
+            }
+        }
+
+        if let Some(raw) = email.raw.clone() {
+            email.parse_raw(&raw);
+        }
+
+        Ok(email)
+    }
+
+    /// Fill the fields SendGrid does not post in raw mode from the MIME
+    /// message.
+    fn parse_raw(&mut self, raw: &[u8]) {
+        use mail_parser::MimeHeaders;
+
+        let message = match mail_parser::MessageParser::default().parse(raw) {
+            Some(m) => m,
+            None => return,
+        };
+
+        let root = message.root_part();
+        self.headers = String::from_utf8_lossy(
+            &raw[root.raw_header_offset()..root.raw_body_offset().min(raw.len())],
+        )
+        .trim_end()
+        .to_string();
+        if self.subject.is_empty() {
+            self.subject = message.subject().unwrap_or_default().to_string();
+        }
+        for (field, name) in [
+            (&mut self.from, "From"),
+            (&mut self.to, "To"),
+            (&mut self.cc, "Cc"),
+        ] {
+            if field.is_empty() {
+                *field = message
+                    .header_raw(name)
+                    .unwrap_or_default()
+                    .trim()
+                    .to_string();
+            }
+        }
+
+        // Without a part of their own, mail-parser converts one content to the
+        // other.
+        if let Some(text) = message.text_part(0).filter(|p| !p.is_text_html()) {
+            self.text = text.text_contents().unwrap_or_default().to_string();
+        }
+        if let Some(html) = message.html_part(0).filter(|p| p.is_text_html()) {
+            self.html = html.text_contents().unwrap_or_default().to_string();
+        }
+
+        for (i, a) in message.attachments().enumerate() {
+            self.attachments.push(InboundAttachment {
+                field: format!("attachment{}", i + 1),
+                filename: a.attachment_name().unwrap_or_default().to_string(),
+                content_type: a
+                    .content_type()
+                    .map(|t| match t.subtype() {
+                        Some(subtype) => format!("{}/{}", t.ctype(), subtype),
+                        None => t.ctype().to_string(),
+                    })
+                    .unwrap_or_default(),
+                content_id: a.content_id().unwrap_or_default().to_string(),
+                content: bytes::Bytes::copy_from_slice(a.contents()),
+            });
+        }
+    }
+
+    /// Parse an Inbound Parse request, from its headers and body.
+    pub fn from_request(headers: &http::HeaderMap, body: &[u8]) -> Result<Self> {
+        let content_type = headers
+            .get(http::header::CONTENT_TYPE)
+            .ok_or_else(|| anyhow!("the request has no Content-Type header"))?
+            .to_str()?;
+
+        InboundEmail::parse(content_type, body)
+    }
+
+    /// Returns true if the mail was posted in raw mode.
+    pub fn is_raw(&self) -> bool {
+        self.raw.is_some()
+    }
+
+    /// Returns the first value of a header of the mail.
+    pub fn header(&self, name: &str) -> Option<String> {
+        let mut value: Option<String> = None;
+        for line in self.headers.lines() {
+            if line.starts_with([' ', '\t']) {
+                // A folded line continues the previous header.
+                if let Some(v) = value.as_mut() {
+                    v.push(' ');
+                    v.push_str(line.trim());
+                }
+                continue;
+            }
+            if value.is_some() {
+                break;
+            }
+            if let Some((n, v)) = line.split_once(':') {
+                if n.trim().eq_ignore_ascii_case(name) {
+                    value = Some(v.trim().to_string());
+                }
+            }
+        }
+
+        value
+    }
+}
+
+struct FormPart {
+    name: String,
+    filename: Option<String>,
+    content_type: String,
+    body: Vec<u8>,
+}
+
+/// Split a `multipart/form-data` body into its parts.
+fn parse_form_data(body: &[u8], boundary: &str) -> Result<Vec<FormPart>> {
+    let delimiter = format!("--{}", boundary);
+    let mut parts = Vec::new();
+
+    let mut rest = match find_bytes(body, delimiter.as_bytes()) {
+        Some(i) => &body[i + delimiter.len()..],
+        None => bail!("the request has no part"),
+    };
+    // The last delimiter is followed by `--`.
+    while !rest.starts_with(b"--") {
+        let end = match find_bytes(rest, delimiter.as_bytes()) {
+            Some(i) => i,
+            None => bail!("the request ends in the middle of a part"),
+        };
+        let part = &rest[..end];
+        rest = &rest[end + delimiter.len()..];
+
+        let part = part.strip_prefix(b"\r\n").unwrap_or(part);
+        let part = part.strip_suffix(b"\r\n").unwrap_or(part);
+        let (head, body) = match find_bytes(part, b"\r\n\r\n") {
+            Some(i) => (&part[..i], &part[i + 4..]),
+            None => (part, &b""[..]),
+        };
+
+        let mut form_part = FormPart {
+            name: String::new(),
+            filename: None,
+            content_type: String::new(),
+            body: body.to_vec(),
+        };
+        for line in String::from_utf8_lossy(head).lines() {
+            let (name, value) = match line.split_once(':') {
+                Some(h) => h,
+                None => continue,
+            };
+            if name.trim().eq_ignore_ascii_case("content-disposition") {
+                for (param, v) in header_params(value) {
+                    match param.as_str() {
+                        "name" => form_part.name = v,
+                        "filename" => form_part.filename = Some(v),
+                        _ => {}
+                    }
+                }
+            } else if name.trim().eq_ignore_ascii_case("content-type") {
+                form_part.content_type = value.trim().to_string();
+            }
+        }
+        parts.push(form_part);
+    }
+
+    Ok(parts)
+}
+
+/// Returns the parameters of a header value like
+/// `form-data; name="file"; filename="a; b.txt"`, with their names lowercased
+/// and their quoted values unescaped.
+fn header_params(value: &str) -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    let mut rest = match value.split_once(';') {
+        Some((_, rest)) => rest,
+        None => return params,
+    };
+
+    while let Some((name, after)) = rest.split_once('=') {
+        // Skip the parameters without a value.
+        let name = name.rsplit(';').next().unwrap_or_default();
+        let after = after.trim_start();
+
+        let mut value = String::new();
+        match after.strip_prefix('"') {
+            Some(quoted) => {
+                let mut end = quoted.len();
+                let mut chars = quoted.char_indices();
+                while let Some((i, c)) = chars.next() {
+                    match c {
+                        '\\' => value.extend(chars.next().map(|(_, c)| c)),
+                        '"' => {
+                            end = i + 1;
+                            break;
+                        }
+                        c => value.push(c),
+                    }
+                }
+                rest = quoted[end..].split_once(';').map(|(_, r)| r).unwrap_or("");
+            }
+            None => {
+                let (v, r) = after.split_once(';').unwrap_or((after, ""));
+                value = v.trim().to_string();
+                rest = r;
+            }
+        }
+
+        params.push((name.trim().to_ascii_lowercase(), value));
+    }
+
+    params
+}
+
+/// Decode a field from its charset, as UTF-8 if it is unknown.
+fn decode(value: &[u8], charset: Option<&String>) -> String {
+    let encoding = charset
+        .and_then(|c| encoding_rs::Encoding::for_label(c.trim().as_bytes()))
+        .unwrap_or(encoding_rs::UTF_8);
+
+    encoding.decode(value).0.to_string()
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
@@ -1 +1,488 @@
+use crate::traits::{MailBuilder, MailOps, Personalization};
 
+#[test]
+fn test_mail_builder() {
+    let send_at = chrono::DateTime::parse_from_rfc3339("2022-01-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let mail = MailBuilder::new("noreply@example.com", "Example")
+        .with_subject("Hello")
+        .with_html("<p>Hello</p><img src=\"cid:logo\">")
+        .with_text("Hello")
+        .with_reply_to("support@example.com", "")
+        .add_personalization(
+            Personalization::new()
+                .add_to("jane@example.com", "Jane")
+                .add_cc("joe@example.com", "")
+                .add_substitution("-name-", "Jane")
+                .add_custom_arg("user_id", "1"),
+        )
+        .add_to("bob@example.com", "")
+        .add_attachment("hello.txt", "text/plain", b"hello")
+        .add_inline_attachment("logo.png", "image/png", &[0x89, 0x50], "logo")
+        .add_category("welcome")
+        .add_custom_arg("campaign", "launch")
+        .with_send_at(send_at)
+        .with_asm(42, &[42, 43])
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&mail).unwrap(),
+        serde_json::json!({
+            "asm": { "group_id": 42, "groups_to_display": [42, 43] },
+            "attachments": [
+                { "content": "aGVsbG8=", "disposition": "attachment", "filename": "hello.txt", "type": "text/plain" },
+                { "content": "iVA=", "content_id": "logo", "disposition": "inline", "filename": "logo.png", "type": "image/png" },
+            ],
+            "categories": ["welcome"],
+            "content": [
+                { "type": "text/plain", "value": "Hello" },
+                { "type": "text/html", "value": "<p>Hello</p><img src=\"cid:logo\">" },
+            ],
+            "custom_args": { "campaign": "launch" },
+            "from": { "email": "noreply@example.com", "name": "Example" },
+            "personalizations": [
+                {
+                    "cc": [{ "email": "joe@example.com" }],
+                    "custom_args": { "user_id": "1" },
+                    "substitutions": { "-name-": "Jane" },
+                    "to": [{ "email": "jane@example.com", "name": "Jane" }],
+                },
+                { "to": [{ "email": "bob@example.com" }] },
+            ],
+            "reply_to": { "email": "support@example.com" },
+            "send_at": 1640995200,
+            "subject": "Hello",
+        })
+    );
+}
+
+#[test]
+fn test_mail_builder_dynamic_template() {
+    #[derive(serde::Serialize)]
+    struct Welcome {
+        first_name: String,
+    }
+
+    let mail = MailBuilder::new("noreply@example.com", "")
+        .with_template("d-1")
+        .add_personalization(
+            Personalization::new()
+                .add_to("jane@example.com", "")
+                .with_dynamic_template_data(&Welcome {
+                    first_name: "Jane".to_string(),
+                })
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    assert_eq!(mail.request.template_id, "d-1");
+    assert!(mail.request.content.is_empty());
+    assert_eq!(
+        serde_json::to_value(&mail).unwrap()["personalizations"][0]["dynamic_template_data"],
+        serde_json::json!({ "first_name": "Jane" })
+    );
+    assert!(Personalization::new()
+        .with_dynamic_template_data(&"Jane")
+        .is_err());
+}
+
+#[test]
+fn test_mail_builder_limits() {
+    let mail = || {
+        MailBuilder::new("noreply@example.com", "")
+            .with_subject("Hello")
+            .with_text("Hello")
+    };
+
+    let err = |m: MailBuilder| m.build().unwrap_err().to_string();
+    assert!(err(mail()).contains("no recipients"));
+    assert!(
+        err(MailBuilder::new("noreply@example.com", "").add_to("a@example.com", ""))
+            .contains("no subject")
+    );
+
+    let mut many = mail();
+    for i in 0..=crate::traits::MAX_PERSONALIZATIONS {
+        many = many.add_to(&format!("{}@example.com", i), "");
+    }
+    assert!(err(many).contains("1001 personalizations"));
+
+    let mut copies = Personalization::new().add_to("a@example.com", "");
+    for i in 0..crate::traits::MAX_RECIPIENTS / 2 {
+        copies = copies
+            .add_cc(&format!("cc{}@example.com", i), "")
+            .add_bcc(&format!("bcc{}@example.com", i), "");
+    }
+    assert!(err(mail().add_personalization(copies)).contains("1001 recipients"));
+
+    let big = vec![0; crate::traits::MAX_MAIL_SIZE];
+    assert!(err(mail().add_to("a@example.com", "").add_attachment(
+        "big.bin",
+        "application/octet-stream",
+        &big
+    ))
+    .contains("bytes SendGrid accepts"));
+
+    assert!(
+        err(mail().add_to("a@example.com", "").add_inline_attachment(
+            "logo.png",
+            "image/png",
+            b"",
+            ""
+        ))
+        .contains("no content ID")
+    );
+}
+
+#[tokio::test]
+async fn test_send_mail() {
+    use wiremock::{
+        matchers::{body_json, method, path},
+        Mock, MockServer, ResponseTemplate,
+    };
+
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/mail/send"))
+        .and(body_json(serde_json::json!({
+            "content": [{ "type": "text/plain", "value": "Hello there" }],
+            "from": { "email": "noreply@example.com" },
+            "personalizations": [{
+                "bcc": [{ "email": "b@example.com" }],
+                "from": { "email": "noreply@example.com" },
+                "to": [{ "email": "a@example.com" }],
+            }],
+        })))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = crate::Client::new("").with_host(server.uri());
+    client
+        .mail_send()
+        .send_plain_text(
+            "",
+            "Hello there",
+            &["a@example.com".to_string()],
+            &[],
+            &["b@example.com".to_string()],
+            "noreply@example.com",
+        )
+        .await
+        .unwrap();
+
+    let err = client
+        .mail_send()
+        .send(MailBuilder::new("noreply@example.com", ""))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("no recipients"));
+}
+
+#[test]
+fn test_verify_event_webhook() {
+    use ring::signature::KeyPair;
+
+    let rng = ring::rand::SystemRandom::new();
+    let pkcs8 = ring::signature::EcdsaKeyPair::generate_pkcs8(
+        &ring::signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        &rng,
+    )
+    .unwrap();
+    let key = ring::signature::EcdsaKeyPair::from_pkcs8(
+        &ring::signature::ECDSA_P256_SHA256_ASN1_SIGNING,
+        pkcs8.as_ref(),
+    )
+    .unwrap();
+
+    // The DER SubjectPublicKeyInfo of the key, as SendGrid returns it.
+    let mut spki = vec![
+        0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01, 0x06, 0x08,
+        0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00,
+    ];
+    spki.extend_from_slice(key.public_key().as_ref());
+    let public_key = base64::encode(&spki);
+
+    let timestamp = "1600112502";
+    let body = br#"[{"email":"a@example.com","event":"processed","timestamp":1600112492}]"#;
+    let mut payload = timestamp.as_bytes().to_vec();
+    payload.extend_from_slice(body);
+    let signature = base64::encode(key.sign(&rng, &payload).unwrap());
+
+    crate::traits::verify_event_webhook(&public_key, &signature, timestamp, body).unwrap();
+    let pem = format!(
+        "-----BEGIN PUBLIC KEY-----\n{}\n-----END PUBLIC KEY-----\n",
+        public_key
+    );
+    crate::traits::verify_event_webhook(&pem, &signature, timestamp, body).unwrap();
+
+    assert!(
+        crate::traits::verify_event_webhook(&public_key, &signature, "1600112503", body).is_err()
+    );
+    assert!(crate::traits::verify_event_webhook(
+        &public_key,
+        &signature,
+        timestamp,
+        &body[..body.len() - 1]
+    )
+    .is_err());
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert(
+        crate::traits::EVENT_WEBHOOK_SIGNATURE_HEADER,
+        signature.parse().unwrap(),
+    );
+    assert!(
+        crate::traits::verify_event_webhook_request(&public_key, &headers, body)
+            .unwrap_err()
+            .to_string()
+            .contains("Timestamp")
+    );
+    headers.insert(
+        crate::traits::EVENT_WEBHOOK_TIMESTAMP_HEADER,
+        timestamp.parse().unwrap(),
+    );
+    crate::traits::verify_event_webhook_request(&public_key, &headers, body).unwrap();
+}
+
+#[test]
+fn test_parse_events() {
+    use crate::traits::EventKind;
+
+    let events = crate::traits::parse_events(
+        br#"[
+            {"email":"a@example.com","timestamp":1513299569,"smtp-id":"<14c5d75ce93.dfd.64b469@ismtpd-555>","event":"processed","category":"cat facts","sg_event_id":"e1","sg_message_id":"m1","user_id":"42"},
+            {"email":"a@example.com","timestamp":1513299569,"event":"bounce","category":["cat facts","dogs"],"sg_event_id":"e2","reason":"500 unknown recipient","status":"5.0.0","type":"bounce"},
+            {"email":"a@example.com","timestamp":1513299569,"event":"click","url":"https://example.com","useragent":"Mozilla","ip":"255.255.255.255"},
+            {"email":"a@example.com","timestamp":1513299569,"event":"group_unsubscribe","asm_group_id":10},
+            {"email":"a@example.com","timestamp":1513299569,"event":"spamreport"},
+            {"email":"a@example.com","timestamp":1513299569,"event":"something_new"}
+        ]"#,
+    )
+    .unwrap();
+
+    assert_eq!(events.len(), 6);
+    assert_eq!(events[0].event, EventKind::Processed);
+    assert_eq!(events[0].category, vec!["cat facts"]);
+    assert_eq!(events[0].smtp_id, "<14c5d75ce93.dfd.64b469@ismtpd-555>");
+    assert_eq!(events[0].timestamp.timestamp(), 1513299569);
+    assert_eq!(events[0].other.keys().collect::<Vec<_>>(), vec!["user_id"]);
+    assert_eq!(
+        events[1].event,
+        EventKind::Bounce {
+            reason: "500 unknown recipient".to_string(),
+            status: "5.0.0".to_string(),
+            bounce_type: "bounce".to_string(),
+        }
+    );
+    assert_eq!(events[1].category, vec!["cat facts", "dogs"]);
+    assert!(events[1].other.is_empty());
+    assert!(events[1].is_suppression());
+    assert!(
+        matches!(&events[2].event, EventKind::Click { url, .. } if url == "https://example.com")
+    );
+    assert!(!events[2].is_suppression());
+    assert_eq!(
+        events[3].event,
+        EventKind::GroupUnsubscribe { asm_group_id: 10 }
+    );
+    assert!(events[4].is_suppression());
+    assert_eq!(events[5].event, EventKind::Other);
+    assert_eq!(events[5].other["event"], "something_new");
+
+    let json = serde_json::to_string(&events[1]).unwrap();
+    assert_eq!(
+        crate::traits::parse_events(format!("[{}]", json).as_bytes()).unwrap()[0],
+        events[1]
+    );
+    let json = serde_json::to_string(&events[5]).unwrap();
+    assert_eq!(json.matches(r#""event":"something_new""#).count(), 1);
+    assert_eq!(json.matches(r#""event""#).count(), 1);
+    assert_eq!(
+        crate::traits::parse_events(format!("[{}]", json).as_bytes()).unwrap()[0],
+        events[5]
+    );
+}
+
+fn form_data(boundary: &str, parts: &[(&str, Option<&str>, &str, &[u8])]) -> Vec<u8> {
+    let mut body = Vec::new();
+    for (name, filename, content_type, value) in parts {
+        body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+        match filename {
+            Some(f) => body.extend_from_slice(
+                format!(
+                    "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+                    name, f
+                )
+                .as_bytes(),
+            ),
+            None => body.extend_from_slice(
+                format!("Content-Disposition: form-data; name=\"{}\"\r\n", name).as_bytes(),
+            ),
+        }
+        if !content_type.is_empty() {
+            body.extend_from_slice(format!("Content-Type: {}\r\n", content_type).as_bytes());
+        }
+        body.extend_from_slice(b"\r\n");
+        body.extend_from_slice(value);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+    body
+}
+
+#[test]
+fn test_parse_inbound() {
+    use crate::inbound::InboundEmail;
+
+    let body = form_data(
+        "xYzZY",
+        &[
+            (
+                "headers",
+                None,
+                "",
+                b"Received: by mx.example.com\r\nSubject: Caf\xe9\r\nX-Long: a\r\n b\r\n",
+            ),
+            ("from", None, "", b"Ann <ann@example.com>"),
+            ("to", None, "", b"in@parse.example.com"),
+            ("subject", None, "", b"Caf\xe9"),
+            ("text", None, "", b"Un caf\xe9, s'il vous pla\xeet.\r\n"),
+            ("html", None, "", "<p>Un café</p><img src=\"cid:logo\">".as_bytes()),
+            (
+                "envelope",
+                None,
+                "",
+                br#"{"to":["in@parse.example.com"],"from":"ann@example.com"}"#,
+            ),
+            (
+                "charsets",
+                None,
+                "",
+                br#"{"to":"UTF-8","html":"UTF-8","subject":"iso-8859-1","from":"UTF-8","text":"iso-8859-1","headers":"iso-8859-1"}"#,
+            ),
+            ("SPF", None, "", b"pass"),
+            ("dkim", None, "", b"{@example.com : pass}"),
+            ("spam_score", None, "", b"0.012"),
+            ("attachments", None, "", b"2"),
+            (
+                "attachment-info",
+                None,
+                "",
+                br#"{"attachment1":{"filename":"notes.txt","name":"notes.txt","type":"text/plain"},"attachment2":{"filename":"logo.png","name":"logo.png","type":"image/png","content-id":"logo"}}"#,
+            ),
+            ("content-ids", None, "", br#"{"logo":"attachment2"}"#),
+            (
+                "attachment1",
+                Some(r#"notes; \"v2\".txt"#),
+                "text/plain",
+                b"line 1\r\nline 2",
+            ),
+            ("attachment2", Some("logo.png"), "", b"\x89PNG\r\n\x1a\n"),
+            ("custom", None, "", b"value"),
+        ],
+    );
+
+    let email = InboundEmail::parse("multipart/form-data; boundary=xYzZY", &body).unwrap();
+    assert!(!email.is_raw());
+    assert_eq!(email.from, "Ann <ann@example.com>");
+    assert_eq!(email.to, "in@parse.example.com");
+    assert_eq!(email.subject, "Café");
+    assert_eq!(email.text, "Un café, s'il vous plaît.\r\n");
+    assert_eq!(email.html, "<p>Un café</p><img src=\"cid:logo\">");
+    assert_eq!(email.envelope.to, vec!["in@parse.example.com"]);
+    assert_eq!(email.envelope.from, "ann@example.com");
+    assert_eq!(email.spf, "pass");
+    assert_eq!(email.spam_score, Some(0.012));
+    assert_eq!(email.header("subject").unwrap(), "Café");
+    assert_eq!(email.header("X-Long").unwrap(), "a b");
+    assert_eq!(email.header("Cc"), None);
+    assert_eq!(email.fields["custom"], "value");
+
+    assert_eq!(email.attachments.len(), 2);
+    assert_eq!(email.attachments[0].filename, r#"notes; "v2".txt"#);
+    assert_eq!(email.attachments[0].content_type, "text/plain");
+    assert_eq!(email.attachments[0].content, &b"line 1\r\nline 2"[..]);
+    assert_eq!(email.attachments[0].content_id, "");
+    assert_eq!(email.attachments[1].field, "attachment2");
+    assert_eq!(email.attachments[1].content_type, "image/png");
+    assert_eq!(email.attachments[1].content_id, "logo");
+    assert_eq!(email.attachments[1].content, &b"\x89PNG\r\n\x1a\n"[..]);
+}
+
+#[test]
+fn test_parse_inbound_raw() {
+    use crate::inbound::InboundEmail;
+
+    let raw: &[u8] = b"From: ann@example.com\r\n\
+        To: in@parse.example.com\r\n\
+        Cc: bob@example.com\r\n\
+        Subject: =?utf-8?q?Caf=C3=A9?=\r\n\
+        MIME-Version: 1.0\r\n\
+        Content-Type: multipart/mixed; boundary=\"outer\"\r\n\
+        \r\n\
+        --outer\r\n\
+        Content-Type: multipart/alternative; boundary=\"inner\"\r\n\
+        \r\n\
+        --inner\r\n\
+        Content-Type: text/plain; charset=utf-8\r\n\
+        \r\n\
+        Hi!\r\n\
+        --inner\r\n\
+        Content-Type: text/html; charset=utf-8\r\n\
+        \r\n\
+        <p>Hi!</p>\r\n\
+        --inner--\r\n\
+        --outer\r\n\
+        Content-Type: application/pdf; name=\"report.pdf\"\r\n\
+        Content-Disposition: attachment; filename=\"report.pdf\"\r\n\
+        Content-Transfer-Encoding: base64\r\n\
+        \r\n\
+        JVBERi0=\r\n\
+        --outer--\r\n";
+    let body = form_data(
+        "----boundary",
+        &[
+            ("from", None, "", b"ann@example.com"),
+            ("to", None, "", b"in@parse.example.com"),
+            (
+                "charsets",
+                None,
+                "",
+                br#"{"to":"UTF-8","from":"UTF-8","subject":"UTF-8"}"#,
+            ),
+            ("email", None, "", raw),
+        ],
+    );
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert(
+        http::header::CONTENT_TYPE,
+        "multipart/form-data; boundary=\"----boundary\""
+            .parse()
+            .unwrap(),
+    );
+    let email = InboundEmail::from_request(&headers, &body).unwrap();
+    assert!(email.is_raw());
+    assert_eq!(email.raw.as_deref().unwrap(), raw);
+    assert_eq!(email.subject, "Café");
+    assert_eq!(email.cc, "bob@example.com");
+    assert_eq!(email.header("MIME-Version").unwrap(), "1.0");
+    assert_eq!(email.text, "Hi!");
+    assert_eq!(email.html, "<p>Hi!</p>");
+    assert_eq!(email.attachments.len(), 1);
+    assert_eq!(email.attachments[0].filename, "report.pdf");
+    assert_eq!(email.attachments[0].content_type, "application/pdf");
+    assert_eq!(email.attachments[0].content, &b"%PDF-"[..]);
+
+    assert!(InboundEmail::parse("application/json", b"{}").is_err());
+    assert!(InboundEmail::parse(
+        "multipart/form-data; boundary=xYzZY",
+        b"--xYzZY\r\nContent-Disposition: form-data; name=\"to\"\r\n\r\nx"
+    )
+    .is_err());
+}
@@ -1,5 +1,18 @@
 #![allow(clippy::field_reassign_with_default)]
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
+
+/// The most personalizations SendGrid accepts in a single mail.
+pub const MAX_PERSONALIZATIONS: usize = 1000;
+
+/// The most recipients SendGrid accepts in a single mail, counting the `to`,
+/// `cc` and `bcc` of every personalization.
+pub const MAX_RECIPIENTS: usize = 1000;
+
+/// The largest mail SendGrid accepts, in bytes, attachments included.
+pub const MAX_MAIL_SIZE: usize = 30 * 1024 * 1024;
+
+/// The most categories SendGrid accepts in a single mail.
+pub const MAX_CATEGORIES: usize = 10;
 
 #[async_trait::async_trait]
 pub trait MailOps {
@@ -15,6 +28,11 @@ pub trait MailOps {
         bcc: &[String],
         from: &str,
     ) -> Result<()>;
+
+    /// Send a mail built with a `MailBuilder`.
+    ///
+    /// The mail is validated against the limits of SendGrid before it is sent.
+    async fn send(&self, mail: MailBuilder) -> Result<()>;
 }
 
 #[async_trait::async_trait]
@@ -63,18 +81,765 @@ impl MailOps for crate::mail_send::MailSend {
         }
         mail.personalizations = vec![p];
 
+        self.post_mail(serde_json::to_vec(&mail)?).await
+    }
+
+    /// Send a mail built with a `MailBuilder`.
+    ///
+    /// The mail is validated against the limits of SendGrid before it is sent.
+    async fn send(&self, mail: MailBuilder) -> Result<()> {
+        let body = serde_json::to_vec(&mail.build()?)?;
+        if body.len() > MAX_MAIL_SIZE {
+            bail!(
+                "the mail is {} bytes, more than the {} bytes SendGrid accepts",
+                body.len(),
+                MAX_MAIL_SIZE
+            );
+        }
+
+        self.post_mail(body).await
+    }
+}
+
+impl crate::mail_send::MailSend {
+    async fn post_mail(&self, body: Vec<u8>) -> Result<()> {
         let resp = self
             .client
             .request_raw(
                 reqwest::Method::POST,
                 "/mail/send",
-                Some(reqwest::Body::from(serde_json::to_vec(&mail).unwrap())),
+                Some(reqwest::Body::from(body)),
             )
             .await?;
 
         match resp.status() {
             http::StatusCode::ACCEPTED => Ok(()),
-            s => Err(anyhow!("received response status: {:?}", s)),
+            s => Err(anyhow!(
+                "received response status: {:?}, error: {}",
+                s,
+                resp.text().await.unwrap_or_default()
+            )),
+        }
+    }
+}
+
+/// Builds a `Mail`.
+///
+/// ```ignore
+/// let mail = MailBuilder::new("noreply@example.com", "Example")
+///     .with_template("d-f43daeeaef504760851f727007e0b5d0")
+///     .add_personalization(
+///         Personalization::new()
+///             .add_to("jane@example.com", "Jane")
+///             .with_dynamic_template_data(&serde_json::json!({ "first_name": "Jane" }))?,
+///     )
+///     .add_attachment("report.pdf", "application/pdf", &report);
+/// client.mail_send().send(mail).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct MailBuilder {
+    mail: crate::types::PostMailSendRequest,
+    text: Option<String>,
+    html: Option<String>,
+    custom_args: serde_json::Map<String, serde_json::Value>,
+    headers: serde_json::Map<String, serde_json::Value>,
+    personalizations: Vec<PersonalizationObjects>,
+}
+
+impl MailBuilder {
+    /// Start a mail sent from `email`, with an optional display `name`.
+    pub fn new(email: &str, name: &str) -> Self {
+        let mut mail: crate::types::PostMailSendRequest = Default::default();
+        mail.from = crate::types::FromEmailObject {
+            email: email.to_string(),
+            name: name.to_string(),
+        };
+
+        MailBuilder {
+            mail,
+            text: None,
+            html: None,
+            custom_args: Default::default(),
+            headers: Default::default(),
+            personalizations: Vec::new(),
+        }
+    }
+
+    /// Set the subject, for the personalizations that do not have their own.
+    pub fn with_subject(mut self, subject: &str) -> Self {
+        self.mail.subject = subject.to_string();
+        self
+    }
+
+    /// Set the plain text content.
+    pub fn with_text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Set the HTML content. Mail clients show it instead of the plain text
+    /// content, if both are set.
+    pub fn with_html(mut self, html: &str) -> Self {
+        self.html = Some(html.to_string());
+        self
+    }
+
+    /// Set where replies to the mail are sent.
+    pub fn with_reply_to(mut self, email: &str, name: &str) -> Self {
+        self.mail.reply_to = Some(crate::types::ReplyEmailObject {
+            email: email.to_string(),
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// Add the recipients of a copy of the mail.
+    pub fn add_personalization(mut self, personalization: Personalization) -> Self {
+        self.mail.personalizations.push(personalization.p);
+        self.personalizations.push(personalization.objects);
+        self
+    }
+
+    /// Send a copy of the mail to a single recipient.
+    pub fn add_to(self, email: &str, name: &str) -> Self {
+        self.add_personalization(Personalization::new().add_to(email, name))
+    }
+
+    /// Attach a file.
+    pub fn add_attachment(mut self, filename: &str, mime_type: &str, content: &[u8]) -> Self {
+        self.mail.attachments.push(crate::types::Attachments {
+            content: base64::encode(content),
+            content_id: String::new(),
+            disposition: Some(crate::types::Disposition::Attachment),
+            filename: filename.to_string(),
+            type_: mime_type.to_string(),
+        });
+        self
+    }
+
+    /// Attach a file shown inline, that the HTML content refers to with
+    /// `cid:{content_id}`.
+    pub fn add_inline_attachment(
+        mut self,
+        filename: &str,
+        mime_type: &str,
+        content: &[u8],
+        content_id: &str,
+    ) -> Self {
+        self.mail.attachments.push(crate::types::Attachments {
+            content: base64::encode(content),
+            content_id: content_id.to_string(),
+            disposition: Some(crate::types::Disposition::Inline),
+            filename: filename.to_string(),
+            type_: mime_type.to_string(),
+        });
+        self
+    }
+
+    /// Send the mail with a dynamic template, filled with the
+    /// `dynamic_template_data` of each personalization.
+    pub fn with_template(mut self, template_id: &str) -> Self {
+        self.mail.template_id = template_id.to_string();
+        self
+    }
+
+    /// Add a category, to group the statistics of the mail.
+    pub fn add_category(mut self, category: &str) -> Self {
+        self.mail.categories.push(category.to_string());
+        self
+    }
+
+    /// Add a custom argument, sent back with the events of the mail.
+    pub fn add_custom_arg(mut self, key: &str, value: &str) -> Self {
+        self.custom_args.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Add a header to the mail.
+    pub fn add_header(mut self, key: &str, value: &str) -> Self {
+        self.headers.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Schedule the mail, up to 72 hours in advance.
+    pub fn with_send_at(mut self, send_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.mail.send_at = send_at.timestamp();
+        self
+    }
+
+    /// Set the unsubscribe group of the mail, and the groups shown on its
+    /// unsubscribe preferences page.
+    pub fn with_asm(mut self, group_id: i64, groups_to_display: &[i64]) -> Self {
+        self.mail.asm = Some(crate::types::Asm {
+            group_id,
+            groups_to_display: groups_to_display.to_vec(),
+        });
+        self
+    }
+
+    /// Set the batch of the mail, to cancel or pause it with the other mails
+    /// of the batch.
+    pub fn with_batch_id(mut self, batch_id: &str) -> Self {
+        self.mail.batch_id = batch_id.to_string();
+        self
+    }
+
+    /// Returns the mail, if it is within the limits of SendGrid.
+    pub fn build(mut self) -> Result<Mail> {
+        // The plain text content has to come first.
+        self.mail.content = Vec::new();
+        if let Some(text) = self.text {
+            self.mail.content.push(crate::types::Content {
+                type_: "text/plain".to_string(),
+                value: text,
+            });
+        }
+        if let Some(html) = self.html {
+            self.mail.content.push(crate::types::Content {
+                type_: "text/html".to_string(),
+                value: html,
+            });
+        }
+
+        validate(&self.mail)?;
+
+        Ok(Mail {
+            request: self.mail,
+            custom_args: self.custom_args,
+            headers: self.headers,
+            personalizations: self.personalizations,
+        })
+    }
+}
+
+/// A mail built with a `MailBuilder`.
+///
+/// The generated `PostMailSendRequest` can not hold the free-form objects of a
+/// mail, so they are kept next to it and merged in when the mail is serialized.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mail {
+    pub request: crate::types::PostMailSendRequest,
+    pub custom_args: serde_json::Map<String, serde_json::Value>,
+    pub headers: serde_json::Map<String, serde_json::Value>,
+    /// The free-form objects of each of `request.personalizations`, in order.
+    pub personalizations: Vec<PersonalizationObjects>,
+}
+
+impl Mail {
+    fn to_value(&self) -> serde_json::Result<serde_json::Value> {
+        let mut value = serde_json::to_value(&self.request)?;
+        if let Some(mail) = value.as_object_mut() {
+            if !self.custom_args.is_empty() {
+                mail.insert("custom_args".to_string(), self.custom_args.clone().into());
+            }
+            if !self.headers.is_empty() {
+                mail.insert("headers".to_string(), self.headers.clone().into());
+            }
+            if let Some(serde_json::Value::Array(ps)) = mail.get_mut("personalizations") {
+                for (p, objects) in ps.iter_mut().zip(&self.personalizations) {
+                    if let (Some(p), serde_json::Value::Object(objects)) =
+                        (p.as_object_mut(), serde_json::to_value(objects)?)
+                    {
+                        p.extend(objects);
+                    }
+                }
+            }
+        }
+
+        Ok(value)
+    }
+}
+
+impl serde::Serialize for Mail {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_value()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+/// The free-form objects of a personalization.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct PersonalizationObjects {
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub custom_args: serde_json::Map<String, serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dynamic_template_data: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub headers: serde_json::Map<String, serde_json::Value>,
+    #[serde(skip_serializing_if = "serde_json::Map::is_empty")]
+    pub substitutions: serde_json::Map<String, serde_json::Value>,
+}
+
+/// The recipients of a copy of a mail, and what to change in their copy.
+#[derive(Debug, Clone, Default)]
+pub struct Personalization {
+    p: crate::types::Personalizations,
+    objects: PersonalizationObjects,
+}
+
+impl Personalization {
+    /// Start a personalization with no recipients.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Add a recipient.
+    pub fn add_to(mut self, email: &str, name: &str) -> Self {
+        self.p.to.push(crate::types::ReplyTo {
+            email: email.to_string(),
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// Add a recipient of a carbon copy.
+    pub fn add_cc(mut self, email: &str, name: &str) -> Self {
+        self.p.cc.push(crate::types::CcBccEmailObject {
+            email: email.to_string(),
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// Add a recipient of a blind carbon copy.
+    pub fn add_bcc(mut self, email: &str, name: &str) -> Self {
+        self.p.bcc.push(crate::types::CcBccEmailObject {
+            email: email.to_string(),
+            name: name.to_string(),
+        });
+        self
+    }
+
+    /// Set the subject of this copy.
+    pub fn with_subject(mut self, subject: &str) -> Self {
+        self.p.subject = subject.to_string();
+        self
+    }
+
+    /// Set the data the dynamic template of the mail is filled with.
+    pub fn with_dynamic_template_data<T: serde::Serialize>(mut self, data: &T) -> Result<Self> {
+        let data = serde_json::to_value(data)?;
+        if !data.is_object() {
+            bail!("dynamic template data must be an object, not {}", data);
+        }
+        self.objects.dynamic_template_data = Some(data);
+        Ok(self)
+    }
+
+    /// Replace `key` with `value` in the content of this copy, for mails
+    /// that do not use a dynamic template.
+    pub fn add_substitution(mut self, key: &str, value: &str) -> Self {
+        self.objects
+            .substitutions
+            .insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Add a custom argument, sent back with the events of this copy.
+    pub fn add_custom_arg(mut self, key: &str, value: &str) -> Self {
+        self.objects
+            .custom_args
+            .insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Add a header to this copy.
+    pub fn add_header(mut self, key: &str, value: &str) -> Self {
+        self.objects.headers.insert(key.to_string(), value.into());
+        self
+    }
+
+    /// Schedule this copy, up to 72 hours in advance.
+    pub fn with_send_at(mut self, send_at: chrono::DateTime<chrono::Utc>) -> Self {
+        self.p.send_at = send_at.timestamp();
+        self
+    }
+}
+
+/// Check a mail against the limits of SendGrid.
+fn validate(mail: &crate::types::PostMailSendRequest) -> Result<()> {
+    if mail.from.email.is_empty() {
+        bail!("the mail has no sender");
+    }
+    if mail.personalizations.is_empty() {
+        bail!("the mail has no recipients");
+    }
+    if mail.personalizations.len() > MAX_PERSONALIZATIONS {
+        bail!(
+            "the mail has {} personalizations, more than the {} SendGrid accepts",
+            mail.personalizations.len(),
+            MAX_PERSONALIZATIONS
+        );
+    }
+    let recipients = mail
+        .personalizations
+        .iter()
+        .map(|p| p.to.len() + p.cc.len() + p.bcc.len())
+        .sum::<usize>();
+    if recipients > MAX_RECIPIENTS {
+        bail!(
+            "the mail has {} recipients, more than the {} SendGrid accepts",
+            recipients,
+            MAX_RECIPIENTS
+        );
+    }
+    for (i, p) in mail.personalizations.iter().enumerate() {
+        if p.to.is_empty() {
+            bail!("personalization {} has no recipients", i);
+        }
+        if p.subject.is_empty() && mail.subject.is_empty() && mail.template_id.is_empty() {
+            bail!("personalization {} has no subject", i);
+        }
+    }
+    if mail.content.is_empty() && mail.template_id.is_empty() {
+        bail!("the mail has no content and no template");
+    }
+    if mail.categories.len() > MAX_CATEGORIES {
+        bail!(
+            "the mail has {} categories, more than the {} SendGrid accepts",
+            mail.categories.len(),
+            MAX_CATEGORIES
+        );
+    }
+    for a in &mail.attachments {
+        if a.disposition == Some(crate::types::Disposition::Inline) && a.content_id.is_empty() {
+            bail!("the inline attachment {} has no content ID", a.filename);
+        }
+    }
+
+    let size = mail
+        .attachments
+        .iter()
+        .map(|a| a.content.len())
+        .sum::<usize>()
+        + mail.content.iter().map(|c| c.value.len()).sum::<usize>();
+    if size > MAX_MAIL_SIZE {
+        bail!(
+            "the mail is {} bytes, more than the {} bytes SendGrid accepts",
+            size,
+            MAX_MAIL_SIZE
+        );
+    }
+
+    Ok(())
+}
+
+/// The header of the base64 ECDSA signature of a signed event webhook request.
+pub const EVENT_WEBHOOK_SIGNATURE_HEADER: &str = "X-Twilio-Email-Event-Webhook-Signature";
+
+/// The header of the timestamp of a signed event webhook request, which is
+/// signed along with the body.
+pub const EVENT_WEBHOOK_TIMESTAMP_HEADER: &str = "X-Twilio-Email-Event-Webhook-Timestamp";
+
+/// Verify the signature of an event webhook request, from its `signature`
+/// and `timestamp` headers and its raw `body`.
+///
+/// `public_key` is the base64 key returned by
+/// `webhooks().get_user_event_settings_signed()`, with or without the PEM
+/// armor.
+pub fn verify_event_webhook(
+    public_key: &str,
+    signature: &str,
+    timestamp: &str,
+    body: &[u8],
+) -> Result<()> {
+    let key: String = public_key
+        .lines()
+        .filter(|l| !l.starts_with("-----"))
+        .collect::<Vec<_>>()
+        .concat();
+    let key = base64::decode(key.trim())?;
+    let point = ec_public_key_point(&key)?;
+
+    let signature = base64::decode(signature.trim())?;
+    let mut payload = timestamp.as_bytes().to_vec();
+    payload.extend_from_slice(body);
+
+    ring::signature::UnparsedPublicKey::new(&ring::signature::ECDSA_P256_SHA256_ASN1, point)
+        .verify(&payload, &signature)
+        .map_err(|_| anyhow!("invalid event webhook signature"))
+}
+
+/// Verify the signature of an event webhook request, from its headers and its
+/// raw `body`.
+pub fn verify_event_webhook_request(
+    public_key: &str,
+    headers: &http::HeaderMap,
+    body: &[u8],
+) -> Result<()> {
+    let header = |name: &str| -> Result<&str> {
+        match headers.get(name) {
+            Some(v) => Ok(v.to_str()?),
+            None => bail!("the request has no {} header", name),
         }
+    };
+
+    verify_event_webhook(
+        public_key,
+        header(EVENT_WEBHOOK_SIGNATURE_HEADER)?,
+        header(EVENT_WEBHOOK_TIMESTAMP_HEADER)?,
+        body,
+    )
+}
+
+/// Returns the uncompressed P-256 point of a DER `SubjectPublicKeyInfo`.
+fn ec_public_key_point(der: &[u8]) -> Result<&[u8]> {
+    // The OID of the prime256v1 curve.
+    const P256: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+
+    // A raw point.
+    if der.len() == 65 && der[0] == 0x04 {
+        return Ok(der);
+    }
+
+    let (info, _) = der_element(der, 0x30)?;
+    let (algorithm, rest) = der_element(info, 0x30)?;
+    if !algorithm.windows(P256.len()).any(|w| w == P256) {
+        bail!("the public key is not a P-256 key");
+    }
+    let (key, _) = der_element(rest, 0x03)?;
+    match key.split_first() {
+        // No unused bits.
+        Some((0, point)) => Ok(point),
+        _ => bail!("invalid public key"),
+    }
+}
+
+/// Returns the contents of the DER element at the start of `der` with the
+/// tag `tag`, and what follows it.
+fn der_element(der: &[u8], tag: u8) -> Result<(&[u8], &[u8])> {
+    if der.len() < 2 || der[0] != tag {
+        bail!("invalid public key");
     }
+
+    let (len, start) = match der[1] {
+        l if l < 0x80 => (l as usize, 2),
+        0x81 if der.len() > 2 => (der[2] as usize, 3),
+        0x82 if der.len() > 3 => (((der[2] as usize) << 8) | der[3] as usize, 4),
+        _ => bail!("invalid public key"),
+    };
+    if der.len() < start + len {
+        bail!("invalid public key");
+    }
+
+    Ok((&der[start..start + len], &der[start + len..]))
+}
+
+/// Parse the body of an event webhook request.
+pub fn parse_events(body: &[u8]) -> Result<Vec<EmailEvent>> {
+    Ok(serde_json::from_slice(body)?)
+}
+
+/// An event of a mail, as sent by the event webhook.
+#[derive(PartialEq, Debug, Clone)]
+pub struct EmailEvent {
+    /// The recipient of the mail.
+    pub email: String,
+    /// When the event happened.
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// The ID of the event, to deduplicate the events that are sent twice.
+    pub sg_event_id: String,
+    pub sg_message_id: String,
+    pub smtp_id: String,
+    /// The categories of the mail. SendGrid sends a single one as a string.
+    pub category: Vec<String>,
+    /// The type of the event, and its details.
+    pub event: EventKind,
+    /// The custom arguments of the mail, and the fields of the event that are
+    /// not modeled, including `event` for the events this crate does not know
+    /// about.
+    pub other: std::collections::BTreeMap<String, serde_json::Value>,
+}
+
+impl serde::Serialize for EmailEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(serde::Serialize)]
+        struct Fields<'a> {
+            email: &'a str,
+            timestamp: i64,
+            sg_event_id: &'a str,
+            sg_message_id: &'a str,
+            #[serde(rename = "smtp-id")]
+            smtp_id: &'a str,
+            category: &'a [String],
+            #[serde(flatten)]
+            event: Option<&'a EventKind>,
+            #[serde(flatten)]
+            other: &'a std::collections::BTreeMap<String, serde_json::Value>,
+        }
+
+        Fields {
+            email: &self.email,
+            timestamp: self.timestamp.timestamp(),
+            sg_event_id: &self.sg_event_id,
+            sg_message_id: &self.sg_message_id,
+            smtp_id: &self.smtp_id,
+            category: &self.category,
+            // The events this crate does not know about keep their `event` in
+            // `other`.
+            event: Some(&self.event).filter(|e| **e != EventKind::Other),
+            other: &self.other,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for EmailEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        struct Common {
+            email: String,
+            #[serde(with = "chrono::serde::ts_seconds")]
+            timestamp: chrono::DateTime<chrono::Utc>,
+            #[serde(default)]
+            sg_event_id: String,
+            #[serde(default)]
+            sg_message_id: String,
+            #[serde(default, rename = "smtp-id")]
+            smtp_id: String,
+            #[serde(default, deserialize_with = "deserialize_categories")]
+            category: Vec<String>,
+            #[serde(flatten)]
+            other: std::collections::BTreeMap<String, serde_json::Value>,
+        }
+
+        let mut fields = serde_json::Map::deserialize(deserializer)?;
+        let event: EventKind = serde_json::from_value(serde_json::Value::Object(fields.clone()))
+            .map_err(D::Error::custom)?;
+        // Leave the fields of the event out of `other`.
+        if event != EventKind::Other {
+            if let Ok(serde_json::Value::Object(f)) = serde_json::to_value(&event) {
+                for key in f.keys() {
+                    fields.remove(key);
+                }
+            }
+        }
+        let common: Common =
+            serde_json::from_value(serde_json::Value::Object(fields)).map_err(D::Error::custom)?;
+
+        Ok(EmailEvent {
+            email: common.email,
+            timestamp: common.timestamp,
+            sg_event_id: common.sg_event_id,
+            sg_message_id: common.sg_message_id,
+            smtp_id: common.smtp_id,
+            category: common.category,
+            event,
+            other: common.other,
+        })
+    }
+}
+
+/// The type of an `EmailEvent`, with the fields specific to it.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum EventKind {
+    /// The mail was received and is ready to be delivered.
+    Processed,
+    /// The receiving server temporarily rejected the mail.
+    Deferred {
+        #[serde(default)]
+        response: String,
+        #[serde(default)]
+        attempt: String,
+    },
+    /// The receiving server accepted the mail.
+    Delivered {
+        #[serde(default)]
+        response: String,
+    },
+    /// The recipient opened the mail.
+    Open {
+        #[serde(default)]
+        useragent: String,
+        #[serde(default)]
+        ip: String,
+    },
+    /// The recipient clicked a link of the mail.
+    Click {
+        #[serde(default)]
+        url: String,
+        #[serde(default)]
+        useragent: String,
+        #[serde(default)]
+        ip: String,
+    },
+    /// The receiving server permanently rejected the mail.
+    Bounce {
+        #[serde(default)]
+        reason: String,
+        #[serde(default)]
+        status: String,
+        /// `bounce`, or `blocked` if the rejection may be temporary.
+        #[serde(default, rename = "type")]
+        bounce_type: String,
+    },
+    /// SendGrid did not send the mail, for example because the recipient
+    /// previously bounced or unsubscribed.
+    Dropped {
+        #[serde(default)]
+        reason: String,
+    },
+    /// The recipient marked the mail as spam.
+    #[serde(rename = "spamreport")]
+    SpamReport,
+    /// The recipient unsubscribed from every mail.
+    Unsubscribe,
+    /// The recipient unsubscribed from an unsubscribe group.
+    GroupUnsubscribe {
+        #[serde(default)]
+        asm_group_id: i64,
+    },
+    /// The recipient subscribed again to an unsubscribe group.
+    GroupResubscribe {
+        #[serde(default)]
+        asm_group_id: i64,
+    },
+    /// An event this crate does not know about.
+    #[serde(other)]
+    Other,
+}
+
+impl EmailEvent {
+    /// Returns true if the recipient should not be sent mail anymore: the
+    /// mail bounced, was dropped or was reported as spam, or the recipient
+    /// unsubscribed.
+    pub fn is_suppression(&self) -> bool {
+        matches!(
+            self.event,
+            EventKind::Bounce { .. }
+                | EventKind::Dropped { .. }
+                | EventKind::SpamReport
+                | EventKind::Unsubscribe
+        )
+    }
+}
+
+fn deserialize_categories<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    enum Categories {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(
+        match <Option<Categories> as serde::Deserialize>::deserialize(deserializer)? {
+            Some(Categories::One(c)) => vec![c],
+            Some(Categories::Many(c)) => c,
+            None => Vec::new(),
+        },
+    )
 }
@@ -154,6 +154,65 @@ impl AdminConversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/admin.conversations.getTeams` endpoint.
+     *
+     * As opposed to `get_team`, this function returns all the pages of the request at once.
+     *
+     * Get all the workspaces a given public or private channel is connected to within this Enterprise org.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.getTeams>
+     */
+    pub async fn get_teams_all(&self, channel_id: &str) -> Result<Vec<String>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel_id.is_empty() {
+            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/admin.conversations.getTeams?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<String> = crate::utils::cursor_items(&mut page, "team_ids")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin.conversations.getTeams` endpoint.
+     *
+     * As opposed to `get_team`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Get all the workspaces a given public or private channel is connected to within this Enterprise org.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.getTeams>
+     */
+    pub fn get_teams_all_stream(
+        &self,
+        channel_id: &str,
+    ) -> impl futures::Stream<Item = Result<String>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel_id.is_empty() {
+            query_args.push(("channel_id".to_string(), channel_id.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/admin.conversations.getTeams?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<String> = crate::utils::cursor_items(&mut page, "team_ids")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `POST` to the `/admin.conversations.invite` endpoint.
      *
@@ -245,6 +304,108 @@ impl AdminConversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/admin.conversations.search` endpoint.
+     *
+     * As opposed to `search`, this function returns all the pages of the request at once.
+     *
+     * Search for public or private channels in an Enterprise organization.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.search>
+     */
+    pub async fn search_all(
+        &self,
+        team_ids: &str,
+        query: &str,
+        search_channel_types: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> Result<Vec<crate::types::ObjsChannel>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !search_channel_types.is_empty() {
+            query_args.push((
+                "search_channel_types".to_string(),
+                search_channel_types.to_string(),
+            ));
+        }
+        if !sort.is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !sort_dir.is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !team_ids.is_empty() {
+            query_args.push(("team_ids".to_string(), team_ids.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/admin.conversations.search?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsChannel> =
+                crate::utils::cursor_items(&mut page, "channels")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/admin.conversations.search` endpoint.
+     *
+     * As opposed to `search`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Search for public or private channels in an Enterprise organization.
+     *
+     * FROM: <https://api.slack.com/methods/admin.conversations.search>
+     */
+    pub fn search_all_stream(
+        &self,
+        team_ids: &str,
+        query: &str,
+        search_channel_types: &str,
+        sort: &str,
+        sort_dir: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::ObjsChannel>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !query.is_empty() {
+            query_args.push(("query".to_string(), query.to_string()));
+        }
+        if !search_channel_types.is_empty() {
+            query_args.push((
+                "search_channel_types".to_string(),
+                search_channel_types.to_string(),
+            ));
+        }
+        if !sort.is_empty() {
+            query_args.push(("sort".to_string(), sort.to_string()));
+        }
+        if !sort_dir.is_empty() {
+            query_args.push(("sort_dir".to_string(), sort_dir.to_string()));
+        }
+        if !team_ids.is_empty() {
+            query_args.push(("team_ids".to_string(), team_ids.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/admin.conversations.search?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsChannel> =
+                crate::utils::cursor_items(&mut page, "channels")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `POST` to the `/admin.conversations.setConversationPrefs` endpoint.
      *
@@ -42,4 +42,52 @@ impl AppsPermissionsResources {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * This function performs a `GET` to the `/apps.permissions.resources.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Returns list of resource grants this app has on a team.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.resources.list>
+     */
+    pub async fn list_all(&self) -> Result<Vec<crate::types::Resources>> {
+        let url = "/apps.permissions.resources.list".to_string();
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::Resources> =
+                crate::utils::cursor_items(&mut page, "resources")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/apps.permissions.resources.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Returns list of resource grants this app has on a team.
+     *
+     * FROM: <https://api.slack.com/methods/apps.permissions.resources.list>
+     */
+    pub fn list_all_stream(
+        &self,
+    ) -> impl futures::Stream<Item = Result<crate::types::Resources>> + '_ {
+        let url = "/apps.permissions.resources.list".to_string();
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::Resources> =
+                crate::utils::cursor_items(&mut page, "resources")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
 }
@@ -0,0 +1,1270 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+
+/// The most blocks Slack accepts in a message.
+pub const MAX_MESSAGE_BLOCKS: usize = 50;
+
+/// The most blocks Slack accepts in a modal or an App Home view.
+pub const MAX_VIEW_BLOCKS: usize = 100;
+
+/// The longest `text` Slack accepts for a message, the fallback of its blocks.
+pub const MAX_MESSAGE_TEXT_LENGTH: usize = 40000;
+
+/// A text object, either plain text or formatted with Slack's `mrkdwn`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Text {
+    PlainText {
+        text: String,
+        /// Whether emojis in colon format are rendered.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    Mrkdwn {
+        text: String,
+        /// Whether URLs, channel and user names are left as they are instead
+        /// of being linked.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+impl Text {
+    /// A plain text object.
+    pub fn plain(text: &str) -> Self {
+        Text::PlainText {
+            text: text.to_string(),
+            emoji: None,
+        }
+    }
+
+    /// A text object formatted with `mrkdwn`.
+    pub fn mrkdwn(text: &str) -> Self {
+        Text::Mrkdwn {
+            text: text.to_string(),
+            verbatim: None,
+        }
+    }
+
+    /// Returns the text of the object.
+    pub fn text(&self) -> &str {
+        match self {
+            Text::PlainText { text, .. } => text,
+            Text::Mrkdwn { text, .. } => text,
+        }
+    }
+
+    fn is_plain(&self) -> bool {
+        matches!(self, Text::PlainText { .. })
+    }
+}
+
+/// A block of a message, a modal or an App Home view.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Section(Section),
+    Actions(Actions),
+    Input(Input),
+    Context(Context),
+    Header(Header),
+    Divider(Divider),
+}
+
+impl Block {
+    /// Returns the ID of the block, empty if Slack generates it.
+    pub fn block_id(&self) -> &str {
+        match self {
+            Block::Section(b) => &b.block_id,
+            Block::Actions(b) => &b.block_id,
+            Block::Input(b) => &b.block_id,
+            Block::Context(b) => &b.block_id,
+            Block::Header(b) => &b.block_id,
+            Block::Divider(b) => &b.block_id,
+        }
+    }
+
+    /// Check the block against the limits of Slack.
+    pub fn validate(&self) -> Result<()> {
+        check_len("a block_id", self.block_id(), 255)?;
+
+        match self {
+            Block::Section(b) => {
+                if b.text.is_none() && b.fields.is_empty() {
+                    bail!("a section needs a text or fields");
+                }
+                if let Some(text) = &b.text {
+                    check_text("the text of a section", text, 3000)?;
+                }
+                if b.fields.len() > 10 {
+                    bail!(
+                        "a section has {} fields, more than the 10 Slack accepts",
+                        b.fields.len()
+                    );
+                }
+                for f in &b.fields {
+                    check_text("a field of a section", f, 2000)?;
+                }
+                if let Some(a) = &b.accessory {
+                    if let Element::PlainTextInput(_) = a {
+                        bail!("a {} can not be the accessory of a section", a.kind());
+                    }
+                    a.validate()?;
+                }
+            }
+            Block::Actions(b) => {
+                if b.elements.is_empty() {
+                    bail!("an actions block has no elements");
+                }
+                if b.elements.len() > 25 {
+                    bail!(
+                        "an actions block has {} elements, more than the 25 Slack accepts",
+                        b.elements.len()
+                    );
+                }
+                for e in &b.elements {
+                    if let Element::PlainTextInput(_) | Element::Image(_) = e {
+                        bail!("a {} can not be in an actions block", e.kind());
+                    }
+                    e.validate()?;
+                }
+            }
+            Block::Input(b) => {
+                check_plain_text("the label of an input", &b.label, 2000)?;
+                if let Some(hint) = &b.hint {
+                    check_plain_text("the hint of an input", hint, 2000)?;
+                }
+                if let Element::Button(_) | Element::Image(_) = b.element {
+                    bail!("a {} can not be the element of an input", b.element.kind());
+                }
+                b.element.validate()?;
+            }
+            Block::Context(b) => {
+                if b.elements.is_empty() {
+                    bail!("a context block has no elements");
+                }
+                if b.elements.len() > 10 {
+                    bail!(
+                        "a context block has {} elements, more than the 10 Slack accepts",
+                        b.elements.len()
+                    );
+                }
+                for e in &b.elements {
+                    match e {
+                        ContextElement::PlainText { text, .. }
+                        | ContextElement::Mrkdwn { text, .. } => {
+                            if text.is_empty() {
+                                bail!("the text of a context is empty");
+                            }
+                            check_len("the text of a context", text, 3000)?;
+                        }
+                        ContextElement::Image(i) => i.validate()?,
+                    }
+                }
+            }
+            Block::Header(b) => check_plain_text("the text of a header", &b.text, 150)?,
+            Block::Divider(_) => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Displays text, possibly in fields, next to an optional element.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Section {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    /// Texts displayed in two columns.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Text>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<Element>,
+}
+
+impl Section {
+    /// A section with a text.
+    pub fn new(text: Text) -> Self {
+        Section {
+            text: Some(text),
+            ..Default::default()
+        }
+    }
+
+    /// A section with only fields.
+    pub fn fields(fields: Vec<Text>) -> Self {
+        Section {
+            fields,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+
+    pub fn add_field(mut self, field: Text) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Display an element, like a button or an image, next to the text.
+    pub fn with_accessory<E: Into<Element>>(mut self, accessory: E) -> Self {
+        self.accessory = Some(accessory.into());
+        self
+    }
+}
+
+/// Holds interactive elements.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Actions {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+    pub elements: Vec<Element>,
+}
+
+impl Actions {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+
+    pub fn add_element<E: Into<Element>>(mut self, element: E) -> Self {
+        self.elements.push(element.into());
+        self
+    }
+}
+
+/// Collects information from users, in modals and App Home views.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Input {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+    pub label: Text,
+    pub element: Element,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hint: Option<Text>,
+    /// Whether the view can be submitted without a value for the input.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub optional: bool,
+    /// Whether a `block_actions` payload is sent when the value changes.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dispatch_action: bool,
+}
+
+impl Input {
+    pub fn new<E: Into<Element>>(label: &str, element: E) -> Self {
+        Input {
+            block_id: String::new(),
+            label: Text::plain(label),
+            element: element.into(),
+            hint: None,
+            optional: false,
+            dispatch_action: false,
+        }
+    }
+
+    pub fn with_block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+
+    pub fn with_hint(mut self, hint: &str) -> Self {
+        self.hint = Some(Text::plain(hint));
+        self
+    }
+
+    pub fn with_optional(mut self, optional: bool) -> Self {
+        self.optional = optional;
+        self
+    }
+
+    pub fn with_dispatch_action(mut self, dispatch_action: bool) -> Self {
+        self.dispatch_action = dispatch_action;
+        self
+    }
+}
+
+/// Displays small texts and images.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Context {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+    pub elements: Vec<ContextElement>,
+}
+
+impl Context {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+
+    pub fn add_text(mut self, text: Text) -> Self {
+        self.elements.push(match text {
+            Text::PlainText { text, emoji } => ContextElement::PlainText { text, emoji },
+            Text::Mrkdwn { text, verbatim } => ContextElement::Mrkdwn { text, verbatim },
+        });
+        self
+    }
+
+    pub fn add_image(mut self, image_url: &str, alt_text: &str) -> Self {
+        self.elements
+            .push(ContextElement::Image(Image::new(image_url, alt_text)));
+        self
+    }
+}
+
+/// An element of a `Context`: a text object or an image.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContextElement {
+    PlainText {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    Mrkdwn {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+    Image(Image),
+}
+
+/// Displays a plain text in a larger, bold font.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Header {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+    pub text: Text,
+}
+
+impl Header {
+    pub fn new(text: &str) -> Self {
+        Header {
+            block_id: String::new(),
+            text: Text::plain(text),
+        }
+    }
+
+    pub fn with_block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+}
+
+/// Separates blocks with a line.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Divider {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub block_id: String,
+}
+
+impl Divider {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    pub fn with_block_id(mut self, block_id: &str) -> Self {
+        self.block_id = block_id.to_string();
+        self
+    }
+}
+
+impl From<Section> for Block {
+    fn from(b: Section) -> Self {
+        Block::Section(b)
+    }
+}
+
+impl From<Actions> for Block {
+    fn from(b: Actions) -> Self {
+        Block::Actions(b)
+    }
+}
+
+impl From<Input> for Block {
+    fn from(b: Input) -> Self {
+        Block::Input(b)
+    }
+}
+
+impl From<Context> for Block {
+    fn from(b: Context) -> Self {
+        Block::Context(b)
+    }
+}
+
+impl From<Header> for Block {
+    fn from(b: Header) -> Self {
+        Block::Header(b)
+    }
+}
+
+impl From<Divider> for Block {
+    fn from(b: Divider) -> Self {
+        Block::Divider(b)
+    }
+}
+
+/// An interactive element, or an image.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Element {
+    Button(Button),
+    StaticSelect(StaticSelect),
+    UsersSelect(UsersSelect),
+    ConversationsSelect(ConversationsSelect),
+    Datepicker(Datepicker),
+    PlainTextInput(PlainTextInput),
+    Image(Image),
+}
+
+impl Element {
+    /// Returns the ID of the action of the element, empty for an image.
+    pub fn action_id(&self) -> &str {
+        match self {
+            Element::Button(e) => &e.action_id,
+            Element::StaticSelect(e) => &e.action_id,
+            Element::UsersSelect(e) => &e.action_id,
+            Element::ConversationsSelect(e) => &e.action_id,
+            Element::Datepicker(e) => &e.action_id,
+            Element::PlainTextInput(e) => &e.action_id,
+            Element::Image(_) => "",
+        }
+    }
+
+    /// Returns the type of the element, as Slack names it.
+    fn kind(&self) -> &'static str {
+        match self {
+            Element::Button(_) => "button",
+            Element::StaticSelect(_) => "static_select",
+            Element::UsersSelect(_) => "users_select",
+            Element::ConversationsSelect(_) => "conversations_select",
+            Element::Datepicker(_) => "datepicker",
+            Element::PlainTextInput(_) => "plain_text_input",
+            Element::Image(_) => "image",
+        }
+    }
+
+    /// Check the element against the limits of Slack.
+    ///
+    /// Which blocks an element can be in is checked with the block.
+    pub fn validate(&self) -> Result<()> {
+        check_len("an action_id", self.action_id(), 255)?;
+
+        match self {
+            Element::Button(e) => {
+                check_plain_text("the text of a button", &e.text, 75)?;
+                check_len("the value of a button", &e.value, 2000)?;
+                check_len("the url of a button", &e.url, 3000)?;
+                if let Some(c) = &e.confirm {
+                    c.validate()?;
+                }
+            }
+            Element::StaticSelect(e) => {
+                check_plain_text("the placeholder of a select", &e.placeholder, 150)?;
+                if e.options.is_empty() {
+                    bail!("the select {} has no options", e.action_id);
+                }
+                if e.options.len() > 100 {
+                    bail!(
+                        "the select {} has {} options, more than the 100 Slack accepts",
+                        e.action_id,
+                        e.options.len()
+                    );
+                }
+                for o in &e.options {
+                    o.validate()?;
+                }
+                if let Some(initial) = &e.initial_option {
+                    if !e.options.contains(initial) {
+                        bail!(
+                            "the initial option of the select {} is not one of its options",
+                            e.action_id
+                        );
+                    }
+                }
+            }
+            Element::UsersSelect(e) => {
+                check_plain_text("the placeholder of a select", &e.placeholder, 150)?
+            }
+            Element::ConversationsSelect(e) => {
+                check_plain_text("the placeholder of a select", &e.placeholder, 150)?
+            }
+            Element::Datepicker(e) => {
+                if let Some(p) = &e.placeholder {
+                    check_plain_text("the placeholder of a datepicker", p, 150)?;
+                }
+            }
+            Element::PlainTextInput(e) => {
+                if let Some(p) = &e.placeholder {
+                    check_plain_text("the placeholder of an input", p, 150)?;
+                }
+                if let Some(max) = e.max_length {
+                    if max > 3000 {
+                        bail!(
+                            "the max_length of the input {} is {}, more than the 3000 Slack \
+                             accepts",
+                            e.action_id,
+                            max
+                        );
+                    }
+                    if e.min_length.unwrap_or_default() > max {
+                        bail!(
+                            "the min_length of the input {} is more than its max_length",
+                            e.action_id
+                        );
+                    }
+                }
+            }
+            Element::Image(e) => e.validate()?,
+        }
+
+        Ok(())
+    }
+}
+
+/// The color of a button.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Button {
+    pub text: Text,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    /// A URL to open in the browser of the user, in addition to sending the
+    /// action.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub url: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub confirm: Option<Confirm>,
+}
+
+impl Button {
+    pub fn new(text: &str, action_id: &str) -> Self {
+        Button {
+            text: Text::plain(text),
+            action_id: action_id.to_string(),
+            url: String::new(),
+            value: String::new(),
+            style: None,
+            confirm: None,
+        }
+    }
+
+    pub fn with_value(mut self, value: &str) -> Self {
+        self.value = value.to_string();
+        self
+    }
+
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.url = url.to_string();
+        self
+    }
+
+    pub fn with_style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Ask the user to confirm before sending the action.
+    pub fn with_confirm(mut self, confirm: Confirm) -> Self {
+        self.confirm = Some(confirm);
+        self
+    }
+}
+
+/// A dialog asking to confirm an action.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Confirm {
+    pub title: Text,
+    pub text: Text,
+    pub confirm: Text,
+    pub deny: Text,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<ButtonStyle>,
+}
+
+impl Confirm {
+    pub fn new(title: &str, text: &str, confirm: &str, deny: &str) -> Self {
+        Confirm {
+            title: Text::plain(title),
+            text: Text::plain(text),
+            confirm: Text::plain(confirm),
+            deny: Text::plain(deny),
+            style: None,
+        }
+    }
+
+    pub fn with_style(mut self, style: ButtonStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_plain_text("the title of a confirmation", &self.title, 100)?;
+        check_text("the text of a confirmation", &self.text, 300)?;
+        check_plain_text("the confirm button of a confirmation", &self.confirm, 30)?;
+        check_plain_text("the deny button of a confirmation", &self.deny, 30)
+    }
+}
+
+/// An option of a select.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct SelectOption {
+    pub text: Text,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<Text>,
+}
+
+impl SelectOption {
+    pub fn new(text: &str, value: &str) -> Self {
+        SelectOption {
+            text: Text::plain(text),
+            value: value.to_string(),
+            description: None,
+        }
+    }
+
+    pub fn with_description(mut self, description: &str) -> Self {
+        self.description = Some(Text::plain(description));
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        check_text("the text of an option", &self.text, 75)?;
+        check_len("the value of an option", &self.value, 150)?;
+        if let Some(d) = &self.description {
+            check_plain_text("the description of an option", d, 75)?;
+        }
+        Ok(())
+    }
+}
+
+/// A select with a static list of options.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct StaticSelect {
+    pub placeholder: Text,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    pub options: Vec<SelectOption>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_option: Option<SelectOption>,
+}
+
+impl StaticSelect {
+    pub fn new(placeholder: &str, action_id: &str) -> Self {
+        StaticSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.to_string(),
+            options: Vec::new(),
+            initial_option: None,
+        }
+    }
+
+    pub fn add_option(mut self, option: SelectOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Select an option when the select is displayed. It must be one of the
+    /// options of the select.
+    pub fn with_initial_option(mut self, option: SelectOption) -> Self {
+        self.initial_option = Some(option);
+        self
+    }
+}
+
+/// A select listing the users of the workspace.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct UsersSelect {
+    pub placeholder: Text,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub initial_user: String,
+}
+
+impl UsersSelect {
+    pub fn new(placeholder: &str, action_id: &str) -> Self {
+        UsersSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.to_string(),
+            initial_user: String::new(),
+        }
+    }
+
+    pub fn with_initial_user(mut self, user_id: &str) -> Self {
+        self.initial_user = user_id.to_string();
+        self
+    }
+}
+
+/// A select listing the conversations of the workspace.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ConversationsSelect {
+    pub placeholder: Text,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub initial_conversation: String,
+    /// Select the conversation the view was opened from, in modals.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub default_to_current_conversation: bool,
+}
+
+impl ConversationsSelect {
+    pub fn new(placeholder: &str, action_id: &str) -> Self {
+        ConversationsSelect {
+            placeholder: Text::plain(placeholder),
+            action_id: action_id.to_string(),
+            initial_conversation: String::new(),
+            default_to_current_conversation: false,
+        }
+    }
+
+    pub fn with_initial_conversation(mut self, conversation_id: &str) -> Self {
+        self.initial_conversation = conversation_id.to_string();
+        self
+    }
+
+    pub fn with_default_to_current_conversation(mut self, default: bool) -> Self {
+        self.default_to_current_conversation = default;
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Datepicker {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_date: Option<chrono::NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+}
+
+impl Datepicker {
+    pub fn new(action_id: &str) -> Self {
+        Datepicker {
+            action_id: action_id.to_string(),
+            initial_date: None,
+            placeholder: None,
+        }
+    }
+
+    pub fn with_initial_date(mut self, date: chrono::NaiveDate) -> Self {
+        self.initial_date = Some(date);
+        self
+    }
+
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+}
+
+/// A free-form text field, in `Input` blocks.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct PlainTextInput {
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub action_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<Text>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub initial_value: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub multiline: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+}
+
+impl PlainTextInput {
+    pub fn new(action_id: &str) -> Self {
+        PlainTextInput {
+            action_id: action_id.to_string(),
+            placeholder: None,
+            initial_value: String::new(),
+            multiline: false,
+            min_length: None,
+            max_length: None,
+        }
+    }
+
+    pub fn with_placeholder(mut self, placeholder: &str) -> Self {
+        self.placeholder = Some(Text::plain(placeholder));
+        self
+    }
+
+    pub fn with_initial_value(mut self, value: &str) -> Self {
+        self.initial_value = value.to_string();
+        self
+    }
+
+    pub fn with_multiline(mut self, multiline: bool) -> Self {
+        self.multiline = multiline;
+        self
+    }
+
+    pub fn with_length(mut self, min: u32, max: u32) -> Self {
+        self.min_length = Some(min);
+        self.max_length = Some(max);
+        self
+    }
+}
+
+/// An image, as the accessory of a `Section` or in a `Context`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct Image {
+    pub image_url: String,
+    pub alt_text: String,
+}
+
+impl Image {
+    pub fn new(image_url: &str, alt_text: &str) -> Self {
+        Image {
+            image_url: image_url.to_string(),
+            alt_text: alt_text.to_string(),
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.image_url.is_empty() {
+            bail!("an image has no url");
+        }
+        check_len("the url of an image", &self.image_url, 3000)?;
+        check_len("the alt_text of an image", &self.alt_text, 2000)
+    }
+}
+
+impl From<Button> for Element {
+    fn from(e: Button) -> Self {
+        Element::Button(e)
+    }
+}
+
+impl From<StaticSelect> for Element {
+    fn from(e: StaticSelect) -> Self {
+        Element::StaticSelect(e)
+    }
+}
+
+impl From<UsersSelect> for Element {
+    fn from(e: UsersSelect) -> Self {
+        Element::UsersSelect(e)
+    }
+}
+
+impl From<ConversationsSelect> for Element {
+    fn from(e: ConversationsSelect) -> Self {
+        Element::ConversationsSelect(e)
+    }
+}
+
+impl From<Datepicker> for Element {
+    fn from(e: Datepicker) -> Self {
+        Element::Datepicker(e)
+    }
+}
+
+impl From<PlainTextInput> for Element {
+    fn from(e: PlainTextInput) -> Self {
+        Element::PlainTextInput(e)
+    }
+}
+
+impl From<Image> for Element {
+    fn from(e: Image) -> Self {
+        Element::Image(e)
+    }
+}
+
+/// A message with blocks, for `chat.postMessage`, `chat.postEphemeral` and
+/// `chat.update`.
+///
+/// ```ignore
+/// let message = Message::new("C0123456789", "Deploy finished")
+///     .add_block(Header::new("Deploy finished"))
+///     .add_block(Section::new(Text::mrkdwn("*api* is now at `v1.2.3`")))
+///     .add_block(Actions::new().add_element(Button::new("Roll back", "rollback")));
+/// client.chat().post_blocks(&message).await?;
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Message {
+    pub channel: String,
+    /// The text of notifications, and of clients that can not display the
+    /// blocks.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocks: Vec<Block>,
+    /// Reply in the thread of this message.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub thread_ts: String,
+    /// Also show the reply of a thread in the channel.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reply_broadcast: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_links: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unfurl_media: Option<bool>,
+}
+
+impl Message {
+    /// A message to a channel, with the text of its notification.
+    pub fn new(channel: &str, text: &str) -> Self {
+        Message {
+            channel: channel.to_string(),
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn add_block<B: Into<Block>>(mut self, block: B) -> Self {
+        self.blocks.push(block.into());
+        self
+    }
+
+    pub fn with_thread_ts(mut self, thread_ts: &str) -> Self {
+        self.thread_ts = thread_ts.to_string();
+        self
+    }
+
+    pub fn with_reply_broadcast(mut self, reply_broadcast: bool) -> Self {
+        self.reply_broadcast = reply_broadcast;
+        self
+    }
+
+    pub fn with_unfurl(mut self, links: bool, media: bool) -> Self {
+        self.unfurl_links = Some(links);
+        self.unfurl_media = Some(media);
+        self
+    }
+
+    /// Check the message against the limits of Slack.
+    pub fn validate(&self) -> Result<()> {
+        if self.channel.is_empty() {
+            bail!("the message has no channel");
+        }
+        if self.text.is_empty() && self.blocks.is_empty() {
+            bail!("the message has no text and no blocks");
+        }
+        check_len("the text of a message", &self.text, MAX_MESSAGE_TEXT_LENGTH)?;
+        if self.blocks.len() > MAX_MESSAGE_BLOCKS {
+            bail!(
+                "the message has {} blocks, more than the {} Slack accepts",
+                self.blocks.len(),
+                MAX_MESSAGE_BLOCKS
+            );
+        }
+        if self.blocks.iter().any(|b| matches!(b, Block::Input(_))) {
+            bail!("input blocks can not be used in messages");
+        }
+        validate_blocks(&self.blocks)
+    }
+}
+
+/// The kind of a `View`.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ViewType {
+    Modal,
+    Home,
+}
+
+/// A modal, or the App Home tab of a user, for the `views` methods.
+///
+/// ```ignore
+/// let view = View::modal("Request time off")
+///     .with_submit("Send")
+///     .with_callback_id("time_off")
+///     .add_block(Input::new("First day", Datepicker::new("start")).with_block_id("start"));
+/// client.views().open_view(trigger_id, &view).await?;
+/// ```
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct View {
+    #[serde(rename = "type")]
+    pub type_: ViewType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    /// The label of the submit button of a modal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submit: Option<Text>,
+    /// The label of the close button of a modal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub close: Option<Text>,
+    pub blocks: Vec<Block>,
+    /// Identifies the view in the interactions it sends.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub callback_id: String,
+    /// A string sent back with the interactions of the view.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub private_metadata: String,
+    /// A unique ID of the view, set by the app.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub external_id: String,
+    /// Close every view of the stack when the modal is closed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub clear_on_close: bool,
+    /// Send a `view_closed` payload when the modal is closed.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub notify_on_close: bool,
+}
+
+impl View {
+    /// A modal with a title.
+    pub fn modal(title: &str) -> Self {
+        View {
+            type_: ViewType::Modal,
+            title: Some(Text::plain(title)),
+            submit: None,
+            close: None,
+            blocks: Vec::new(),
+            callback_id: String::new(),
+            private_metadata: String::new(),
+            external_id: String::new(),
+            clear_on_close: false,
+            notify_on_close: false,
+        }
+    }
+
+    /// An App Home tab.
+    pub fn home() -> Self {
+        View {
+            type_: ViewType::Home,
+            title: None,
+            ..View::modal("")
+        }
+    }
+
+    pub fn add_block<B: Into<Block>>(mut self, block: B) -> Self {
+        self.blocks.push(block.into());
+        self
+    }
+
+    pub fn with_submit(mut self, submit: &str) -> Self {
+        self.submit = Some(Text::plain(submit));
+        self
+    }
+
+    pub fn with_close(mut self, close: &str) -> Self {
+        self.close = Some(Text::plain(close));
+        self
+    }
+
+    pub fn with_callback_id(mut self, callback_id: &str) -> Self {
+        self.callback_id = callback_id.to_string();
+        self
+    }
+
+    pub fn with_private_metadata(mut self, private_metadata: &str) -> Self {
+        self.private_metadata = private_metadata.to_string();
+        self
+    }
+
+    pub fn with_external_id(mut self, external_id: &str) -> Self {
+        self.external_id = external_id.to_string();
+        self
+    }
+
+    pub fn with_clear_on_close(mut self, clear_on_close: bool) -> Self {
+        self.clear_on_close = clear_on_close;
+        self
+    }
+
+    pub fn with_notify_on_close(mut self, notify_on_close: bool) -> Self {
+        self.notify_on_close = notify_on_close;
+        self
+    }
+
+    /// Check the view against the limits of Slack.
+    pub fn validate(&self) -> Result<()> {
+        if self.type_ == ViewType::Modal {
+            match &self.title {
+                Some(title) => check_plain_text("the title of a modal", title, 24)?,
+                None => bail!("the modal has no title"),
+            }
+            if let Some(submit) = &self.submit {
+                check_plain_text("the submit button of a modal", submit, 24)?;
+            } else if self.blocks.iter().any(|b| matches!(b, Block::Input(_))) {
+                bail!("a modal with input blocks needs a submit button");
+            }
+            if let Some(close) = &self.close {
+                check_plain_text("the close button of a modal", close, 24)?;
+            }
+        }
+        check_len("the callback_id of a view", &self.callback_id, 255)?;
+        check_len(
+            "the private_metadata of a view",
+            &self.private_metadata,
+            3000,
+        )?;
+        check_len("the external_id of a view", &self.external_id, 255)?;
+        if self.blocks.len() > MAX_VIEW_BLOCKS {
+            bail!(
+                "the view has {} blocks, more than the {} Slack accepts",
+                self.blocks.len(),
+                MAX_VIEW_BLOCKS
+            );
+        }
+        validate_blocks(&self.blocks)
+    }
+
+    /// Validate the view and serialize it, for the `view` parameter of the
+    /// generated `views` functions.
+    pub fn to_json(&self) -> Result<String> {
+        self.validate()?;
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+fn validate_blocks(blocks: &[Block]) -> Result<()> {
+    let mut block_ids = std::collections::HashSet::new();
+    for (i, b) in blocks.iter().enumerate() {
+        if !b.block_id().is_empty() && !block_ids.insert(b.block_id()) {
+            bail!(
+                "the block_id {} is used by more than one block",
+                b.block_id()
+            );
+        }
+        b.validate()
+            .map_err(|e| anyhow::anyhow!("block {}: {}", i, e))?;
+    }
+
+    Ok(())
+}
+
+fn check_len(what: &str, value: &str, max: usize) -> Result<()> {
+    let len = value.chars().count();
+    if len > max {
+        bail!(
+            "{} is {} characters, more than the {} Slack accepts",
+            what,
+            len,
+            max
+        );
+    }
+
+    Ok(())
+}
+
+fn check_text(what: &str, text: &Text, max: usize) -> Result<()> {
+    if text.text().is_empty() {
+        bail!("{} is empty", what);
+    }
+    check_len(what, text.text(), max)
+}
+
+fn check_plain_text(what: &str, text: &Text, max: usize) -> Result<()> {
+    if !text.is_plain() {
+        bail!("{} must be plain text", what);
+    }
+    check_text(what, text, max)
+}
+
+/// A view as Slack returns it, once it is opened, pushed, updated or
+/// published.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct PublishedView {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub team_id: String,
+    #[serde(default)]
+    pub app_id: String,
+    #[serde(default)]
+    pub bot_id: String,
+    /// Pass it back to `views.update` or `views.publish` to make sure the
+    /// view was not changed in the meantime.
+    #[serde(default)]
+    pub hash: String,
+    #[serde(default)]
+    pub root_view_id: String,
+    #[serde(default)]
+    pub previous_view_id: String,
+    #[serde(default)]
+    pub callback_id: String,
+    #[serde(default)]
+    pub private_metadata: String,
+    #[serde(default)]
+    pub external_id: String,
+    #[serde(default)]
+    pub blocks: Vec<serde_json::Value>,
+    /// The values of the inputs of the view.
+    #[serde(default)]
+    pub state: ViewState,
+}
+
+/// The values of the inputs of a view, by block and action ID.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct ViewState {
+    #[serde(default)]
+    pub values: std::collections::BTreeMap<String, std::collections::BTreeMap<String, StateValue>>,
+}
+
+impl ViewState {
+    /// Returns the value of the element with `action_id` in the block with
+    /// `block_id`.
+    pub fn get(&self, block_id: &str, action_id: &str) -> Option<&StateValue> {
+        self.values.get(block_id)?.get(action_id)
+    }
+}
+
+/// The value of an interactive element, depending on its type.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct StateValue {
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    /// The value of a button or of a plain text input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_option: Option<SelectOption>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selected_options: Vec<SelectOption>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_user: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selected_users: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_conversation: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub selected_conversations: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_channel: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_date: Option<chrono::NaiveDate>,
+}
+
+/// The response of the `views` methods.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct ViewResponse {
+    #[serde(default)]
+    pub ok: bool,
+    #[serde(default)]
+    pub view: PublishedView,
+}
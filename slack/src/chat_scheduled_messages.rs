@@ -57,4 +57,84 @@ impl ChatScheduledMessages {
 
         self.client.get(&url, None).await
     }
+
+    /**
+     * This function performs a `GET` to the `/chat.scheduledMessages.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Returns a list of scheduled messages.
+     *
+     * FROM: <https://api.slack.com/methods/chat.scheduledMessages.list>
+     */
+    pub async fn list_all(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+    ) -> Result<Vec<crate::types::ScheduledMessages>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/chat.scheduledMessages.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ScheduledMessages> =
+                crate::utils::cursor_items(&mut page, "scheduled_messages")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/chat.scheduledMessages.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Returns a list of scheduled messages.
+     *
+     * FROM: <https://api.slack.com/methods/chat.scheduledMessages.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+    ) -> impl futures::Stream<Item = Result<crate::types::ScheduledMessages>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/chat.scheduledMessages.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ScheduledMessages> =
+                crate::utils::cursor_items(&mut page, "scheduled_messages")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
 }
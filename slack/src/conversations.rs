@@ -111,6 +111,94 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/conversations.history` endpoint.
+     *
+     * As opposed to `history`, this function returns all the pages of the request at once.
+     *
+     * Fetches a conversation's history of messages and events.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.history>
+     */
+    pub async fn history_all(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+    ) -> Result<Vec<crate::types::ObjsMessage>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if inclusive {
+            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations.history?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsMessage> =
+                crate::utils::cursor_items(&mut page, "messages")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/conversations.history` endpoint.
+     *
+     * As opposed to `history`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Fetches a conversation's history of messages and events.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.history>
+     */
+    pub fn history_all_stream(
+        &self,
+        channel: &str,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+    ) -> impl futures::Stream<Item = Result<crate::types::ObjsMessage>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if inclusive {
+            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations.history?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsMessage> =
+                crate::utils::cursor_items(&mut page, "messages")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `GET` to the `/conversations.info` endpoint.
      *
@@ -255,6 +343,78 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/conversations.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Lists all channels in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.list>
+     */
+    pub async fn list_all(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+    ) -> Result<Vec<crate::types::ObjsConversation>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_archived {
+            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+        }
+        if !types.is_empty() {
+            query_args.push(("types".to_string(), types.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsConversation> =
+                crate::utils::cursor_items(&mut page, "channels")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/conversations.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Lists all channels in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        exclude_archived: bool,
+        types: &str,
+    ) -> impl futures::Stream<Item = Result<crate::types::ObjsConversation>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_archived {
+            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+        }
+        if !types.is_empty() {
+            query_args.push(("types".to_string(), types.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsConversation> =
+                crate::utils::cursor_items(&mut page, "channels")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `POST` to the `/conversations.mark` endpoint.
      *
@@ -307,6 +467,65 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/conversations.members` endpoint.
+     *
+     * As opposed to `member`, this function returns all the pages of the request at once.
+     *
+     * Retrieve members of a conversation.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.members>
+     */
+    pub async fn members_all(&self, channel: &str) -> Result<Vec<String>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations.members?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<String> = crate::utils::cursor_items(&mut page, "members")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/conversations.members` endpoint.
+     *
+     * As opposed to `member`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Retrieve members of a conversation.
+     *
+     * FROM: <https://api.slack.com/methods/conversations.members>
+     */
+    pub fn members_all_stream(
+        &self,
+        channel: &str,
+    ) -> impl futures::Stream<Item = Result<String>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations.members?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<String> = crate::utils::cursor_items(&mut page, "members")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `POST` to the `/conversations.open` endpoint.
      *
@@ -395,6 +614,100 @@ impl Conversations {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/conversations.replies` endpoint.
+     *
+     * As opposed to `replie`, this function returns all the pages of the request at once.
+     *
+     * Retrieve a thread of messages posted to a conversation
+     *
+     * FROM: <https://api.slack.com/methods/conversations.replies>
+     */
+    pub async fn replies_all(
+        &self,
+        channel: &str,
+        ts: f64,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+    ) -> Result<Vec<serde_json::Value>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if inclusive {
+            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        if !ts.to_string().is_empty() {
+            query_args.push(("ts".to_string(), ts.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations.replies?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<serde_json::Value> = crate::utils::cursor_items(&mut page, "messages")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/conversations.replies` endpoint.
+     *
+     * As opposed to `replie`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Retrieve a thread of messages posted to a conversation
+     *
+     * FROM: <https://api.slack.com/methods/conversations.replies>
+     */
+    pub fn replies_all_stream(
+        &self,
+        channel: &str,
+        ts: f64,
+        latest: f64,
+        oldest: f64,
+        inclusive: bool,
+    ) -> impl futures::Stream<Item = Result<serde_json::Value>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !channel.is_empty() {
+            query_args.push(("channel".to_string(), channel.to_string()));
+        }
+        if inclusive {
+            query_args.push(("inclusive".to_string(), inclusive.to_string()));
+        }
+        if !latest.to_string().is_empty() {
+            query_args.push(("latest".to_string(), latest.to_string()));
+        }
+        if !oldest.to_string().is_empty() {
+            query_args.push(("oldest".to_string(), oldest.to_string()));
+        }
+        if !ts.to_string().is_empty() {
+            query_args.push(("ts".to_string(), ts.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/conversations.replies?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<serde_json::Value> = crate::utils::cursor_items(&mut page, "messages")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `POST` to the `/conversations.setPurpose` endpoint.
      *
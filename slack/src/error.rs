@@ -0,0 +1,111 @@
+use serde::Deserialize;
+
+/// An error Slack answered a request with.
+///
+/// Slack answers most errors with a `200 OK` and `"ok": false`, so every
+/// response is checked before it is deserialized. The error can be told apart
+/// from the others with `downcast_ref`:
+///
+/// ```ignore
+/// match client.chat().post_blocks(&message).await {
+///     Err(e) => match e.downcast_ref::<SlackError>() {
+///         Some(e) if e.error == "channel_not_found" => {}
+///         Some(e) if e.is_ratelimited() => tokio::time::sleep(e.retry_after.unwrap()).await,
+///         _ => return Err(e),
+///     },
+///     Ok(_) => {}
+/// }
+/// ```
+#[derive(PartialEq, Debug, Clone)]
+pub struct SlackError {
+    /// The HTTP status of the response, `200 OK` for most errors.
+    pub status: http::StatusCode,
+    /// The error code, like `channel_not_found` or `ratelimited`.
+    pub error: String,
+    /// The warnings about the request, separated by commas.
+    pub warning: String,
+    /// The details of the error, from `response_metadata.messages`.
+    pub messages: Vec<String>,
+    /// How long to wait before the next request, from the `Retry-After`
+    /// header of `ratelimited` errors.
+    pub retry_after: Option<std::time::Duration>,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    ok: bool,
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    warning: String,
+    #[serde(default)]
+    response_metadata: ResponseMetadata,
+}
+
+#[derive(Default, Deserialize)]
+struct ResponseMetadata {
+    #[serde(default)]
+    messages: Vec<String>,
+}
+
+impl SlackError {
+    /// Returns the error of a response, if it has `"ok": false` or Slack
+    /// rate limited the request.
+    pub fn from_response(
+        status: http::StatusCode,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Option<SlackError> {
+        let resp = serde_json::from_slice::<ErrorResponse>(body).ok();
+        let mut error = match resp {
+            Some(resp) if !resp.ok => SlackError {
+                status,
+                error: resp.error,
+                warning: resp.warning,
+                messages: resp.response_metadata.messages,
+                retry_after: None,
+            },
+            // Rate limited requests are answered with a `429 Too Many
+            // Requests`, with or without a body.
+            _ if status == http::StatusCode::TOO_MANY_REQUESTS => SlackError {
+                status,
+                error: "ratelimited".to_string(),
+                warning: String::new(),
+                messages: Vec::new(),
+                retry_after: None,
+            },
+            _ => return None,
+        };
+
+        if error.is_ratelimited() {
+            error.retry_after = headers
+                .get(http::header::RETRY_AFTER)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse().ok())
+                .map(std::time::Duration::from_secs);
+        }
+
+        Some(error)
+    }
+
+    /// Returns true if Slack rate limited the request.
+    pub fn is_ratelimited(&self) -> bool {
+        self.error == "ratelimited"
+    }
+}
+
+impl std::fmt::Display for SlackError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "slack error: {}", self.error)?;
+        if !self.messages.is_empty() {
+            write!(f, " ({})", self.messages.join(", "))?;
+        }
+        if let Some(retry_after) = self.retry_after {
+            write!(f, ", retry after {} seconds", retry_after.as_secs())?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for SlackError {}
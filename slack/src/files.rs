@@ -74,6 +74,72 @@ impl Files {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/files.info` endpoint.
+     *
+     * As opposed to `info`, this function returns all the pages of the request at once.
+     *
+     * Gets information about a file.
+     *
+     * FROM: <https://api.slack.com/methods/files.info>
+     */
+    pub async fn info_all(&self, file: &str, count: &str) -> Result<Vec<serde_json::Value>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !file.is_empty() {
+            query_args.push(("file".to_string(), file.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/files.info?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<serde_json::Value> = crate::utils::cursor_items(&mut page, "comments")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/files.info` endpoint.
+     *
+     * As opposed to `info`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Gets information about a file.
+     *
+     * FROM: <https://api.slack.com/methods/files.info>
+     */
+    pub fn info_all_stream(
+        &self,
+        file: &str,
+        count: &str,
+    ) -> impl futures::Stream<Item = Result<serde_json::Value>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if !file.is_empty() {
+            query_args.push(("file".to_string(), file.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/files.info?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<serde_json::Value> = crate::utils::cursor_items(&mut page, "comments")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `GET` to the `/files.list` endpoint.
      *
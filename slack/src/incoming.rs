@@ -0,0 +1,570 @@
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+use crate::blocks::{PublishedView, StateValue, Text};
+
+/// The header of the signature of a request from Slack.
+pub const SIGNATURE_HEADER: &str = "X-Slack-Signature";
+
+/// The header of the timestamp of a request from Slack, which is signed along
+/// with the body.
+pub const TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+
+/// How old a request can be, in seconds, before it is rejected as a replay.
+pub const MAX_REQUEST_AGE: i64 = 5 * 60;
+
+/// Verify the `v0` signature of a request from Slack, from its `signature`
+/// and `timestamp` headers and its raw `body`.
+///
+/// Requests older than `MAX_REQUEST_AGE` are rejected, so a captured request
+/// can not be replayed.
+pub fn verify_signature(
+    signing_secret: &str,
+    signature: &str,
+    timestamp: &str,
+    body: &[u8],
+) -> Result<()> {
+    verify_signature_at(
+        signing_secret,
+        signature,
+        timestamp,
+        body,
+        chrono::Utc::now(),
+    )
+}
+
+/// Verify the `v0` signature of a request from Slack, as if it was received
+/// at `now`.
+pub fn verify_signature_at(
+    signing_secret: &str,
+    signature: &str,
+    timestamp: &str,
+    body: &[u8],
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<()> {
+    let sent: i64 = timestamp
+        .trim()
+        .parse()
+        .map_err(|_| anyhow::anyhow!("invalid request timestamp: {}", timestamp))?;
+    let age = now.timestamp() - sent;
+    if age.abs() > MAX_REQUEST_AGE {
+        bail!(
+            "the request was signed {} seconds from now, more than the {} seconds accepted",
+            age.abs(),
+            MAX_REQUEST_AGE
+        );
+    }
+
+    let tag = match signature.trim().strip_prefix("v0=") {
+        Some(hex) => decode_hex(hex)?,
+        None => bail!("unsupported request signature version: {}", signature),
+    };
+
+    let mut payload = format!("v0:{}:", timestamp.trim()).into_bytes();
+    payload.extend_from_slice(body);
+
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, signing_secret.as_bytes());
+    ring::hmac::verify(&key, &payload, &tag)
+        .map_err(|_| anyhow::anyhow!("invalid request signature"))
+}
+
+/// Verify the signature of a request from Slack, from its headers and its raw
+/// `body`.
+pub fn verify_request(signing_secret: &str, headers: &http::HeaderMap, body: &[u8]) -> Result<()> {
+    verify_signature(
+        signing_secret,
+        header(headers, SIGNATURE_HEADER)?,
+        header(headers, TIMESTAMP_HEADER)?,
+        body,
+    )
+}
+
+fn header<'a>(headers: &'a http::HeaderMap, name: &str) -> Result<&'a str> {
+    match headers.get(name) {
+        Some(v) => Ok(v.to_str()?),
+        None => bail!("the request has no {} header", name),
+    }
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    hex.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            std::str::from_utf8(pair)
+                .ok()
+                .filter(|p| p.len() == 2)
+                .and_then(|p| u8::from_str_radix(p, 16).ok())
+                .ok_or_else(|| anyhow::anyhow!("invalid request signature"))
+        })
+        .collect()
+}
+
+/// A request Slack sends to an app.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Incoming {
+    /// A slash command.
+    Command(SlashCommand),
+    /// An interaction with a shortcut, a message or a view.
+    Interaction(Interaction),
+    /// A request of the Events API.
+    Event(EventRequest),
+}
+
+impl Incoming {
+    /// Decode the body of a request from Slack, with the value of its
+    /// `Content-Type` header.
+    ///
+    /// The signature of the request must be verified first, see
+    /// `from_request`.
+    pub fn parse(content_type: &str, body: &[u8]) -> Result<Self> {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+
+        match mime.as_str() {
+            "application/json" => Ok(Incoming::Event(serde_json::from_slice(body)?)),
+            "application/x-www-form-urlencoded" => {
+                // Interactions are a JSON `payload` field, commands are the
+                // fields themselves.
+                let fields: Vec<(String, String)> = serde_urlencoded::from_bytes(body)?;
+                match fields.into_iter().find(|(k, _)| k == "payload") {
+                    Some((_, payload)) => {
+                        Ok(Incoming::Interaction(serde_json::from_str(&payload)?))
+                    }
+                    None => Ok(Incoming::Command(serde_urlencoded::from_bytes(body)?)),
+                }
+            }
+            _ => bail!("unexpected request content type: {}", content_type),
+        }
+    }
+
+    /// Verify the signature of a request from Slack and decode its body.
+    pub fn from_request(
+        signing_secret: &str,
+        headers: &http::HeaderMap,
+        body: &[u8],
+    ) -> Result<Self> {
+        verify_request(signing_secret, headers, body)?;
+
+        Incoming::parse(header(headers, http::header::CONTENT_TYPE.as_str())?, body)
+    }
+
+    /// Returns the challenge to respond with, for a `url_verification`
+    /// request.
+    pub fn challenge(&self) -> Option<&str> {
+        match self {
+            Incoming::Event(EventRequest::UrlVerification { challenge, .. }) => Some(challenge),
+            _ => None,
+        }
+    }
+}
+
+/// A slash command, like `/deploy api`.
+#[derive(Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct SlashCommand {
+    /// The command, with its slash.
+    #[serde(default)]
+    pub command: String,
+    /// What follows the command.
+    #[serde(default)]
+    pub text: String,
+    /// Where to post up to 5 responses, in the 30 minutes after the command.
+    #[serde(default)]
+    pub response_url: String,
+    /// Opens a modal, in the 3 seconds after the command.
+    #[serde(default)]
+    pub trigger_id: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub user_name: String,
+    #[serde(default)]
+    pub team_id: String,
+    #[serde(default)]
+    pub team_domain: String,
+    #[serde(default)]
+    pub enterprise_id: String,
+    #[serde(default)]
+    pub enterprise_name: String,
+    #[serde(default)]
+    pub channel_id: String,
+    #[serde(default)]
+    pub channel_name: String,
+    #[serde(default)]
+    pub api_app_id: String,
+    /// The deprecated verification token.
+    #[serde(default)]
+    pub token: String,
+}
+
+/// An interaction, from the `payload` field of the request.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Interaction {
+    BlockActions(BlockActions),
+    ViewSubmission(ViewSubmission),
+    ViewClosed(ViewClosed),
+    /// A global shortcut.
+    Shortcut(Shortcut),
+    /// A message shortcut.
+    MessageAction(MessageAction),
+    /// An interaction of another type, as is.
+    Other(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for Interaction {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        Ok(match value.get("type").and_then(|t| t.as_str()) {
+            Some("block_actions") => Interaction::BlockActions(typed(value)?),
+            Some("view_submission") => Interaction::ViewSubmission(typed(value)?),
+            Some("view_closed") => Interaction::ViewClosed(typed(value)?),
+            Some("shortcut") => Interaction::Shortcut(typed(value)?),
+            Some("message_action") => Interaction::MessageAction(typed(value)?),
+            _ => Interaction::Other(value),
+        })
+    }
+}
+
+/// The user who interacted.
+#[derive(Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct InteractionUser {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub team_id: String,
+}
+
+#[derive(Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct InteractionTeam {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub domain: String,
+}
+
+#[derive(Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct InteractionChannel {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub name: String,
+}
+
+/// An interaction with the elements of a message or a view.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct BlockActions {
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub team: Option<InteractionTeam>,
+    #[serde(default)]
+    pub api_app_id: String,
+    #[serde(default)]
+    pub trigger_id: String,
+    /// Where to respond to the message, for interactions with a message.
+    #[serde(default)]
+    pub response_url: String,
+    /// What the elements are in: a message or a view.
+    #[serde(default)]
+    pub container: serde_json::Value,
+    #[serde(default)]
+    pub channel: Option<InteractionChannel>,
+    #[serde(default)]
+    pub message: Option<crate::types::ObjsMessage>,
+    #[serde(default)]
+    pub view: Option<PublishedView>,
+    /// The elements that were interacted with.
+    #[serde(default)]
+    pub actions: Vec<Action>,
+}
+
+/// An interaction with an element.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct Action {
+    #[serde(default)]
+    pub action_id: String,
+    #[serde(default)]
+    pub block_id: String,
+    #[serde(default)]
+    pub action_ts: String,
+    /// The text of a button.
+    #[serde(default)]
+    pub text: Option<Text>,
+    /// The type and the value of the element.
+    #[serde(flatten)]
+    pub state: StateValue,
+}
+
+/// A modal was submitted.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct ViewSubmission {
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub team: Option<InteractionTeam>,
+    #[serde(default)]
+    pub api_app_id: String,
+    #[serde(default)]
+    pub trigger_id: String,
+    /// The submitted view, with the values of its inputs in `state`.
+    pub view: PublishedView,
+    #[serde(default)]
+    pub response_urls: Vec<ResponseUrl>,
+}
+
+/// Where to respond in a conversation picked in a modal.
+#[derive(Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct ResponseUrl {
+    #[serde(default)]
+    pub block_id: String,
+    #[serde(default)]
+    pub action_id: String,
+    #[serde(default)]
+    pub channel_id: String,
+    #[serde(default)]
+    pub response_url: String,
+}
+
+/// A modal with `notify_on_close` was closed.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct ViewClosed {
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub team: Option<InteractionTeam>,
+    #[serde(default)]
+    pub api_app_id: String,
+    pub view: PublishedView,
+    /// Whether every view of the stack was closed.
+    #[serde(default)]
+    pub is_cleared: bool,
+}
+
+/// A global shortcut was used.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct Shortcut {
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub team: Option<InteractionTeam>,
+    #[serde(default)]
+    pub api_app_id: String,
+    #[serde(default)]
+    pub trigger_id: String,
+    #[serde(default)]
+    pub callback_id: String,
+    #[serde(default)]
+    pub action_ts: String,
+}
+
+/// A message shortcut was used on a message.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct MessageAction {
+    pub user: InteractionUser,
+    #[serde(default)]
+    pub team: Option<InteractionTeam>,
+    #[serde(default)]
+    pub api_app_id: String,
+    #[serde(default)]
+    pub trigger_id: String,
+    #[serde(default)]
+    pub callback_id: String,
+    #[serde(default)]
+    pub action_ts: String,
+    #[serde(default)]
+    pub response_url: String,
+    #[serde(default)]
+    pub channel: Option<InteractionChannel>,
+    pub message: crate::types::ObjsMessage,
+    #[serde(default)]
+    pub message_ts: String,
+}
+
+/// A request of the Events API.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventRequest {
+    /// Sent when the request URL is configured, respond with the challenge.
+    UrlVerification {
+        challenge: String,
+        #[serde(default)]
+        token: String,
+    },
+    EventCallback(EventCallback),
+    /// Sent when events are dropped because the app received too many.
+    AppRateLimited {
+        #[serde(default)]
+        team_id: String,
+        #[serde(default)]
+        api_app_id: String,
+        /// The minute events were dropped from, as a timestamp.
+        #[serde(default)]
+        minute_rate_limited: i64,
+    },
+}
+
+/// An event, with the workspace and the app it was sent for.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct EventCallback {
+    #[serde(default)]
+    pub team_id: String,
+    #[serde(default)]
+    pub api_app_id: String,
+    pub event: Event,
+    /// The unique ID of the event, to handle retries only once.
+    #[serde(default)]
+    pub event_id: String,
+    #[serde(default)]
+    pub event_time: i64,
+    #[serde(default)]
+    pub authorizations: Vec<Authorization>,
+    #[serde(default)]
+    pub is_ext_shared_channel: bool,
+    #[serde(default)]
+    pub event_context: String,
+    /// The deprecated verification token.
+    #[serde(default)]
+    pub token: String,
+}
+
+/// An installation of the app an event is visible to.
+#[derive(Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct Authorization {
+    #[serde(default)]
+    pub enterprise_id: Option<String>,
+    #[serde(default)]
+    pub team_id: String,
+    #[serde(default)]
+    pub user_id: String,
+    #[serde(default)]
+    pub is_bot: bool,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+}
+
+/// An event of the Events API.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Event {
+    Message(MessageEvent),
+    AppMention(MessageEvent),
+    AppHomeOpened(AppHomeOpened),
+    ReactionAdded(ReactionEvent),
+    ReactionRemoved(ReactionEvent),
+    MemberJoinedChannel(MemberEvent),
+    MemberLeftChannel(MemberEvent),
+    /// An event of another type, as is.
+    Other(serde_json::Value),
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        Ok(match value.get("type").and_then(|t| t.as_str()) {
+            Some("message") => Event::Message(typed(value)?),
+            Some("app_mention") => Event::AppMention(typed(value)?),
+            Some("app_home_opened") => Event::AppHomeOpened(typed(value)?),
+            Some("reaction_added") => Event::ReactionAdded(typed(value)?),
+            Some("reaction_removed") => Event::ReactionRemoved(typed(value)?),
+            Some("member_joined_channel") => Event::MemberJoinedChannel(typed(value)?),
+            Some("member_left_channel") => Event::MemberLeftChannel(typed(value)?),
+            _ => Event::Other(value),
+        })
+    }
+}
+
+/// A message posted in a conversation, or mentioning the app.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct MessageEvent {
+    #[serde(default)]
+    pub channel: String,
+    /// The type of the conversation: `channel`, `group`, `im` or `mpim`.
+    #[serde(default)]
+    pub channel_type: String,
+    #[serde(default)]
+    pub event_ts: String,
+    #[serde(flatten)]
+    pub message: crate::types::ObjsMessage,
+}
+
+/// A user opened the App Home of the app.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct AppHomeOpened {
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub channel: String,
+    /// `home` or `messages`.
+    #[serde(default)]
+    pub tab: String,
+    #[serde(default)]
+    pub event_ts: String,
+    /// The published App Home view, if any.
+    #[serde(default)]
+    pub view: Option<PublishedView>,
+}
+
+/// A reaction was added to, or removed from, an item.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct ReactionEvent {
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub reaction: String,
+    /// The author of the item.
+    #[serde(default)]
+    pub item_user: String,
+    pub item: ReactionItem,
+    #[serde(default)]
+    pub event_ts: String,
+}
+
+/// The item of a `ReactionEvent`.
+#[derive(Deserialize, PartialEq, Debug, Clone, Default)]
+pub struct ReactionItem {
+    /// `message`, `file` or `file_comment`.
+    #[serde(default, rename = "type")]
+    pub type_: String,
+    #[serde(default)]
+    pub channel: String,
+    #[serde(default)]
+    pub ts: String,
+    #[serde(default)]
+    pub file: String,
+    #[serde(default)]
+    pub file_comment: String,
+}
+
+/// A user joined, or left, a conversation.
+#[derive(Deserialize, PartialEq, Debug, Clone)]
+pub struct MemberEvent {
+    #[serde(default)]
+    pub user: String,
+    #[serde(default)]
+    pub channel: String,
+    #[serde(default)]
+    pub channel_type: String,
+    #[serde(default)]
+    pub team: String,
+    /// Who added the user, if they did not join by themselves.
+    #[serde(default)]
+    pub inviter: String,
+    #[serde(default)]
+    pub event_ts: String,
+}
+
+fn typed<T, E>(value: serde_json::Value) -> std::result::Result<T, E>
+where
+    T: serde::de::DeserializeOwned,
+    E: serde::de::Error,
+{
+    serde_json::from_value(value).map_err(E::custom)
+}
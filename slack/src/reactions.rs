@@ -125,6 +125,84 @@ impl Reactions {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/reactions.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Lists reactions made by a user.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.list>
+     */
+    pub async fn list_all(
+        &self,
+        user: &str,
+        full: bool,
+        count: i64,
+    ) -> Result<Vec<serde_json::Value>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if full {
+            query_args.push(("full".to_string(), full.to_string()));
+        }
+        if !user.is_empty() {
+            query_args.push(("user".to_string(), user.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/reactions.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<serde_json::Value> = crate::utils::cursor_items(&mut page, "items")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/reactions.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Lists reactions made by a user.
+     *
+     * FROM: <https://api.slack.com/methods/reactions.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        user: &str,
+        full: bool,
+        count: i64,
+    ) -> impl futures::Stream<Item = Result<serde_json::Value>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if count > 0 {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        if full {
+            query_args.push(("full".to_string(), full.to_string()));
+        }
+        if !user.is_empty() {
+            query_args.push(("user".to_string(), user.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/reactions.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<serde_json::Value> = crate::utils::cursor_items(&mut page, "items")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `POST` to the `/reactions.remove` endpoint.
      *
@@ -0,0 +1,292 @@
+use anyhow::{anyhow, Result};
+use futures::{SinkExt, Stream, StreamExt};
+use serde::Deserialize;
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::incoming::{EventRequest, Incoming};
+
+type WebSocket =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
+/// Receives the events and the interactions of an app over a WebSocket,
+/// instead of on a public request URL.
+///
+/// The `Client` must use an app-level token, with the `connections:write`
+/// scope.
+///
+/// ```ignore
+/// let client = Client::new("", "", "", app_token, "");
+/// let mut envelopes = Box::pin(SocketMode::new(client).stream());
+/// while let Some(envelope) = envelopes.next().await {
+///     let envelope = match envelope {
+///         Ok(envelope) => envelope,
+///         Err(e) => {
+///             println!("{}", e);
+///             continue;
+///         }
+///     };
+///     envelope.ack()?;
+///     match envelope.payload {
+///         Incoming::Command(c) => println!("{} {}", c.command, c.text),
+///         _ => {}
+///     }
+/// }
+/// ```
+pub struct SocketMode {
+    client: crate::Client,
+    min_backoff: std::time::Duration,
+    max_backoff: std::time::Duration,
+}
+
+/// A request Slack sent over a Socket Mode connection.
+///
+/// It has to be acknowledged with `ack` or `ack_with` within 3 seconds, or
+/// Slack sends it again.
+#[derive(Debug, Clone)]
+pub struct Envelope {
+    /// The ID the envelope is acknowledged with.
+    pub envelope_id: String,
+    pub payload: Incoming,
+    /// Whether Slack reads a response payload from the acknowledgement, see
+    /// `ack_with`.
+    pub accepts_response_payload: bool,
+    /// How many times Slack already sent the envelope, without an
+    /// acknowledgement in time.
+    pub retry_attempt: i64,
+    pub retry_reason: String,
+    acks: tokio::sync::mpsc::UnboundedSender<Message>,
+}
+
+impl Envelope {
+    /// Acknowledge the envelope.
+    pub fn ack(&self) -> Result<()> {
+        self.send_ack(serde_json::json!({ "envelope_id": self.envelope_id }))
+    }
+
+    /// Acknowledge the envelope with a response, for the envelopes that
+    /// accept one: for example a `response_action` for a view submission, or
+    /// the message a slash command replies with.
+    pub fn ack_with<T: serde::Serialize>(&self, payload: &T) -> Result<()> {
+        self.send_ack(serde_json::json!({
+            "envelope_id": self.envelope_id,
+            "payload": payload,
+        }))
+    }
+
+    fn send_ack(&self, ack: serde_json::Value) -> Result<()> {
+        self.acks
+            .send(Message::Text(ack.to_string()))
+            .map_err(|_| anyhow!("the Socket Mode connection of the envelope is closed"))
+    }
+}
+
+#[derive(Deserialize)]
+struct ConnectionsOpen {
+    #[serde(default)]
+    url: String,
+}
+
+#[derive(Deserialize)]
+struct RawEnvelope {
+    #[serde(rename = "type")]
+    type_: String,
+    #[serde(default)]
+    envelope_id: String,
+    #[serde(default)]
+    payload: serde_json::Value,
+    #[serde(default)]
+    accepts_response_payload: bool,
+    #[serde(default)]
+    retry_attempt: i64,
+    #[serde(default)]
+    retry_reason: String,
+    /// Why the connection is about to be closed, in `disconnect` messages.
+    #[serde(default)]
+    reason: String,
+}
+
+impl SocketMode {
+    pub fn new(client: crate::Client) -> Self {
+        SocketMode {
+            client,
+            min_backoff: std::time::Duration::from_secs(1),
+            max_backoff: std::time::Duration::from_secs(60),
+        }
+    }
+
+    /// Set how long to wait before connecting again, after a connection could
+    /// not be opened or was closed before Slack said hello. The wait starts
+    /// at `min` and doubles with each failure, up to `max`.
+    pub fn with_backoff(mut self, min: std::time::Duration, max: std::time::Duration) -> Self {
+        self.min_backoff = min;
+        self.max_backoff = max.max(min);
+        self
+    }
+
+    /// Returns the URL of a new connection, from `apps.connections.open`.
+    pub async fn open(&self) -> Result<String> {
+        let open: ConnectionsOpen = self.client.post("/apps.connections.open", None).await?;
+
+        Ok(open.url)
+    }
+
+    /// Connect, and stream the envelopes Slack sends.
+    ///
+    /// When Slack asks to, or when the connection drops, a new connection is
+    /// opened. The stream does not end: the errors to open a connection are
+    /// returned, and it is tried again after a backoff.
+    ///
+    /// The envelopes that can not be read are acknowledged, so that Slack
+    /// does not send them again, and returned as errors.
+    pub fn stream(self) -> impl Stream<Item = Result<Envelope>> + Send {
+        futures::stream::unfold(
+            (self, None::<Connection>, std::time::Duration::ZERO),
+            |(mode, mut connection, mut wait)| async move {
+                loop {
+                    let c = match connection.as_mut() {
+                        Some(c) => c,
+                        None => {
+                            tokio::time::sleep(wait).await;
+                            match mode.connect().await {
+                                Ok(c) => connection.insert(c),
+                                Err(e) => {
+                                    let wait = mode.backoff(wait);
+                                    log::warn!(
+                                        "could not open a Socket Mode connection, retrying in {:?}: {}",
+                                        wait,
+                                        e
+                                    );
+                                    return Some((Err(e), (mode, None, wait)));
+                                }
+                            }
+                        }
+                    };
+
+                    match c.receive().await {
+                        Some(envelope) => return Some((envelope, (mode, connection, wait))),
+                        None => {
+                            // Only reconnect at once if the connection worked.
+                            wait = if c.hello {
+                                std::time::Duration::ZERO
+                            } else {
+                                mode.backoff(wait)
+                            };
+                            connection = None;
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn backoff(&self, wait: std::time::Duration) -> std::time::Duration {
+        if wait.is_zero() {
+            self.min_backoff
+        } else {
+            (wait * 2).min(self.max_backoff)
+        }
+    }
+
+    async fn connect(&self) -> Result<Connection> {
+        let url = self.open().await?;
+        let (ws, _) = tokio_tungstenite::connect_async(url.as_str()).await?;
+        let (mut sink, messages) = ws.split();
+
+        // The envelopes are acknowledged from wherever the caller handles
+        // them, through a channel to the connection, until it is dropped.
+        let (acks, mut outgoing) = tokio::sync::mpsc::unbounded_channel();
+        let (dropped, mut closed) = tokio::sync::oneshot::channel::<()>();
+        tokio::spawn(async move {
+            loop {
+                let message = tokio::select! {
+                    biased;
+                    Some(message) = outgoing.recv() => message,
+                    _ = &mut closed => break,
+                };
+                if let Err(e) = sink.send(message).await {
+                    log::warn!("could not write to the Socket Mode connection: {}", e);
+                    break;
+                }
+            }
+        });
+
+        Ok(Connection {
+            messages,
+            acks,
+            hello: false,
+            _dropped: dropped,
+        })
+    }
+}
+
+struct Connection {
+    messages: futures::stream::SplitStream<WebSocket>,
+    acks: tokio::sync::mpsc::UnboundedSender<Message>,
+    /// Whether Slack said hello, once the connection was ready.
+    hello: bool,
+    /// Stops writing to the connection once it is dropped.
+    _dropped: tokio::sync::oneshot::Sender<()>,
+}
+
+impl Connection {
+    /// Returns the next envelope of the connection, or `None` if the
+    /// connection is closed.
+    async fn receive(&mut self) -> Option<Result<Envelope>> {
+        loop {
+            let text = match self.messages.next().await {
+                Some(Ok(Message::Text(text))) => text,
+                Some(Ok(Message::Close(_))) | None => {
+                    log::debug!("the Socket Mode connection was closed, reconnecting");
+                    return None;
+                }
+                Some(Ok(_)) => continue,
+                Some(Err(e)) => {
+                    log::warn!("the Socket Mode connection failed, reconnecting: {}", e);
+                    return None;
+                }
+            };
+
+            let raw: RawEnvelope = match serde_json::from_str(&text) {
+                Ok(raw) => raw,
+                Err(e) => return Some(Err(e.into())),
+            };
+
+            let payload = match raw.type_.as_str() {
+                "events_api" => {
+                    serde_json::from_value::<EventRequest>(raw.payload).map(Incoming::Event)
+                }
+                "interactive" => serde_json::from_value(raw.payload).map(Incoming::Interaction),
+                "slash_commands" => serde_json::from_value(raw.payload).map(Incoming::Command),
+                "hello" => {
+                    self.hello = true;
+                    continue;
+                }
+                "disconnect" => {
+                    log::debug!("Slack asked to reconnect: {}", raw.reason);
+                    let _ = self.acks.send(Message::Close(None));
+                    return None;
+                }
+                _ => continue,
+            };
+
+            let payload = match payload {
+                Ok(payload) => payload,
+                Err(e) => {
+                    // Slack would only send it again.
+                    let ack = serde_json::json!({ "envelope_id": raw.envelope_id });
+                    let _ = self.acks.send(Message::Text(ack.to_string()));
+                    return Some(Err(e.into()));
+                }
+            };
+
+            return Some(Ok(Envelope {
+                envelope_id: raw.envelope_id,
+                payload,
+                accepts_response_payload: raw.accepts_response_payload,
+                retry_attempt: raw.retry_attempt,
+                retry_reason: raw.retry_reason,
+                acks: self.acks.clone(),
+            }));
+        }
+    }
+}
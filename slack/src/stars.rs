@@ -69,6 +69,65 @@ impl Stars {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/stars.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Lists stars for a user.
+     *
+     * FROM: <https://api.slack.com/methods/stars.list>
+     */
+    pub async fn list_all(&self, count: &str) -> Result<Vec<serde_json::Value>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/stars.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<serde_json::Value> = crate::utils::cursor_items(&mut page, "items")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/stars.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Lists stars for a user.
+     *
+     * FROM: <https://api.slack.com/methods/stars.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        count: &str,
+    ) -> impl futures::Stream<Item = Result<serde_json::Value>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if !count.is_empty() {
+            query_args.push(("count".to_string(), count.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/stars.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<serde_json::Value> = crate::utils::cursor_items(&mut page, "items")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `POST` to the `/stars.remove` endpoint.
      *
@@ -1 +1,1206 @@
+use wiremock::{
+    matchers::{method, path, path_regex, query_param, query_param_is_missing},
+    Mock, MockServer, ResponseTemplate,
+};
 
+use crate::{
+    blocks::{
+        Actions, Button, ButtonStyle, Confirm, Context, Datepicker, Divider, Header, Input,
+        Message, PlainTextInput, Section, SelectOption, StaticSelect, Text, View,
+    },
+    error::SlackError,
+    traits::{ChatOps, FileShare, FilesOps, UploadFile, ViewsOps},
+};
+
+fn client(host: String) -> crate::Client {
+    crate::Client::new("", "", "", "xoxb-token", "").with_host(host)
+}
+
+#[test]
+fn test_message_blocks() {
+    let message = Message::new("C1", "Deploy finished")
+        .with_thread_ts("1.2")
+        .add_block(Header::new("Deploy finished"))
+        .add_block(
+            Section::new(Text::mrkdwn("*api* is now at `v1.2.3`"))
+                .with_block_id("summary")
+                .add_field(Text::plain("Took 3m"))
+                .with_accessory(Button::new("Logs", "logs").with_url("https://example.com")),
+        )
+        .add_block(Divider::new())
+        .add_block(
+            Actions::new().add_element(
+                Button::new("Roll back", "rollback")
+                    .with_value("v1.2.2")
+                    .with_style(ButtonStyle::Danger)
+                    .with_confirm(Confirm::new("Sure?", "This rolls back api.", "Yes", "No")),
+            ),
+        )
+        .add_block(
+            Context::new()
+                .add_text(Text::mrkdwn("by <@U1>"))
+                .add_image("https://example.com/a.png", "avatar"),
+        );
+    message.validate().unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&message).unwrap(),
+        serde_json::json!({
+            "channel": "C1",
+            "text": "Deploy finished",
+            "thread_ts": "1.2",
+            "blocks": [
+                { "type": "header", "text": { "type": "plain_text", "text": "Deploy finished" } },
+                {
+                    "type": "section",
+                    "block_id": "summary",
+                    "text": { "type": "mrkdwn", "text": "*api* is now at `v1.2.3`" },
+                    "fields": [{ "type": "plain_text", "text": "Took 3m" }],
+                    "accessory": {
+                        "type": "button",
+                        "text": { "type": "plain_text", "text": "Logs" },
+                        "action_id": "logs",
+                        "url": "https://example.com",
+                    },
+                },
+                { "type": "divider" },
+                {
+                    "type": "actions",
+                    "elements": [{
+                        "type": "button",
+                        "text": { "type": "plain_text", "text": "Roll back" },
+                        "action_id": "rollback",
+                        "value": "v1.2.2",
+                        "style": "danger",
+                        "confirm": {
+                            "title": { "type": "plain_text", "text": "Sure?" },
+                            "text": { "type": "plain_text", "text": "This rolls back api." },
+                            "confirm": { "type": "plain_text", "text": "Yes" },
+                            "deny": { "type": "plain_text", "text": "No" },
+                        },
+                    }],
+                },
+                {
+                    "type": "context",
+                    "elements": [
+                        { "type": "mrkdwn", "text": "by <@U1>" },
+                        { "type": "image", "image_url": "https://example.com/a.png", "alt_text": "avatar" },
+                    ],
+                },
+            ],
+        })
+    );
+
+    // The blocks read back the same.
+    let json = serde_json::to_string(&message).unwrap();
+    assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+}
+
+#[test]
+fn test_message_limits() {
+    let mut message = Message::new("C1", "");
+    assert!(message.validate().is_err());
+
+    for i in 0..51 {
+        message = message.add_block(Section::new(Text::plain(&i.to_string())));
+    }
+    let err = message.validate().unwrap_err().to_string();
+    assert!(err.contains("51 blocks, more than the 50"), "{}", err);
+
+    let message = Message::new("C1", "").add_block(Header::new(&"a".repeat(151)));
+    let err = message.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("the text of a header is 151 characters"),
+        "{}",
+        err
+    );
+
+    let message = Message::new("C1", "").add_block(Header {
+        block_id: String::new(),
+        text: Text::mrkdwn("*bold*"),
+    });
+    assert!(message.validate().is_err());
+
+    let message = Message::new("C1", "")
+        .add_block(Divider::new().with_block_id("a"))
+        .add_block(Divider::new().with_block_id("a"));
+    assert!(message.validate().is_err());
+
+    let message = Message::new("C1", "").add_block(Input::new("Name", PlainTextInput::new("name")));
+    assert!(message.validate().is_err());
+
+    let mut actions = Actions::new();
+    for i in 0..26 {
+        actions = actions.add_element(Button::new("Go", &i.to_string()));
+    }
+    assert!(Message::new("C1", "")
+        .add_block(actions)
+        .validate()
+        .is_err());
+
+    // Lengths are counted in characters, not bytes.
+    let message = Message::new("C1", "").add_block(Header::new(&"é".repeat(150)));
+    message.validate().unwrap();
+
+    // Each block only takes some kinds of elements.
+    let err = Message::new("C1", "")
+        .add_block(Actions::new().add_element(PlainTextInput::new("name")))
+        .validate()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("a plain_text_input can not be in an actions block"),
+        "{}",
+        err
+    );
+    let err = Message::new("C1", "")
+        .add_block(Section::new(Text::plain("Name")).with_accessory(PlainTextInput::new("name")))
+        .validate()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("a plain_text_input can not be the accessory of a section"),
+        "{}",
+        err
+    );
+    let err = View::modal("Deploy")
+        .with_submit("Go")
+        .add_block(Input::new("Deploy", Button::new("Go", "go")))
+        .validate()
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("a button can not be the element of an input"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_modal() {
+    let deadline = chrono::NaiveDate::from_ymd(2022, 1, 31);
+    let view = View::modal("Request time off")
+        .with_submit("Send")
+        .with_callback_id("time_off")
+        .with_private_metadata("C1")
+        .add_block(
+            Input::new(
+                "First day",
+                Datepicker::new("start").with_initial_date(deadline),
+            )
+            .with_block_id("start"),
+        )
+        .add_block(
+            Input::new(
+                "Kind",
+                StaticSelect::new("Pick one", "kind")
+                    .add_option(SelectOption::new("Vacation", "vacation"))
+                    .add_option(SelectOption::new("Sick", "sick")),
+            )
+            .with_block_id("kind")
+            .with_optional(true),
+        );
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&view.to_json().unwrap()).unwrap(),
+        serde_json::json!({
+            "type": "modal",
+            "title": { "type": "plain_text", "text": "Request time off" },
+            "submit": { "type": "plain_text", "text": "Send" },
+            "callback_id": "time_off",
+            "private_metadata": "C1",
+            "blocks": [
+                {
+                    "type": "input",
+                    "block_id": "start",
+                    "label": { "type": "plain_text", "text": "First day" },
+                    "element": { "type": "datepicker", "action_id": "start", "initial_date": "2022-01-31" },
+                },
+                {
+                    "type": "input",
+                    "block_id": "kind",
+                    "label": { "type": "plain_text", "text": "Kind" },
+                    "optional": true,
+                    "element": {
+                        "type": "static_select",
+                        "action_id": "kind",
+                        "placeholder": { "type": "plain_text", "text": "Pick one" },
+                        "options": [
+                            { "text": { "type": "plain_text", "text": "Vacation" }, "value": "vacation" },
+                            { "text": { "type": "plain_text", "text": "Sick" }, "value": "sick" },
+                        ],
+                    },
+                },
+            ],
+        })
+    );
+
+    // A modal with inputs can not be submitted without a submit button.
+    let mut no_submit = view.clone();
+    no_submit.submit = None;
+    assert!(no_submit.to_json().is_err());
+
+    let long_title = View::modal(&"a".repeat(25));
+    assert!(long_title.validate().is_err());
+
+    let bad_initial = View::modal("Pick").with_submit("Go").add_block(Input::new(
+        "Kind",
+        StaticSelect::new("Pick one", "kind")
+            .add_option(SelectOption::new("Vacation", "vacation"))
+            .with_initial_option(SelectOption::new("Sick", "sick")),
+    ));
+    assert!(bad_initial.validate().is_err());
+
+    View::home()
+        .add_block(Section::new(Text::mrkdwn("Welcome home")))
+        .validate()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_post_blocks() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "channel": "C1",
+            "ts": "1.5",
+            "message": { "text": "Hi", "type": "message" },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/views.open"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "view": { "id": "V1", "hash": "h1", "callback_id": "time_off" },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(server.uri());
+
+    let message = Message::new("C1", "Hi").add_block(Section::new(Text::mrkdwn("*Hi*")));
+    let posted = client.chat().post_blocks(&message).await.unwrap();
+    assert_eq!(posted.ts, "1.5");
+
+    let view = View::modal("Time off").with_callback_id("time_off");
+    let opened = client.views().open_view("T1", &view).await.unwrap();
+    assert_eq!(opened.view.id, "V1");
+    assert_eq!(opened.view.hash, "h1");
+
+    // Home views can not be opened as modals.
+    assert!(client.views().open_view("T1", &View::home()).await.is_err());
+
+    let sent = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|r| serde_json::from_slice::<serde_json::Value>(&r.body).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(sent[0]["blocks"][0]["text"]["text"], "*Hi*");
+    assert_eq!(sent[1]["trigger_id"], "T1");
+    assert_eq!(sent[1]["view"]["callback_id"], "time_off");
+}
+
+#[tokio::test]
+async fn test_slack_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/chat.postMessage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": false,
+            "error": "invalid_blocks",
+            "warning": "missing_charset",
+            "response_metadata": {
+                "messages": ["[ERROR] unsupported type: image [json-pointer:/blocks/0]"],
+            },
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(server.uri());
+
+    let message = Message::new("C1", "Hi");
+    let err = client.chat().post_blocks(&message).await.unwrap_err();
+    let err = err.downcast_ref::<SlackError>().unwrap();
+    assert_eq!(err.status, http::StatusCode::OK);
+    assert_eq!(err.error, "invalid_blocks");
+    assert_eq!(err.warning, "missing_charset");
+    assert_eq!(
+        err.messages,
+        vec!["[ERROR] unsupported type: image [json-pointer:/blocks/0]"]
+    );
+    assert_eq!(err.retry_after, None);
+    assert_eq!(
+        err.to_string(),
+        "slack error: invalid_blocks ([ERROR] unsupported type: image [json-pointer:/blocks/0])"
+    );
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert(http::header::RETRY_AFTER, "30".parse().unwrap());
+    let err = SlackError::from_response(
+        http::StatusCode::TOO_MANY_REQUESTS,
+        &headers,
+        br#"{"ok":false,"error":"ratelimited"}"#,
+    )
+    .unwrap();
+    assert!(err.is_ratelimited());
+    assert_eq!(err.retry_after, Some(std::time::Duration::from_secs(30)));
+    assert_eq!(
+        err.to_string(),
+        "slack error: ratelimited, retry after 30 seconds"
+    );
+
+    // Without a body.
+    let err =
+        SlackError::from_response(http::StatusCode::TOO_MANY_REQUESTS, &headers, b"").unwrap();
+    assert!(err.is_ratelimited());
+    assert_eq!(err.retry_after, Some(std::time::Duration::from_secs(30)));
+
+    assert_eq!(
+        SlackError::from_response(http::StatusCode::OK, &headers, br#"{"ok":true}"#),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_upload_external() {
+    let server = MockServer::start().await;
+    for (id, matcher) in [
+        ("F1", query_param("filename", "report.csv")),
+        ("F2", query_param("alt_txt", "A chart")),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/files.getUploadURLExternal"))
+            .and(matcher)
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "ok": true,
+                "upload_url": format!("{}/upload/{}", server.uri(), id),
+                "file_id": id,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path_regex("^/upload/F[12]$"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("OK - 11"))
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/files.completeUploadExternal"))
+        .respond_with(|req: &wiremock::Request| {
+            let body: serde_json::Value = serde_json::from_slice(&req.body).unwrap();
+            let files: Vec<_> = body["files"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|f| serde_json::json!({ "id": f["id"], "title": f["title"] }))
+                .collect();
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "ok": true, "files": files }))
+        })
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(server.uri());
+
+    let path = std::env::temp_dir().join(format!("slack-upload-{}.png", uuid::Uuid::new_v4()));
+    tokio::fs::write(&path, b"chart data").await.unwrap();
+    let files = vec![
+        UploadFile::from_reader(
+            "report.csv",
+            std::io::Cursor::new(b"a,b\n1,2\n3,4".to_vec()),
+            11,
+        )
+        .with_title("Weekly report"),
+        UploadFile::from_path(&path)
+            .await
+            .unwrap()
+            .with_alt_text("A chart"),
+    ];
+    let share = FileShare::channels(&["C1", "C2"]).with_initial_comment("The weekly report");
+    let completed = client.files().upload_external(files, &share).await.unwrap();
+    tokio::fs::remove_file(&path).await.unwrap();
+    assert_eq!(completed.len(), 2);
+    assert_eq!(completed[0].id, "F1");
+    assert_eq!(completed[0].title, "Weekly report");
+    assert_eq!(completed[1].id, "F2");
+
+    // A thread is in a single channel.
+    let share = FileShare {
+        channels: vec!["C1".to_string(), "C2".to_string()],
+        thread_ts: "1.5".to_string(),
+        ..Default::default()
+    };
+    let files = vec![UploadFile::from_bytes("a.txt", "a")];
+    assert!(client.files().upload_external(files, &share).await.is_err());
+
+    // Nothing is sent for an invalid share.
+    let sent = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|r| {
+            (
+                r.url[url::Position::BeforePath..].to_string(),
+                String::from_utf8_lossy(&r.body).to_string(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sent.len(), 5);
+    let filename = path.file_name().unwrap().to_str().unwrap();
+    assert_eq!(
+        sent[0].0,
+        "/files.getUploadURLExternal?filename=report.csv&length=11"
+    );
+    assert_eq!(
+        sent[1],
+        ("/upload/F1".to_string(), "a,b\n1,2\n3,4".to_string())
+    );
+    assert_eq!(
+        sent[2].0,
+        format!(
+            "/files.getUploadURLExternal?filename={}&length=10&alt_txt=A+chart",
+            filename
+        )
+    );
+    assert_eq!(sent[3].0, "/upload/F2");
+    assert_eq!(sent[3].1.len(), 10);
+    let complete: serde_json::Value = serde_json::from_str(&sent[4].1).unwrap();
+    assert_eq!(sent[4].0, "/files.completeUploadExternal");
+    assert_eq!(
+        complete,
+        serde_json::json!({
+            "files": [{ "id": "F1", "title": "Weekly report" }, { "id": "F2" }],
+            "channels": "C1,C2",
+            "initial_comment": "The weekly report",
+        })
+    );
+}
+
+/// A page of `conversations.list`, like the example of its documentation.
+fn channels_page(last: bool) -> serde_json::Value {
+    let channel = |id: &str, name: &str, topic: &str| {
+        serde_json::json!({
+            "id": id,
+            "name": name,
+            "is_channel": true,
+            "is_group": false,
+            "is_im": false,
+            "created": 1449252889,
+            "creator": "U012A3CDE",
+            "is_archived": false,
+            "is_general": name == "general",
+            "unlinked": 0,
+            "name_normalized": name,
+            "is_shared": false,
+            "is_ext_shared": false,
+            "is_org_shared": false,
+            "pending_shared": [],
+            "is_pending_ext_shared": false,
+            "is_member": true,
+            "is_private": false,
+            "is_mpim": false,
+            "updated": 1678229664302u64,
+            "parent_conversation": null,
+            "topic": { "value": topic, "creator": "", "last_set": 0 },
+            "purpose": { "value": "", "creator": "", "last_set": 0 },
+            "previous_names": [],
+            "num_members": 4,
+        })
+    };
+
+    if last {
+        serde_json::json!({
+            "ok": true,
+            "channels": [channel("C061EG9T2", "random", "Non-work banter")],
+            "response_metadata": { "next_cursor": "" },
+        })
+    } else {
+        serde_json::json!({
+            "ok": true,
+            "channels": [channel("C012AB3CD", "general", "Company-wide announcements")],
+            "response_metadata": { "next_cursor": "dGVhbTpDMDYxRkE1UEI=" },
+        })
+    }
+}
+
+/// A page of `users.list`, like the example of its documentation.
+fn users_page(last: bool) -> serde_json::Value {
+    let user = |id: &str, name: &str, real_name: &str| {
+        serde_json::json!({
+            "id": id,
+            "team_id": "T012AB3C4",
+            "name": name,
+            "deleted": false,
+            "color": "9f69e7",
+            "real_name": real_name,
+            "tz": "America/Los_Angeles",
+            "tz_label": "Pacific Daylight Time",
+            "tz_offset": -25200,
+            "profile": {
+                "avatar_hash": "ge3b51ca72de",
+                "status_text": "Print is dead",
+                "status_emoji": ":books:",
+                "real_name": real_name,
+                "display_name": name,
+                "real_name_normalized": real_name,
+                "display_name_normalized": name,
+                "email": format!("{}@ghostbusters.example.com", name),
+                "image_24": "https://.../image_24.jpg",
+                "team": "T012AB3C4",
+            },
+            "is_admin": true,
+            "is_owner": false,
+            "is_primary_owner": false,
+            "is_restricted": false,
+            "is_ultra_restricted": false,
+            "is_bot": false,
+            "updated": 1502138686,
+            "is_app_user": false,
+            "has_2fa": false,
+        })
+    };
+
+    if last {
+        serde_json::json!({
+            "ok": true,
+            "members": [user("W07QCRPA4", "glinda", "Glinda Southgood")],
+            "cache_ts": 1498777272,
+            "response_metadata": { "next_cursor": "" },
+        })
+    } else {
+        serde_json::json!({
+            "ok": true,
+            "members": [user("W012A3CDE", "spengler", "Egon Spengler")],
+            "cache_ts": 1498777272,
+            "response_metadata": { "next_cursor": "dXNlcjpXMDdRQ1JQQTQ=" },
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_cursor_pages() {
+    let server = MockServer::start().await;
+    for (page_path, cursor, first, next) in [
+        (
+            "/conversations.members",
+            "dXNlcjpVMg==",
+            serde_json::json!({
+                "ok": true,
+                "members": ["U1", "U2"],
+                "response_metadata": { "next_cursor": "dXNlcjpVMg==" },
+            }),
+            serde_json::json!({
+                "ok": true,
+                "members": ["U3"],
+                "response_metadata": { "next_cursor": "" },
+            }),
+        ),
+        (
+            "/conversations.list",
+            "dGVhbTpDMDYxRkE1UEI=",
+            channels_page(false),
+            channels_page(true),
+        ),
+        (
+            "/users.list",
+            "dXNlcjpXMDdRQ1JQQTQ=",
+            users_page(false),
+            users_page(true),
+        ),
+        (
+            "/conversations.history",
+            "bmV4dA==",
+            // Slack can return an empty page, with more to come.
+            serde_json::json!({
+                "ok": true,
+                "messages": [],
+                "has_more": true,
+                "response_metadata": { "next_cursor": "bmV4dA==" },
+            }),
+            serde_json::json!({
+                "ok": true,
+                "messages": [{ "type": "message", "text": "third", "ts": "3.0" }],
+                "has_more": false,
+            }),
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(page_path))
+            .and(query_param_is_missing("cursor"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(first))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(page_path))
+            .and(query_param("cursor", cursor))
+            .respond_with(ResponseTemplate::new(200).set_body_json(next))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+    let client = client(server.uri());
+
+    let channels = client.conversations().list_all(true, "").await.unwrap();
+    let names: Vec<&str> = channels.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, vec!["general", "random"]);
+    assert_eq!(channels[0].topic.value, "Company-wide announcements");
+
+    let users: Vec<crate::types::ObjsUser> =
+        futures::TryStreamExt::try_collect(client.users().list_all_stream(false))
+            .await
+            .unwrap();
+    let ids: Vec<&str> = users.iter().map(|u| u.id.as_str()).collect();
+    assert_eq!(ids, vec!["W012A3CDE", "W07QCRPA4"]);
+    assert_eq!(users[0].profile.real_name, "Egon Spengler");
+
+    let members = client.conversations().members_all("C1").await.unwrap();
+    assert_eq!(members, vec!["U1", "U2", "U3"]);
+
+    let messages: Vec<crate::types::ObjsMessage> = futures::TryStreamExt::try_collect(
+        client
+            .conversations()
+            .history_all_stream("C1", 0.0, 0.0, false),
+    )
+    .await
+    .unwrap();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0].text, "third");
+
+    let paths = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|r| r.url[url::Position::BeforePath..].to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            "/conversations.list?exclude_archived=true&limit=200",
+            "/conversations.list?exclude_archived=true&limit=200&cursor=dGVhbTpDMDYxRkE1UEI%3D",
+            "/users.list?limit=200",
+            "/users.list?limit=200&cursor=dXNlcjpXMDdRQ1JQQTQ%3D",
+            "/conversations.members?channel=C1&limit=200",
+            "/conversations.members?channel=C1&limit=200&cursor=dXNlcjpVMg%3D%3D",
+            "/conversations.history?channel=C1&latest=0&oldest=0&limit=200",
+            "/conversations.history?channel=C1&latest=0&oldest=0&limit=200&cursor=bmV4dA%3D%3D",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_cursor_pages_untyped_items() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/conversations.replies"))
+        .and(query_param_is_missing("cursor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "messages": [
+                {"type": "message", "text": "parent", "ts": "1.0", "reply_count": 2},
+                {"type": "message", "text": "first reply", "ts": "1.1", "thread_ts": "1.0"},
+            ],
+            "has_more": true,
+            "response_metadata": {"next_cursor": "bmV4dA=="},
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/conversations.replies"))
+        .and(query_param("cursor", "bmV4dA=="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "ok": true,
+            "messages": [{"type": "message", "text": "second reply", "ts": "1.2"}],
+            "has_more": false,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(server.uri());
+
+    let messages = client
+        .conversations()
+        .replies_all("C1", 1.0, 0.0, 0.0, false)
+        .await
+        .unwrap();
+    let texts: Vec<&str> = messages.iter().filter_map(|m| m["text"].as_str()).collect();
+    assert_eq!(texts, vec!["parent", "first reply", "second reply"]);
+    assert_eq!(messages[0]["reply_count"], 2);
+}
+
+// The example of https://api.slack.com/authentication/verifying-requests-from-slack.
+const SIGNING_SECRET: &str = "8f742231b10e8888abcd99yyyzzz85a5";
+const SIGNED_TIMESTAMP: &str = "1531420618";
+const SIGNED_BODY: &str = "token=xyzz0WbapA4vBCDEFasx0q6G&team_id=T1DC2JH3J&\
+                           team_domain=testteamnow&channel_id=G8PSS9T3V&channel_name=foobar&\
+                           user_id=U2CERLKJA&user_name=roadrunner&command=%2Fwebhook-collect&\
+                           text=&response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%\
+                           2FT1DC2JH3J%2F397700885554%2F96rGlfmibIGlgcZRskXaIFfN&\
+                           trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+const SIGNATURE: &str = "v0=a2114d57b48eac39b9ad189dd8316235a7b4a8d21a10bd27519666489c69b503";
+
+#[test]
+fn test_verify_signature() {
+    use crate::incoming::verify_signature_at;
+
+    let signed_at = chrono::DateTime::parse_from_rfc3339("2018-07-12T18:36:58Z")
+        .unwrap()
+        .with_timezone(&chrono::Utc);
+    let now = signed_at + chrono::Duration::seconds(30);
+    verify_signature_at(
+        SIGNING_SECRET,
+        SIGNATURE,
+        SIGNED_TIMESTAMP,
+        SIGNED_BODY.as_bytes(),
+        now,
+    )
+    .unwrap();
+
+    // A changed body.
+    let body = SIGNED_BODY.replace("roadrunner", "coyote");
+    assert!(verify_signature_at(
+        SIGNING_SECRET,
+        SIGNATURE,
+        SIGNED_TIMESTAMP,
+        body.as_bytes(),
+        now
+    )
+    .is_err());
+
+    // Another secret.
+    assert!(verify_signature_at(
+        "secret",
+        SIGNATURE,
+        SIGNED_TIMESTAMP,
+        SIGNED_BODY.as_bytes(),
+        now
+    )
+    .is_err());
+
+    // A replayed request.
+    let err = verify_signature_at(
+        SIGNING_SECRET,
+        SIGNATURE,
+        SIGNED_TIMESTAMP,
+        SIGNED_BODY.as_bytes(),
+        signed_at + chrono::Duration::minutes(6),
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("360 seconds"), "{}", err);
+
+    // Another version.
+    assert!(verify_signature_at(
+        SIGNING_SECRET,
+        &SIGNATURE.replace("v0=", "v1="),
+        SIGNED_TIMESTAMP,
+        SIGNED_BODY.as_bytes(),
+        now
+    )
+    .is_err());
+}
+
+#[test]
+fn test_incoming_from_request() {
+    use crate::incoming::{Incoming, SIGNATURE_HEADER, TIMESTAMP_HEADER};
+
+    // A fresh request, signed like Slack does.
+    let timestamp = chrono::Utc::now().timestamp().to_string();
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, SIGNING_SECRET.as_bytes());
+    let tag = ring::hmac::sign(&key, format!("v0:{}:{}", timestamp, SIGNED_BODY).as_bytes());
+    let signature: String = tag.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+
+    let mut headers = http::HeaderMap::new();
+    headers.insert(
+        http::header::CONTENT_TYPE,
+        "application/x-www-form-urlencoded".parse().unwrap(),
+    );
+    headers.insert(TIMESTAMP_HEADER, timestamp.parse().unwrap());
+    headers.insert(
+        SIGNATURE_HEADER,
+        format!("v0={}", signature).parse().unwrap(),
+    );
+
+    let command =
+        match Incoming::from_request(SIGNING_SECRET, &headers, SIGNED_BODY.as_bytes()).unwrap() {
+            Incoming::Command(c) => c,
+            i => panic!("expected a command, got {:?}", i),
+        };
+    assert_eq!(command.command, "/webhook-collect");
+    assert_eq!(command.text, "");
+    assert_eq!(command.user_id, "U2CERLKJA");
+    assert_eq!(command.channel_name, "foobar");
+    assert_eq!(
+        command.response_url,
+        "https://hooks.slack.com/commands/T1DC2JH3J/397700885554/96rGlfmibIGlgcZRskXaIFfN"
+    );
+
+    // The signature of the example is too old by now.
+    headers.insert(TIMESTAMP_HEADER, SIGNED_TIMESTAMP.parse().unwrap());
+    headers.insert(SIGNATURE_HEADER, SIGNATURE.parse().unwrap());
+    assert!(Incoming::from_request(SIGNING_SECRET, &headers, SIGNED_BODY.as_bytes()).is_err());
+}
+
+#[test]
+fn test_parse_interactions() {
+    use crate::incoming::{Incoming, Interaction};
+
+    let form = |payload: serde_json::Value| {
+        serde_urlencoded::to_string(&[("payload", payload.to_string())]).unwrap()
+    };
+    let content_type = "application/x-www-form-urlencoded";
+
+    let body = form(serde_json::json!({
+        "type": "block_actions",
+        "user": { "id": "U1", "username": "jane", "team_id": "T1" },
+        "team": { "id": "T1", "domain": "example" },
+        "api_app_id": "A1",
+        "trigger_id": "1.2.3",
+        "response_url": "https://hooks.slack.com/actions/T1/1/2",
+        "container": { "type": "message", "message_ts": "1.5", "channel_id": "C1" },
+        "channel": { "id": "C1", "name": "deploys" },
+        "message": { "type": "message", "text": "Deploy finished", "ts": "1.5", "bot_id": "B1", "user": "U2" },
+        "actions": [
+            {
+                "type": "button",
+                "action_id": "rollback",
+                "block_id": "actions",
+                "text": { "type": "plain_text", "text": "Roll back", "emoji": true },
+                "value": "v1.2.2",
+                "action_ts": "1.6",
+            },
+            {
+                "type": "static_select",
+                "action_id": "kind",
+                "block_id": "actions",
+                "selected_option": { "text": { "type": "plain_text", "text": "Sick" }, "value": "sick" },
+                "action_ts": "1.6",
+            },
+        ],
+    }));
+    let actions = match Incoming::parse(content_type, body.as_bytes()).unwrap() {
+        Incoming::Interaction(Interaction::BlockActions(a)) => a,
+        i => panic!("expected block actions, got {:?}", i),
+    };
+    assert_eq!(actions.user.id, "U1");
+    assert_eq!(actions.channel.unwrap().name, "deploys");
+    assert_eq!(actions.message.unwrap().text, "Deploy finished");
+    assert_eq!(actions.actions[0].action_id, "rollback");
+    assert_eq!(actions.actions[0].state.type_, "button");
+    assert_eq!(actions.actions[0].state.value.as_deref(), Some("v1.2.2"));
+    assert_eq!(
+        actions.actions[1]
+            .state
+            .selected_option
+            .as_ref()
+            .unwrap()
+            .value,
+        "sick"
+    );
+
+    let body = form(serde_json::json!({
+        "type": "view_submission",
+        "user": { "id": "U1" },
+        "trigger_id": "1.2.3",
+        "view": {
+            "id": "V1",
+            "type": "modal",
+            "callback_id": "time_off",
+            "private_metadata": "C1",
+            "hash": "h1",
+            "blocks": [],
+            "state": {
+                "values": {
+                    "start": { "start": { "type": "datepicker", "selected_date": "2022-01-31" } },
+                    "reason": { "reason": { "type": "plain_text_input", "value": "Skiing" } },
+                    "who": { "who": { "type": "users_select", "selected_user": null } },
+                },
+            },
+        },
+        "response_urls": [],
+    }));
+    let submission = match Incoming::parse(content_type, body.as_bytes()).unwrap() {
+        Incoming::Interaction(Interaction::ViewSubmission(s)) => s,
+        i => panic!("expected a view submission, got {:?}", i),
+    };
+    let state = &submission.view.state;
+    assert_eq!(submission.view.callback_id, "time_off");
+    assert_eq!(
+        state.get("start", "start").unwrap().selected_date,
+        Some(chrono::NaiveDate::from_ymd(2022, 1, 31))
+    );
+    assert_eq!(
+        state.get("reason", "reason").unwrap().value.as_deref(),
+        Some("Skiing")
+    );
+    assert_eq!(state.get("who", "who").unwrap().selected_user, None);
+    assert!(state.get("reason", "missing").is_none());
+
+    let body = form(serde_json::json!({
+        "type": "shortcut",
+        "user": { "id": "U1" },
+        "callback_id": "new_request",
+        "trigger_id": "1.2.3",
+        "action_ts": "1.7",
+    }));
+    match Incoming::parse(content_type, body.as_bytes()).unwrap() {
+        Incoming::Interaction(Interaction::Shortcut(s)) => {
+            assert_eq!(s.callback_id, "new_request");
+            assert_eq!(s.trigger_id, "1.2.3");
+        }
+        i => panic!("expected a shortcut, got {:?}", i),
+    }
+
+    // Other interactions are kept as they are.
+    let body = form(serde_json::json!({ "type": "block_suggestion", "value": "vac" }));
+    match Incoming::parse(content_type, body.as_bytes()).unwrap() {
+        Incoming::Interaction(Interaction::Other(v)) => assert_eq!(v["value"], "vac"),
+        i => panic!("expected another interaction, got {:?}", i),
+    }
+}
+
+#[test]
+fn test_parse_events() {
+    use crate::incoming::{Event, EventRequest, Incoming};
+
+    let body = r#"{"token":"t","challenge":"3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P","type":"url_verification"}"#;
+    let incoming = Incoming::parse("application/json", body.as_bytes()).unwrap();
+    assert_eq!(
+        incoming.challenge(),
+        Some("3eZbrw1aBm2rZgRNFdxV2595E9CY3gmdALWMmHkvFXO7tYXAYM8P")
+    );
+
+    let body = serde_json::json!({
+        "type": "event_callback",
+        "team_id": "T1",
+        "api_app_id": "A1",
+        "event_id": "Ev1",
+        "event_time": 1_640_995_200,
+        "authorizations": [{ "team_id": "T1", "user_id": "U0", "is_bot": true }],
+        "event": {
+            "type": "app_mention",
+            "user": "U1",
+            "text": "<@U0> deploy api",
+            "ts": "1.8",
+            "channel": "C1",
+            "event_ts": "1.8",
+        },
+    })
+    .to_string();
+    let callback =
+        match Incoming::parse("application/json; charset=utf-8", body.as_bytes()).unwrap() {
+            Incoming::Event(EventRequest::EventCallback(c)) => c,
+            i => panic!("expected an event callback, got {:?}", i),
+        };
+    assert_eq!(callback.event_id, "Ev1");
+    assert!(callback.authorizations[0].is_bot);
+    match callback.event {
+        Event::AppMention(m) => {
+            assert_eq!(m.channel, "C1");
+            assert_eq!(m.message.user, "U1");
+            assert_eq!(m.message.text, "<@U0> deploy api");
+        }
+        e => panic!("expected a mention, got {:?}", e),
+    }
+
+    let body = serde_json::json!({
+        "type": "event_callback",
+        "event": {
+            "type": "reaction_added",
+            "user": "U1",
+            "reaction": "thumbsup",
+            "item_user": "U2",
+            "item": { "type": "message", "channel": "C1", "ts": "1.5" },
+            "event_ts": "1.9",
+        },
+    })
+    .to_string();
+    match Incoming::parse("application/json", body.as_bytes()).unwrap() {
+        Incoming::Event(EventRequest::EventCallback(c)) => match c.event {
+            Event::ReactionAdded(r) => {
+                assert_eq!(r.reaction, "thumbsup");
+                assert_eq!(r.item.ts, "1.5");
+            }
+            e => panic!("expected a reaction, got {:?}", e),
+        },
+        i => panic!("expected an event callback, got {:?}", i),
+    }
+
+    // Other events are kept as they are.
+    let body = r#"{"type":"event_callback","event":{"type":"channel_rename","channel":{"id":"C1","name":"ops"}}}"#;
+    match Incoming::parse("application/json", body.as_bytes()).unwrap() {
+        Incoming::Event(EventRequest::EventCallback(c)) => match c.event {
+            Event::Other(v) => assert_eq!(v["channel"]["name"], "ops"),
+            e => panic!("expected another event, got {:?}", e),
+        },
+        i => panic!("expected an event callback, got {:?}", i),
+    }
+
+    assert!(Incoming::parse("text/plain", b"hello").is_err());
+}
+
+#[tokio::test]
+async fn test_socket_mode() {
+    use futures::{SinkExt, StreamExt};
+    use tokio_tungstenite::tungstenite::Message;
+
+    use crate::incoming::{Event, EventRequest, Incoming, Interaction};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let ws_url = format!("ws://{}/link", listener.local_addr().unwrap());
+
+    let server = MockServer::start().await;
+    // The first connection can not be opened.
+    Mock::given(method("POST"))
+        .and(path("/apps.connections.open"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "ok": false, "error": "internal_error" })),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/apps.connections.open"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "ok": true, "url": ws_url })),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let connections = vec![
+        vec![
+            serde_json::json!({ "type": "hello", "num_connections": 1 }),
+            serde_json::json!({
+                "type": "events_api",
+                "envelope_id": "e1",
+                "accepts_response_payload": false,
+                "retry_attempt": 0,
+                "payload": {
+                    "type": "event_callback",
+                    "event_id": "Ev1",
+                    "event": { "type": "app_mention", "user": "U1", "text": "<@U0> hi", "ts": "1.1", "channel": "C1" },
+                },
+            }),
+            serde_json::json!({ "type": "disconnect", "reason": "refresh_requested" }),
+        ],
+        vec![
+            serde_json::json!({ "type": "hello", "num_connections": 1 }),
+            serde_json::json!({
+                "type": "slash_commands",
+                "envelope_id": "e2",
+                "payload": { "command": "/deploy", "text": "api", "user_id": "U1", "trigger_id": "1.2.3" },
+            }),
+            serde_json::json!("not an envelope"),
+            serde_json::json!({
+                "type": "interactive",
+                "envelope_id": "e3",
+                "accepts_response_payload": true,
+                "retry_attempt": 1,
+                "retry_reason": "timeout",
+                "payload": { "type": "shortcut", "user": { "id": "U1" }, "callback_id": "new_request" },
+            }),
+        ],
+    ];
+
+    let acks = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let stub_acks = acks.clone();
+    let stub = tokio::spawn(async move {
+        for messages in connections {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            for message in messages {
+                ws.send(Message::Text(message.to_string())).await.unwrap();
+                if message.get("envelope_id").is_none() {
+                    continue;
+                }
+                // Wait for the acknowledgement before sending more.
+                loop {
+                    if let Message::Text(ack) = ws.next().await.unwrap().unwrap() {
+                        let ack: serde_json::Value = serde_json::from_str(&ack).unwrap();
+                        stub_acks.lock().unwrap().push(ack);
+                        break;
+                    }
+                }
+            }
+            // Wait for the client to close the connection, or to read more.
+            while let Some(Ok(_)) = ws.next().await {}
+        }
+    });
+
+    let client = crate::Client::new("", "", "", "xapp-token", "").with_host(server.uri());
+    let envelopes: Vec<_> = crate::socket_mode::SocketMode::new(client)
+        .with_backoff(
+            std::time::Duration::from_millis(1),
+            std::time::Duration::from_millis(10),
+        )
+        .stream()
+        .take(5)
+        .inspect(|envelope| match envelope {
+            Ok(e) if e.accepts_response_payload => e
+                .ack_with(&serde_json::json!({ "response_action": "clear" }))
+                .unwrap(),
+            Ok(e) => e.ack().unwrap(),
+            Err(_) => {}
+        })
+        .collect()
+        .await;
+
+    // The stream goes on after a connection could not be opened.
+    assert!(envelopes[0].is_err());
+    let envelopes = &envelopes[1..];
+
+    match &envelopes[0].as_ref().unwrap().payload {
+        Incoming::Event(EventRequest::EventCallback(c)) => match &c.event {
+            Event::AppMention(m) => assert_eq!(m.message.text, "<@U0> hi"),
+            e => panic!("expected a mention, got {:?}", e),
+        },
+        p => panic!("expected an event, got {:?}", p),
+    }
+
+    // The client reconnected after the `disconnect` message.
+    let command = envelopes[1].as_ref().unwrap();
+    assert_eq!(command.envelope_id, "e2");
+    match &command.payload {
+        Incoming::Command(c) => {
+            assert_eq!(c.command, "/deploy");
+            assert_eq!(c.text, "api");
+        }
+        p => panic!("expected a command, got {:?}", p),
+    }
+
+    // A message that can not be read does not end the stream.
+    assert!(envelopes[2].is_err());
+
+    let shortcut = envelopes[3].as_ref().unwrap();
+    assert_eq!(shortcut.retry_attempt, 1);
+    assert_eq!(shortcut.retry_reason, "timeout");
+    match &shortcut.payload {
+        Incoming::Interaction(Interaction::Shortcut(s)) => assert_eq!(s.callback_id, "new_request"),
+        p => panic!("expected a shortcut, got {:?}", p),
+    }
+
+    // The stub is done once the stream is dropped.
+    stub.await.unwrap();
+    assert_eq!(
+        *acks.lock().unwrap(),
+        vec![
+            serde_json::json!({ "envelope_id": "e1" }),
+            serde_json::json!({ "envelope_id": "e2" }),
+            serde_json::json!({ "envelope_id": "e3", "payload": { "response_action": "clear" } }),
+        ]
+    );
+}
@@ -0,0 +1,408 @@
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use serde::Deserialize;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::blocks::{Message, View, ViewResponse, ViewType};
+
+#[async_trait::async_trait]
+pub trait ChatOps {
+    /// Post a message with blocks to a channel.
+    ///
+    /// The message is validated against the limits of Slack before it is sent.
+    async fn post_blocks(
+        &self,
+        message: &Message,
+    ) -> Result<crate::types::ChatPostMessageSuccessSchema>;
+
+    /// Post a message with blocks to a channel, only visible to one user.
+    async fn post_ephemeral_blocks(
+        &self,
+        user: &str,
+        message: &Message,
+    ) -> Result<crate::types::ChatPostEphemeralSuccessSchema>;
+
+    /// Replace the text and the blocks of a message.
+    async fn update_blocks(
+        &self,
+        ts: &str,
+        message: &Message,
+    ) -> Result<crate::types::ChatUpdateSuccessSchema>;
+}
+
+#[async_trait::async_trait]
+impl ChatOps for crate::chat::Chat {
+    /// Post a message with blocks to a channel.
+    ///
+    /// The message is validated against the limits of Slack before it is sent.
+    async fn post_blocks(
+        &self,
+        message: &Message,
+    ) -> Result<crate::types::ChatPostMessageSuccessSchema> {
+        message.validate()?;
+        let body = serde_json::to_vec(message)?;
+        self.client
+            .post("/chat.postMessage", Some(body.into()))
+            .await
+    }
+
+    /// Post a message with blocks to a channel, only visible to one user.
+    async fn post_ephemeral_blocks(
+        &self,
+        user: &str,
+        message: &Message,
+    ) -> Result<crate::types::ChatPostEphemeralSuccessSchema> {
+        message.validate()?;
+        let mut body = serde_json::to_value(message)?;
+        body["user"] = serde_json::Value::String(user.to_string());
+        self.client
+            .post(
+                "/chat.postEphemeral",
+                Some(serde_json::to_vec(&body)?.into()),
+            )
+            .await
+    }
+
+    /// Replace the text and the blocks of a message.
+    async fn update_blocks(
+        &self,
+        ts: &str,
+        message: &Message,
+    ) -> Result<crate::types::ChatUpdateSuccessSchema> {
+        message.validate()?;
+        let mut body = serde_json::to_value(message)?;
+        body["ts"] = serde_json::Value::String(ts.to_string());
+        self.client
+            .post("/chat.update", Some(serde_json::to_vec(&body)?.into()))
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+pub trait ViewsOps {
+    /// Open a modal, in response to an interaction with its `trigger_id`.
+    ///
+    /// The view is validated against the limits of Slack before it is sent.
+    async fn open_view(&self, trigger_id: &str, view: &View) -> Result<ViewResponse>;
+
+    /// Push a modal on top of the stack of the modal the interaction came
+    /// from.
+    async fn push_view(&self, trigger_id: &str, view: &View) -> Result<ViewResponse>;
+
+    /// Replace a view, by its ID or its `external_id`.
+    ///
+    /// With a `hash`, the view is only replaced if it did not change since.
+    async fn update_view(&self, view_id: &str, view: &View, hash: &str) -> Result<ViewResponse>;
+
+    /// Publish the App Home tab of a user.
+    async fn publish_home(&self, user_id: &str, view: &View, hash: &str) -> Result<ViewResponse>;
+}
+
+#[async_trait::async_trait]
+impl ViewsOps for crate::views::Views {
+    /// Open a modal, in response to an interaction with its `trigger_id`.
+    ///
+    /// The view is validated against the limits of Slack before it is sent.
+    async fn open_view(&self, trigger_id: &str, view: &View) -> Result<ViewResponse> {
+        let body = serde_json::json!({
+            "trigger_id": trigger_id,
+            "view": validated(view, ViewType::Modal)?,
+        });
+        self.client
+            .post("/views.open", Some(serde_json::to_vec(&body)?.into()))
+            .await
+    }
+
+    /// Push a modal on top of the stack of the modal the interaction came
+    /// from.
+    async fn push_view(&self, trigger_id: &str, view: &View) -> Result<ViewResponse> {
+        let body = serde_json::json!({
+            "trigger_id": trigger_id,
+            "view": validated(view, ViewType::Modal)?,
+        });
+        self.client
+            .post("/views.push", Some(serde_json::to_vec(&body)?.into()))
+            .await
+    }
+
+    /// Replace a view, by its ID or its `external_id`.
+    ///
+    /// With a `hash`, the view is only replaced if it did not change since.
+    async fn update_view(&self, view_id: &str, view: &View, hash: &str) -> Result<ViewResponse> {
+        view.validate()?;
+        let mut body = serde_json::json!({ "view": view });
+        if !view_id.is_empty() {
+            body["view_id"] = serde_json::Value::String(view_id.to_string());
+        } else if !view.external_id.is_empty() {
+            body["external_id"] = serde_json::Value::String(view.external_id.to_string());
+        } else {
+            anyhow::bail!("the view has no ID and no external_id");
+        }
+        if !hash.is_empty() {
+            body["hash"] = serde_json::Value::String(hash.to_string());
+        }
+        self.client
+            .post("/views.update", Some(serde_json::to_vec(&body)?.into()))
+            .await
+    }
+
+    /// Publish the App Home tab of a user.
+    async fn publish_home(&self, user_id: &str, view: &View, hash: &str) -> Result<ViewResponse> {
+        let mut body = serde_json::json!({
+            "user_id": user_id,
+            "view": validated(view, ViewType::Home)?,
+        });
+        if !hash.is_empty() {
+            body["hash"] = serde_json::Value::String(hash.to_string());
+        }
+        self.client
+            .post("/views.publish", Some(serde_json::to_vec(&body)?.into()))
+            .await
+    }
+}
+
+fn validated(view: &View, type_: ViewType) -> Result<&View> {
+    if view.type_ != type_ {
+        anyhow::bail!("expected a {:?} view, not a {:?} view", type_, view.type_);
+    }
+    view.validate()?;
+
+    Ok(view)
+}
+
+/// A file to upload, with `FilesOps::upload_external`.
+pub struct UploadFile {
+    pub filename: String,
+    pub title: String,
+    /// The description of an image, for screen readers.
+    pub alt_text: String,
+    /// The syntax highlighting of a snippet, like `python`.
+    pub snippet_type: String,
+    length: u64,
+    body: reqwest::Body,
+}
+
+impl UploadFile {
+    /// Upload the contents of a file, named after the last component of
+    /// its path.
+    pub async fn from_path<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path = path.as_ref();
+        let filename = match path.file_name() {
+            Some(name) => name.to_string_lossy().to_string(),
+            None => bail!("{} is not the path of a file", path.display()),
+        };
+        let file = tokio::fs::File::open(path).await?;
+        let length = file.metadata().await?.len();
+
+        Ok(UploadFile::from_reader(&filename, file, length))
+    }
+
+    /// Upload the contents of a reader, as they are read.
+    ///
+    /// Slack needs the `length` of the contents, in bytes, before they are
+    /// uploaded.
+    pub fn from_reader<R>(filename: &str, reader: R, length: u64) -> Self
+    where
+        R: AsyncRead + Unpin + Send + Sync + 'static,
+    {
+        let chunks = futures::stream::try_unfold(reader, |mut reader| async move {
+            let mut chunk = vec![0; UPLOAD_CHUNK_SIZE];
+            let n = reader.read(&mut chunk).await?;
+            if n == 0 {
+                return Ok::<_, std::io::Error>(None);
+            }
+            chunk.truncate(n);
+
+            Ok(Some((bytes::Bytes::from(chunk), reader)))
+        });
+
+        UploadFile::new(filename, length, reqwest::Body::wrap_stream(chunks))
+    }
+
+    /// Upload contents that are already in memory.
+    pub fn from_bytes<B: Into<bytes::Bytes>>(filename: &str, contents: B) -> Self {
+        let contents = contents.into();
+        UploadFile::new(filename, contents.len() as u64, contents.into())
+    }
+
+    fn new(filename: &str, length: u64, body: reqwest::Body) -> Self {
+        UploadFile {
+            filename: filename.to_string(),
+            title: String::new(),
+            alt_text: String::new(),
+            snippet_type: String::new(),
+            length,
+            body,
+        }
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+
+    pub fn with_alt_text(mut self, alt_text: &str) -> Self {
+        self.alt_text = alt_text.to_string();
+        self
+    }
+
+    pub fn with_snippet_type(mut self, snippet_type: &str) -> Self {
+        self.snippet_type = snippet_type.to_string();
+        self
+    }
+}
+
+/// The number of bytes read at a time, from the reader of an `UploadFile`.
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Where uploaded files are shared, in a single message.
+///
+/// Files that are not shared are only visible to their uploader.
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct FileShare {
+    pub channels: Vec<String>,
+    /// The timestamp of the message to reply to, in the only channel.
+    pub thread_ts: String,
+    /// The text of the message the files are shared with.
+    pub initial_comment: String,
+}
+
+impl FileShare {
+    /// Share the files to one or more channels.
+    pub fn channels<S: ToString>(channels: &[S]) -> Self {
+        FileShare {
+            channels: channels.iter().map(|c| c.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Share the files in the thread of a message.
+    pub fn thread(channel: &str, thread_ts: &str) -> Self {
+        FileShare {
+            channels: vec![channel.to_string()],
+            thread_ts: thread_ts.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_initial_comment(mut self, initial_comment: &str) -> Self {
+        self.initial_comment = initial_comment.to_string();
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct UploadUrl {
+    upload_url: String,
+    file_id: String,
+}
+
+#[derive(Deserialize)]
+struct CompletedUpload {
+    #[serde(default)]
+    files: Vec<crate::types::ObjsFile>,
+}
+
+#[async_trait::async_trait]
+pub trait FilesOps {
+    /// Upload files and share them together, in a single message.
+    ///
+    /// This gets an upload URL for each file with
+    /// `files.getUploadURLExternal`, uploads the contents of the file to it,
+    /// and completes the upload of all the files with
+    /// `files.completeUploadExternal`. Returns the completed files.
+    async fn upload_external(
+        &self,
+        files: Vec<UploadFile>,
+        share: &FileShare,
+    ) -> Result<Vec<crate::types::ObjsFile>>;
+}
+
+#[async_trait::async_trait]
+impl FilesOps for crate::files::Files {
+    /// Upload files and share them together, in a single message.
+    ///
+    /// This gets an upload URL for each file with
+    /// `files.getUploadURLExternal`, uploads the contents of the file to it,
+    /// and completes the upload of all the files with
+    /// `files.completeUploadExternal`. Returns the completed files.
+    async fn upload_external(
+        &self,
+        files: Vec<UploadFile>,
+        share: &FileShare,
+    ) -> Result<Vec<crate::types::ObjsFile>> {
+        if files.is_empty() {
+            bail!("there are no files to upload");
+        }
+        if !share.thread_ts.is_empty() && share.channels.len() != 1 {
+            bail!("files can only be shared in a thread of a single channel");
+        }
+        if !share.initial_comment.is_empty() && share.channels.is_empty() {
+            bail!("an initial comment needs a channel to share the files to");
+        }
+
+        let mut uploaded = Vec::new();
+        for file in files {
+            let mut query_args = vec![
+                ("filename".to_string(), file.filename.to_string()),
+                ("length".to_string(), file.length.to_string()),
+            ];
+            if !file.alt_text.is_empty() {
+                query_args.push(("alt_txt".to_string(), file.alt_text.to_string()));
+            }
+            if !file.snippet_type.is_empty() {
+                query_args.push(("snippet_type".to_string(), file.snippet_type.to_string()));
+            }
+            let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+            let url = format!("/files.getUploadURLExternal?{}", query_);
+            let upload: UploadUrl = self.client.get(&url, None).await?;
+
+            // The upload URL is signed, it does not take the token.
+            let resp = self
+                .client
+                .client
+                .post(&upload.upload_url)
+                .header(reqwest::header::CONTENT_LENGTH, file.length)
+                .body(file.body)
+                .send()
+                .await?;
+            let status = resp.status();
+            if !status.is_success() {
+                let body = resp.bytes().await?;
+                bail!(
+                    "code: {}, error: {:?}",
+                    status,
+                    String::from_utf8_lossy(&body)
+                );
+            }
+
+            let mut completed = serde_json::json!({ "id": upload.file_id });
+            if !file.title.is_empty() {
+                completed["title"] = serde_json::Value::String(file.title);
+            }
+            uploaded.push(completed);
+        }
+
+        let mut body = serde_json::json!({ "files": uploaded });
+        if !share.thread_ts.is_empty() {
+            body["channel_id"] = serde_json::Value::String(share.channels[0].to_string());
+            body["thread_ts"] = serde_json::Value::String(share.thread_ts.to_string());
+        } else if !share.channels.is_empty() {
+            body["channels"] = serde_json::Value::String(share.channels.join(","));
+        }
+        if !share.initial_comment.is_empty() {
+            body["initial_comment"] = serde_json::Value::String(share.initial_comment.to_string());
+        }
+
+        let completed: CompletedUpload = self
+            .client
+            .post(
+                "/files.completeUploadExternal",
+                Some(serde_json::to_vec(&body)?.into()),
+            )
+            .await?;
+
+        Ok(completed.files)
+    }
+}
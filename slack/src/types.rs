@@ -3331,8 +3331,8 @@ pub struct ObjsUserProfile {
         deserialize_with = "crate::utils::deserialize_null_string::deserialize"
     )]
     pub email: String,
-    #[serde()]
-    pub fields: FieldsOneOf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<FieldsOneOf>,
     #[serde(
         default,
         skip_serializing_if = "String::is_empty",
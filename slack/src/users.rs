@@ -58,6 +58,86 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/users.conversations` endpoint.
+     *
+     * As opposed to `conversation`, this function returns all the pages of the request at once.
+     *
+     * List conversations the calling user may access.
+     *
+     * FROM: <https://api.slack.com/methods/users.conversations>
+     */
+    pub async fn conversations_all(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+    ) -> Result<Vec<crate::types::ObjsConversation>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_archived {
+            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+        }
+        if !types.is_empty() {
+            query_args.push(("types".to_string(), types.to_string()));
+        }
+        if !user.is_empty() {
+            query_args.push(("user".to_string(), user.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users.conversations?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsConversation> =
+                crate::utils::cursor_items(&mut page, "channels")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/users.conversations` endpoint.
+     *
+     * As opposed to `conversation`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * List conversations the calling user may access.
+     *
+     * FROM: <https://api.slack.com/methods/users.conversations>
+     */
+    pub fn conversations_all_stream(
+        &self,
+        user: &str,
+        types: &str,
+        exclude_archived: bool,
+    ) -> impl futures::Stream<Item = Result<crate::types::ObjsConversation>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if exclude_archived {
+            query_args.push(("exclude_archived".to_string(), exclude_archived.to_string()));
+        }
+        if !types.is_empty() {
+            query_args.push(("types".to_string(), types.to_string()));
+        }
+        if !user.is_empty() {
+            query_args.push(("user".to_string(), user.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users.conversations?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsConversation> =
+                crate::utils::cursor_items(&mut page, "channels")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `POST` to the `/users.deletePhoto` endpoint.
      *
@@ -193,6 +273,67 @@ impl Users {
         self.client.get(&url, None).await
     }
 
+    /**
+     * This function performs a `GET` to the `/users.list` endpoint.
+     *
+     * As opposed to `list`, this function returns all the pages of the request at once.
+     *
+     * Lists all users in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/users.list>
+     */
+    pub async fn list_all(&self, include_locale: bool) -> Result<Vec<crate::types::ObjsUser>> {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if include_locale {
+            query_args.push(("include_locale".to_string(), include_locale.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsUser> =
+                crate::utils::cursor_items(&mut page, "members")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        futures::TryStreamExt::try_collect(resp).await
+    }
+
+    /**
+     * This function performs a `GET` to the `/users.list` endpoint.
+     *
+     * As opposed to `list`, this function returns a stream of all the items, requesting the pages as they are needed.
+     *
+     * Lists all users in a Slack team.
+     *
+     * FROM: <https://api.slack.com/methods/users.list>
+     */
+    pub fn list_all_stream(
+        &self,
+        include_locale: bool,
+    ) -> impl futures::Stream<Item = Result<crate::types::ObjsUser>> + '_ {
+        let mut query_args: Vec<(String, String)> = Default::default();
+        if include_locale {
+            query_args.push(("include_locale".to_string(), include_locale.to_string()));
+        }
+        let query_ = serde_urlencoded::to_string(&query_args).unwrap();
+        let url = format!("/users.list?{}", query_);
+
+        let resp = crate::utils::cursor_pages(&url, move |url| async move {
+            let mut page: serde_json::Value = self.client.get(&url, None).await?;
+            let next_cursor = crate::utils::next_cursor(&page);
+            let items: Vec<crate::types::ObjsUser> =
+                crate::utils::cursor_items(&mut page, "members")?;
+            Ok((next_cursor, items))
+        });
+
+        // Return our response data.
+        resp
+    }
+
     /**
      * This function performs a `GET` to the `/users.lookupByEmail` endpoint.
      *